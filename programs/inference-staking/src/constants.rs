@@ -21,3 +21,10 @@ pub const MIN_SLASHING_DELAY_SECONDS: u64 = 86_400;
 /// Precision scalar for USDC calculations
 /// Using 1e18 for maximum precision with large share counts
 pub const USDC_PRECISION_FACTOR: u128 = 1_000_000_000_000_000_000;
+
+/// Halt reason code set when a pool is halted by a reported equivocation.
+pub const HALT_REASON_EQUIVOCATION: u8 = u8::MAX;
+
+/// Size of an attestation message signed by an operator auth key: operator pool (32 bytes),
+/// subject id of the job or epoch (32 bytes) and result hash (32 bytes).
+pub const ATTESTATION_MESSAGE_LENGTH: usize = 96;
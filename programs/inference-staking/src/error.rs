@@ -102,4 +102,172 @@ pub enum ErrorCode {
     InvalidAmount,
     #[msg("Invalid shares amount provided - cannot be greater than total operator shares")]
     InvalidSlashSharesAmount,
+    #[msg("Exceeded allowed reward mints length")]
+    RewardMintsExceeded,
+    #[msg("Reward mint is already registered or is the staked token or USDC mint")]
+    InvalidRewardMint,
+    #[msg("Reward mint is not registered")]
+    RewardMintNotRegistered,
+    #[msg("Reward mint amounts do not match registered reward mints")]
+    InvalidRewardMintAmounts,
+    #[msg("Invalid reward mint vault provided")]
+    InvalidRewardMintVault,
+    #[msg("Insufficient reward mint tokens to issue")]
+    InsufficientRewardMintTokens,
+    #[msg("No reward mint earnings available to claim")]
+    NoRewardMintEarningsToClaim,
+    #[msg("Reward mint earnings must be claimed before closing")]
+    UnclaimedRewardMintEarnings,
+    #[msg("Commission rate exceeds the maximum allowed commission rate")]
+    CommissionRateAboveMax,
+    #[msg("Commission rate increase exceeds the maximum allowed increase")]
+    CommissionIncreaseTooLarge,
+    #[msg("Commission increase notice period must be at least the decrease notice period")]
+    InvalidCommissionNoticePeriod,
+    #[msg("Exceeded allowed commission rate schedule length")]
+    CommissionScheduleTooLong,
+    #[msg("Commission rate schedule must be ordered by effective epoch, after the current epoch")]
+    InvalidCommissionSchedule,
+    #[msg("Commission rate change does not meet the required notice period")]
+    CommissionNoticePeriodNotMet,
+    #[msg("Invalid commission recipients length")]
+    CommissionRecipientsLengthInvalid,
+    #[msg("Duplicate commission recipient provided")]
+    DuplicateCommissionRecipient,
+    #[msg("Commission recipient shares must be non-zero and sum to 10,000 basis points")]
+    InvalidCommissionSplit,
+    #[msg("Commission split is configured, commission must be distributed to all recipients")]
+    CommissionSplitConfigured,
+    #[msg("Commission split is not configured")]
+    CommissionSplitNotConfigured,
+    #[msg("Invalid commission recipient token account provided")]
+    InvalidCommissionRecipientAccount,
+    #[msg("Invalid referrer provided")]
+    InvalidReferrer,
+    #[msg("StakingRecord already has a different referrer")]
+    ReferrerAlreadySet,
+    #[msg("Invalid referral record provided")]
+    InvalidReferralRecord,
+    #[msg("No referral earnings available to claim")]
+    NoReferralEarningsToClaim,
+    #[msg("Referral commission share cannot exceed 10,000 basis points")]
+    InvalidReferralCommissionShare,
+    #[msg("Slash basis points must be less than 10,000")]
+    InvalidSlashBps,
+    #[msg("Delegator share of the slash exceeds the maximum ratio to the operator share")]
+    DelegatorSlashRatioExceeded,
+    #[msg("Pending unstakes cannot be settled while an unstaking slash can still be reversed")]
+    UnstakingSlashUnresolved,
+    #[msg("Confiscation percentage cannot exceed 10,000 basis points")]
+    InvalidConfiscationPolicy,
+    #[msg("Slash proposal has already been executed")]
+    SlashProposalAlreadyExecuted,
+    #[msg("Slash proposal does not have enough approvals")]
+    SlashProposalNotApproved,
+    #[msg("Slashing authority has already approved the slash proposal")]
+    DuplicateSlashApproval,
+    #[msg("Slashing approval threshold cannot exceed the number of slashing authorities")]
+    InvalidSlashingApprovalThreshold,
+    #[msg("Slash escrow has already been released or reversed")]
+    SlashEscrowResolved,
+    #[msg("Slash appeal period has ended")]
+    SlashAppealPeriodEnded,
+    #[msg("Slash appeal period has not ended")]
+    SlashAppealPeriodNotEnded,
+    #[msg("Slash redistribution, burn and insurance shares cannot exceed 10,000 basis points")]
+    InvalidSlashSplit,
+    #[msg("Insurance commission share cannot exceed 10,000 basis points")]
+    InvalidInsuranceCommissionShare,
+    #[msg("No pending insurance contributions to deposit")]
+    NoPendingInsuranceContributions,
+    #[msg("Insurance claim has already been approved")]
+    InsuranceClaimAlreadyApproved,
+    #[msg("Insurance claim has not been approved")]
+    InsuranceClaimNotApproved,
+    #[msg("Insurance claim has already been paid")]
+    InsuranceClaimAlreadyPaid,
+    #[msg("Approved amount exceeds the requested amount")]
+    InsuranceClaimAmountExceeded,
+    #[msg("Insufficient insurance fund balance")]
+    InsufficientInsuranceFundBalance,
+    #[msg("Operator StakingRecord cannot file an insurance claim")]
+    OperatorInsuranceClaim,
+    #[msg("Halt expiry must be in the future")]
+    InvalidHaltExpiry,
+    #[msg("Halt has no expiry or has not expired")]
+    HaltNotExpired,
+    #[msg("Pool has been slashed since it was halted")]
+    SlashedSinceHalt,
+    #[msg("Invalid equivocation proof")]
+    InvalidEquivocationProof,
+    #[msg("Key is not the admin or an auth key of the operator pool")]
+    InvalidTombstoneKey,
+    #[msg("Invalid Tombstone account")]
+    InvalidTombstoneAccount,
+    #[msg("Key has been tombstoned")]
+    KeyTombstoned,
+    #[msg("Operator pool has been tombstoned")]
+    OperatorPoolTombstoned,
+    #[msg("No pending program admin transfer")]
+    NoPendingProgramAdmin,
+    #[msg("Signer is not the pending program admin")]
+    InvalidPendingProgramAdmin,
+    #[msg("Config change must be queued while a config change timelock is set")]
+    ConfigChangeRequiresTimelock,
+    #[msg("Config change timelock has not passed")]
+    ConfigChangeNotMatured,
+    #[msg("Approval threshold cannot exceed the number of authorities")]
+    InvalidApprovalThreshold,
+    #[msg("Signer is not an authority of the action role")]
+    InvalidActionAuthority,
+    #[msg("Approved PendingAction is required")]
+    PendingActionRequired,
+    #[msg("PendingAction does not match the instruction")]
+    PendingActionMismatch,
+    #[msg("PendingAction has already been executed")]
+    PendingActionAlreadyExecuted,
+    #[msg("PendingAction does not have enough approvals")]
+    PendingActionNotApproved,
+    #[msg("Authority has already approved the PendingAction")]
+    DuplicateActionApproval,
+    #[msg("Max. number of approvals reached")]
+    ApprovalsExceeded,
+    #[msg("Invalid epoch authority")]
+    InvalidEpochAuthority,
+    #[msg("Instruction is paused")]
+    InstructionPaused,
+    #[msg("Invalid instructions pause mask")]
+    InvalidPauseMask,
+    #[msg("Pause expiry must be in the future")]
+    InvalidPauseExpiry,
+    #[msg("Signer must be the program admin or a halt authority")]
+    InvalidPauseAuthority,
+    #[msg("Program is winding down")]
+    ProgramWindingDown,
+    #[msg("Program is not winding down")]
+    ProgramNotWindingDown,
+    #[msg("Wind-down final epoch cannot be before the current epoch")]
+    InvalidWindDownFinalEpoch,
+    #[msg("Epoch is after the wind-down final epoch")]
+    EpochAfterWindDownFinalEpoch,
+    #[msg("Nothing to wind down for the staking record")]
+    NothingToWindDown,
+    #[msg("Account must be migrated to the current version")]
+    AccountVersionOutdated,
+    #[msg("Account is already at the current version")]
+    AccountVersionCurrent,
+    #[msg("Account version is not supported")]
+    UnsupportedAccountVersion,
+    #[msg("AdminAuditLog is required")]
+    AdminAuditLogRequired,
+    #[msg("InsuranceFund accounts are required")]
+    InsuranceFundRequired,
+    #[msg("Invalid reward mint destination account provided")]
+    InvalidRewardMintDestination,
+    #[msg("Max. number of unstaking slashes of the pool has been reached")]
+    UnstakingSlashLimitReached,
+    #[msg("Only the program admin can unpause instructions or shorten a pause")]
+    InvalidUnpauseAuthority,
+    #[msg("Setting cannot be changed and cleared at the same time")]
+    ConflictingConfigChange,
 }
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AcceptProgramAdminEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this accept program admin event instruction was executed for
+    pub epoch: u64,
+
+    // Previous program admin
+    pub old_program_admin: Pubkey,
+
+    // New program admin that accepted the transfer
+    pub new_program_admin: Pubkey,
+}
+
+impl AcceptProgramAdminEvent {
+    /// Schema version of AcceptProgramAdminEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
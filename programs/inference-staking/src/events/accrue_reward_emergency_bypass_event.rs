@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AccrueRewardEmergencyBypassEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that bypassed a reward accrual
+    pub operator_pool: Pubkey,
+
+    // Epoch this accrue reward emergency bypass event instruction was executed for
+    pub epoch: u64,
+
+    // Epoch of the reward record that was bypassed
+    pub bypassed_epoch: u64,
+}
+
+impl AccrueRewardEmergencyBypassEvent {
+    /// Schema version of AccrueRewardEmergencyBypassEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
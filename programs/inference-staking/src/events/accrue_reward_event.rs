@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct AccrueRewardEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -28,4 +31,24 @@ pub struct AccrueRewardEvent {
 
     // Operator USDC share
     pub operator_usdc_commission: u64,
+
+    // Additional reward mint amounts, in RewardRecord order
+    pub reward_mint_amounts: Vec<u64>,
+
+    // Part of the operator reward commission paid to referrers
+    pub referral_reward_commission: u64,
+
+    // Part of the operator USDC commission paid to referrers
+    pub referral_usdc_commission: u64,
+
+    // Reward token commission set aside for the insurance fund
+    pub insurance_reward_commission: u64,
+
+    // USDC commission set aside for the insurance fund
+    pub insurance_usdc_commission: u64,
+}
+
+impl AccrueRewardEvent {
+    /// Schema version of AccrueRewardEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
 }
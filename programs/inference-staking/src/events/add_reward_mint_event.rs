@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AddRewardMintEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this add reward mint event instruction was executed for
+    pub epoch: u64,
+
+    // Additional reward mint that was registered
+    pub reward_mint: Pubkey,
+
+    // Index of the reward mint in the PoolOverview registry
+    pub reward_mint_index: u8,
+}
+
+impl AddRewardMintEvent {
+    /// Schema version of AddRewardMintEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
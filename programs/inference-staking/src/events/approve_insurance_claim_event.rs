@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ApproveInsuranceClaimEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool the claimant was staked in
+    pub operator_pool: Pubkey,
+
+    // Epoch this approve insurance claim event instruction was executed for
+    pub epoch: u64,
+
+    // Insurance claim that was approved
+    pub insurance_claim: Pubkey,
+
+    // Id of the insurance claim
+    pub claim_id: u64,
+
+    // Amount of tokens approved
+    pub token_amount: u64,
+
+    // Amount of USDC approved
+    pub usdc_amount: u64,
+}
+
+impl ApproveInsuranceClaimEvent {
+    /// Schema version of ApproveInsuranceClaimEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
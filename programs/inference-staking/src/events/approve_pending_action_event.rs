@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ApprovePendingActionEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this approve pending action event instruction was executed for
+    pub epoch: u64,
+
+    // Pending action that was approved
+    pub pending_action: Pubkey,
+
+    // Id of the pending action
+    pub action_id: u64,
+
+    // Authority that approved the action
+    pub authority: Pubkey,
+
+    // Number of approvals on the action after this approval
+    pub approvals: u8,
+}
+
+impl ApprovePendingActionEvent {
+    /// Schema version of ApprovePendingActionEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ApproveSlashProposalEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that is proposed to be slashed
+    pub operator_pool: Pubkey,
+
+    // Epoch this approve slash proposal event instruction was executed for
+    pub epoch: u64,
+
+    // Slash proposal that was approved
+    pub slash_proposal: Pubkey,
+
+    // Slashing authority that approved the proposal
+    pub authority: Pubkey,
+
+    // Number of approvals on the proposal after this approval
+    pub approvals: u8,
+}
+
+impl ApproveSlashProposalEvent {
+    /// Schema version of ApproveSlashProposalEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
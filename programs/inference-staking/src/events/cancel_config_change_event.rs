@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CancelConfigChangeEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this cancel config change event instruction was executed for
+    pub epoch: u64,
+
+    // Pending config change that was cancelled
+    pub pending_config_change: Pubkey,
+
+    // Id of the config change
+    pub config_change_id: u64,
+}
+
+impl CancelConfigChangeEvent {
+    /// Schema version of CancelConfigChangeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
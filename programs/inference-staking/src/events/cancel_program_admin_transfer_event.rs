@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CancelProgramAdminTransferEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this cancel program admin transfer event instruction was executed for
+    pub epoch: u64,
+
+    // Program admin that cancelled the transfer
+    pub program_admin: Pubkey,
+
+    // Proposed program admin whose transfer was cancelled
+    pub pending_program_admin: Pubkey,
+}
+
+impl CancelProgramAdminTransferEvent {
+    /// Schema version of CancelProgramAdminTransferEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct CancelUnstakeEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -26,3 +29,8 @@ pub struct CancelUnstakeEvent {
     // Number of shares created from the cancelled unstake
     pub shares_amount: u64,
 }
+
+impl CancelUnstakeEvent {
+    /// Schema version of CancelUnstakeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
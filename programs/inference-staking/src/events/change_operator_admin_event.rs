@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct ChangeOperatorAdminEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -17,3 +20,8 @@ pub struct ChangeOperatorAdminEvent {
     // New admin of the operator pool
     pub new_admin: Pubkey,
 }
+
+impl ChangeOperatorAdminEvent {
+    /// Schema version of ChangeOperatorAdminEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
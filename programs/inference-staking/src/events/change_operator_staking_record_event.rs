@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct ChangeOperatorStakingRecordEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -20,3 +23,8 @@ pub struct ChangeOperatorStakingRecordEvent {
     // New operator staking record
     pub new_staking_record: Pubkey,
 }
+
+impl ChangeOperatorStakingRecordEvent {
+    /// Schema version of ChangeOperatorStakingRecordEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
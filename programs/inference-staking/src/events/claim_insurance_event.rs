@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClaimInsuranceEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool the claimant was staked in
+    pub operator_pool: Pubkey,
+
+    // Epoch this claim insurance event instruction was executed for
+    pub epoch: u64,
+
+    // Insurance claim that was paid out
+    pub insurance_claim: Pubkey,
+
+    // Id of the insurance claim
+    pub claim_id: u64,
+
+    // Delegator that received the payout
+    pub claimant: Pubkey,
+
+    // Amount of tokens paid out
+    pub token_amount: u64,
+
+    // Amount of USDC paid out
+    pub usdc_amount: u64,
+}
+
+impl ClaimInsuranceEvent {
+    /// Schema version of ClaimInsuranceEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClaimReferralEarningsEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that referral earnings were claimed for
+    pub operator_pool: Pubkey,
+
+    // Epoch this claim referral earnings event instruction was executed for
+    pub epoch: u64,
+
+    // Referral record that earnings were claimed from
+    pub referral_record: Pubkey,
+
+    // Referrer that claimed the earnings
+    pub referrer: Pubkey,
+
+    // Amount of reward tokens claimed
+    pub reward_amount: u64,
+
+    // Amount of USDC claimed
+    pub usdc_amount: u64,
+}
+
+impl ClaimReferralEarningsEvent {
+    /// Schema version of ClaimReferralEarningsEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClaimRewardMintEarningsEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool this claim reward mint earnings event instruction was executed for
+    pub operator_pool: Pubkey,
+
+    // Epoch this claim reward mint earnings event instruction was executed for
+    pub epoch: u64,
+
+    // Staking record that reward mint earnings were claimed from
+    pub staking_record: Pubkey,
+
+    // Owner of the staking record
+    pub owner: Pubkey,
+
+    // Whether the claimer is the operator
+    pub is_operator: bool,
+
+    // Additional reward mint that was claimed
+    pub reward_mint: Pubkey,
+
+    // Destination account where reward mint earnings were sent
+    pub destination: Pubkey,
+
+    // Amount of reward mint tokens claimed
+    pub amount: u64,
+}
+
+impl ClaimRewardMintEarningsEvent {
+    /// Schema version of ClaimRewardMintEarningsEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct ClaimUnstakeEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -23,3 +26,8 @@ pub struct ClaimUnstakeEvent {
     // Amount of tokens claimed
     pub token_amount: u64,
 }
+
+impl ClaimUnstakeEvent {
+    /// Schema version of ClaimUnstakeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct ClaimUsdcEarningsEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -26,3 +29,8 @@ pub struct ClaimUsdcEarningsEvent {
     // Amount of USDC claimed
     pub usdc_amount: u64,
 }
+
+impl ClaimUsdcEarningsEvent {
+    /// Schema version of ClaimUsdcEarningsEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CloseOperatorPoolEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that was closed
+    pub operator_pool: Pubkey,
+
+    // Epoch this close operator pool event instruction was executed for
+    pub epoch: u64,
+
+    // Epoch the operator pool is closed at
+    pub closed_at_epoch: u64,
+}
+
+impl CloseOperatorPoolEvent {
+    /// Schema version of CloseOperatorPoolEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
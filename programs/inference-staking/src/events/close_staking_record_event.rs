@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CloseStakingRecordEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool of the closed staking record
+    pub operator_pool: Pubkey,
+
+    // Epoch this close staking record event instruction was executed for
+    pub epoch: u64,
+
+    // Staking record that was closed
+    pub staking_record: Pubkey,
+
+    // Owner of the staking record
+    pub owner: Pubkey,
+
+    // Account that received the reclaimed rent
+    pub receiver: Pubkey,
+}
+
+impl CloseStakingRecordEvent {
+    /// Schema version of CloseStakingRecordEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreateAdminAuditLogEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this create admin audit log event instruction was executed for
+    pub epoch: u64,
+
+    // Admin audit log that was created
+    pub admin_audit_log: Pubkey,
+}
+
+impl CreateAdminAuditLogEvent {
+    /// Schema version of CreateAdminAuditLogEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreateInsuranceClaimEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool the claimant was staked in
+    pub operator_pool: Pubkey,
+
+    // Epoch this create insurance claim event instruction was executed for
+    pub epoch: u64,
+
+    // Insurance claim that was created
+    pub insurance_claim: Pubkey,
+
+    // Id of the insurance claim
+    pub claim_id: u64,
+
+    // Delegator that filed the claim
+    pub claimant: Pubkey,
+
+    // Staking record of the claimant
+    pub staking_record: Pubkey,
+
+    // Hash of the off-chain description of the loss
+    pub evidence_hash: [u8; 32],
+
+    // Amount of tokens requested
+    pub token_amount: u64,
+
+    // Amount of USDC requested
+    pub usdc_amount: u64,
+}
+
+impl CreateInsuranceClaimEvent {
+    /// Schema version of CreateInsuranceClaimEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreateInsuranceFundEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this create insurance fund event instruction was executed for
+    pub epoch: u64,
+
+    // Insurance fund that was created
+    pub insurance_fund: Pubkey,
+}
+
+impl CreateInsuranceFundEvent {
+    /// Schema version of CreateInsuranceFundEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
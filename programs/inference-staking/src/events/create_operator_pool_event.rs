@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreateOperatorPoolEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that was created
+    pub operator_pool: Pubkey,
+
+    // Epoch this create operator pool event instruction was executed for
+    pub epoch: u64,
+
+    // Admin of the operator pool
+    pub admin: Pubkey,
+
+    // Staking record of the operator
+    pub operator_staking_record: Pubkey,
+
+    // Registration fee paid for the operator pool
+    pub registration_fee: u64,
+
+    // Whether commission fees are automatically staked
+    pub auto_stake_fees: bool,
+
+    // Commission rate on reward tokens in basis points
+    pub reward_commission_rate_bps: u16,
+
+    // Commission rate on USDC earnings in basis points
+    pub usdc_commission_rate_bps: u16,
+
+    // Whether delegation to the operator pool is allowed
+    pub allow_delegation: bool,
+
+    // Auth keys of the operator pool
+    pub operator_auth_keys: Vec<Pubkey>,
+}
+
+impl CreateOperatorPoolEvent {
+    /// Schema version of CreateOperatorPoolEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AuthorityRole;
+
+#[event]
+pub struct CreatePendingActionEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this create pending action event instruction was executed for
+    pub epoch: u64,
+
+    // Pending action that was created
+    pub pending_action: Pubkey,
+
+    // Id of the pending action
+    pub action_id: u64,
+
+    // Authority role that must approve the action
+    pub role: AuthorityRole,
+
+    // Hash of the gated instruction and its arguments
+    pub action_hash: [u8; 32],
+
+    // Authority that created the action
+    pub proposer: Pubkey,
+}
+
+impl CreatePendingActionEvent {
+    /// Schema version of CreatePendingActionEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
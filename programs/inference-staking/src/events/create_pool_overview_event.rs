@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PoolOverviewConfig;
+
+#[event]
+pub struct CreatePoolOverviewEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this create pool overview event instruction was executed for
+    pub epoch: u64,
+
+    // Program admin of the pool overview
+    pub program_admin: Pubkey,
+
+    // Mint address of the staked token
+    pub mint: Pubkey,
+
+    // Initial settings of the pool overview
+    pub config: PoolOverviewConfig,
+}
+
+impl CreatePoolOverviewEvent {
+    /// Schema version of CreatePoolOverviewEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreateReferralRecordEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool the referral record was created for
+    pub operator_pool: Pubkey,
+
+    // Epoch this create referral record event instruction was executed for
+    pub epoch: u64,
+
+    // Referral record that was created
+    pub referral_record: Pubkey,
+
+    // Referrer of the referral record
+    pub referrer: Pubkey,
+}
+
+impl CreateReferralRecordEvent {
+    /// Schema version of CreateReferralRecordEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreateRewardRecordEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch the reward record was created for
+    pub epoch: u64,
+
+    // Reward record that was created
+    pub reward_record: Pubkey,
+
+    // Reward distribution authority that created the reward record
+    pub authority: Pubkey,
+
+    // Merkle roots of the reward distribution
+    pub merkle_roots: Vec<[u8; 32]>,
+
+    // Total amount of reward tokens issued for the epoch
+    pub total_rewards: u64,
+
+    // Total amount of USDC paid out for the epoch
+    pub total_usdc_payout: u64,
+
+    // Amount issued for each additional reward mint, in PoolOverview registry order
+    pub reward_mint_payouts: Vec<u64>,
+
+    // Pending action that approved the reward record, if required
+    pub pending_action: Option<Pubkey>,
+}
+
+impl CreateRewardRecordEvent {
+    /// Schema version of CreateRewardRecordEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
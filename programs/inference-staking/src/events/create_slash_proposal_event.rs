@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreateSlashProposalEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that is proposed to be slashed
+    pub operator_pool: Pubkey,
+
+    // Epoch this create slash proposal event instruction was executed for
+    pub epoch: u64,
+
+    // Slash proposal that was created
+    pub slash_proposal: Pubkey,
+
+    // Slashing authority that created the proposal
+    pub proposer: Pubkey,
+
+    // Hash of the off-chain evidence for the slash
+    pub evidence_hash: [u8; 32],
+}
+
+impl CreateSlashProposalEvent {
+    /// Schema version of CreateSlashProposalEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
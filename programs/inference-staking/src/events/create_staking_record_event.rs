@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreateStakingRecordEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool the staking record was created for
+    pub operator_pool: Pubkey,
+
+    // Epoch this create staking record event instruction was executed for
+    pub epoch: u64,
+
+    // Staking record that was created
+    pub staking_record: Pubkey,
+
+    // Owner of the staking record
+    pub owner: Pubkey,
+}
+
+impl CreateStakingRecordEvent {
+    /// Schema version of CreateStakingRecordEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
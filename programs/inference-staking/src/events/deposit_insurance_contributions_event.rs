@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct DepositInsuranceContributionsEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this deposit insurance contributions event instruction was executed for
+    pub epoch: u64,
+
+    // Amount of reward token commission deposited
+    pub token_amount: u64,
+
+    // Amount of USDC commission deposited
+    pub usdc_amount: u64,
+}
+
+impl DepositInsuranceContributionsEvent {
+    /// Schema version of DepositInsuranceContributionsEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct DistributeOperatorRewardCommissionEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that reward commission was distributed from
+    pub operator_pool: Pubkey,
+
+    // Epoch this distribute operator reward commission event instruction was executed for
+    pub epoch: u64,
+
+    // Destination token accounts, in commission recipient order
+    pub destinations: Vec<Pubkey>,
+
+    // Amount sent to each destination, in commission recipient order
+    pub amounts: Vec<u64>,
+
+    // Total amount of reward commission distributed
+    pub reward_amount_distributed: u64,
+}
+
+impl DistributeOperatorRewardCommissionEvent {
+    /// Schema version of DistributeOperatorRewardCommissionEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
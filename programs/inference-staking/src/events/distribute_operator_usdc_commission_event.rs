@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct DistributeOperatorUsdcCommissionEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that USDC commission was distributed from
+    pub operator_pool: Pubkey,
+
+    // Epoch this distribute operator USDC commission event instruction was executed for
+    pub epoch: u64,
+
+    // Destination token accounts, in commission recipient order
+    pub destinations: Vec<Pubkey>,
+
+    // Amount sent to each destination, in commission recipient order
+    pub amounts: Vec<u64>,
+
+    // Total amount of USDC commission distributed
+    pub usdc_amount_distributed: u64,
+}
+
+impl DistributeOperatorUsdcCommissionEvent {
+    /// Schema version of DistributeOperatorUsdcCommissionEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
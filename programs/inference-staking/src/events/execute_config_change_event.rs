@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PoolOverviewConfig;
+
+#[event]
+pub struct ExecuteConfigChangeEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this execute config change event instruction was executed for
+    pub epoch: u64,
+
+    // Pending config change that was executed
+    pub pending_config_change: Pubkey,
+
+    // Id of the config change
+    pub config_change_id: u64,
+
+    // Settings of the pool overview before the config change
+    pub old_config: PoolOverviewConfig,
+
+    // Settings of the pool overview after the config change
+    pub new_config: PoolOverviewConfig,
+}
+
+impl ExecuteConfigChangeEvent {
+    /// Schema version of ExecuteConfigChangeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
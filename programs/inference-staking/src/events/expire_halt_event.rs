@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ExpireHaltEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that had its halt lifted
+    pub operator_pool: Pubkey,
+
+    // Epoch this expire halt event instruction was executed for
+    pub epoch: u64,
+
+    // Timestamp when the pool was halted
+    pub halted_at: i64,
+
+    // Reason code of the expired halt
+    pub reason_code: u8,
+
+    // Hash of the evidence for the expired halt
+    pub evidence_hash: [u8; 32],
+}
+
+impl ExpireHaltEvent {
+    /// Schema version of ExpireHaltEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct InitiateWindDownEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this initiate wind down event instruction was executed for
+    pub epoch: u64,
+
+    // Program admin that initiated the wind-down
+    pub program_admin: Pubkey,
+
+    // Last epoch that rewards are distributed for
+    pub final_epoch: u64,
+
+    // Timestamp at which the wind-down was initiated
+    pub wind_down_at: i64,
+}
+
+impl InitiateWindDownEvent {
+    /// Schema version of InitiateWindDownEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MarkEpochAsFinalizingEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch that was marked as finalizing
+    pub epoch: u64,
+
+    // Epoch authority that marked the epoch as finalizing
+    pub authority: Pubkey,
+}
+
+impl MarkEpochAsFinalizingEvent {
+    /// Schema version of MarkEpochAsFinalizingEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateOperatorPoolEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool this migrate operator pool event instruction was executed for
+    pub operator_pool: Pubkey,
+
+    // Epoch this migrate operator pool event instruction was executed for
+    pub epoch: u64,
+
+    // Payer of the rent for the additional account space
+    pub payer: Pubkey,
+
+    // Account version before the migration
+    pub from_version: u8,
+
+    // Account version after the migration
+    pub to_version: u8,
+}
+
+impl MigrateOperatorPoolEvent {
+    /// Schema version of MigrateOperatorPoolEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
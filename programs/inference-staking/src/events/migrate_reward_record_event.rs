@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateRewardRecordEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this migrate reward record event instruction was executed for
+    pub epoch: u64,
+
+    // Reward record that was migrated
+    pub reward_record: Pubkey,
+
+    // Epoch of the reward record that was migrated
+    pub reward_record_epoch: u64,
+
+    // Payer of the rent for the additional account space
+    pub payer: Pubkey,
+
+    // Account version before the migration
+    pub from_version: u8,
+
+    // Account version after the migration
+    pub to_version: u8,
+}
+
+impl MigrateRewardRecordEvent {
+    /// Schema version of MigrateRewardRecordEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateStakingRecordEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool this migrate staking record event instruction was executed for
+    pub operator_pool: Pubkey,
+
+    // Epoch this migrate staking record event instruction was executed for
+    pub epoch: u64,
+
+    // Staking record that was migrated
+    pub staking_record: Pubkey,
+
+    // Owner of the staking record
+    pub owner: Pubkey,
+
+    // Payer of the rent for the additional account space
+    pub payer: Pubkey,
+
+    // Account version before the migration
+    pub from_version: u8,
+
+    // Account version after the migration
+    pub to_version: u8,
+}
+
+impl MigrateStakingRecordEvent {
+    /// Schema version of MigrateStakingRecordEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
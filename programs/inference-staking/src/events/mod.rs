@@ -1,31 +1,117 @@
+pub mod accept_program_admin_event;
+pub mod accrue_reward_emergency_bypass_event;
 pub mod accrue_reward_event;
+pub mod add_reward_mint_event;
+pub mod approve_insurance_claim_event;
+pub mod approve_pending_action_event;
+pub mod approve_slash_proposal_event;
+pub mod cancel_config_change_event;
+pub mod cancel_program_admin_transfer_event;
 pub mod cancel_unstake_event;
 pub mod change_operator_admin_event;
 pub mod change_operator_staking_record_event;
+pub mod claim_insurance_event;
+pub mod claim_referral_earnings_event;
+pub mod claim_reward_mint_earnings_event;
 pub mod claim_unstake_event;
 pub mod claim_usdc_earnings_event;
+pub mod close_operator_pool_event;
+pub mod close_staking_record_event;
+pub mod create_admin_audit_log_event;
+pub mod create_insurance_claim_event;
+pub mod create_insurance_fund_event;
+pub mod create_operator_pool_event;
+pub mod create_pending_action_event;
+pub mod create_pool_overview_event;
+pub mod create_referral_record_event;
+pub mod create_reward_record_event;
+pub mod create_slash_proposal_event;
+pub mod create_staking_record_event;
+pub mod deposit_insurance_contributions_event;
+pub mod distribute_operator_reward_commission_event;
+pub mod distribute_operator_usdc_commission_event;
+pub mod execute_config_change_event;
+pub mod expire_halt_event;
+pub mod initiate_wind_down_event;
+pub mod mark_epoch_as_finalizing_event;
+pub mod migrate_operator_pool_event;
+pub mod migrate_reward_record_event;
+pub mod migrate_staking_record_event;
 pub mod operator_auto_stake_event;
+pub mod propose_program_admin_event;
+pub mod queue_config_change_event;
+pub mod release_slash_escrow_event;
+pub mod report_equivocation_event;
+pub mod reverse_slash_event;
 pub mod set_halt_status_event;
+pub mod set_instruction_pause_event;
 pub mod slash_stake_event;
 pub mod stake_event;
 pub mod sweep_closed_pool_usdc_dust_event;
+pub mod tombstone_operator_key_event;
 pub mod unstake_event;
 pub mod update_operator_pool_event;
+pub mod update_pool_overview_authorities_event;
+pub mod update_pool_overview_event;
+pub mod wind_down_staking_record_event;
 pub mod withdraw_operator_reward_commission_event;
 pub mod withdraw_operator_usdc_commission_event;
 
+pub use accept_program_admin_event::*;
+pub use accrue_reward_emergency_bypass_event::*;
 pub use accrue_reward_event::*;
+pub use add_reward_mint_event::*;
+pub use approve_insurance_claim_event::*;
+pub use approve_pending_action_event::*;
+pub use approve_slash_proposal_event::*;
+pub use cancel_config_change_event::*;
+pub use cancel_program_admin_transfer_event::*;
 pub use cancel_unstake_event::*;
 pub use change_operator_admin_event::*;
 pub use change_operator_staking_record_event::*;
+pub use claim_insurance_event::*;
+pub use claim_referral_earnings_event::*;
+pub use claim_reward_mint_earnings_event::*;
 pub use claim_unstake_event::*;
 pub use claim_usdc_earnings_event::*;
+pub use close_operator_pool_event::*;
+pub use close_staking_record_event::*;
+pub use create_admin_audit_log_event::*;
+pub use create_insurance_claim_event::*;
+pub use create_insurance_fund_event::*;
+pub use create_operator_pool_event::*;
+pub use create_pending_action_event::*;
+pub use create_pool_overview_event::*;
+pub use create_referral_record_event::*;
+pub use create_reward_record_event::*;
+pub use create_slash_proposal_event::*;
+pub use create_staking_record_event::*;
+pub use deposit_insurance_contributions_event::*;
+pub use distribute_operator_reward_commission_event::*;
+pub use distribute_operator_usdc_commission_event::*;
+pub use execute_config_change_event::*;
+pub use expire_halt_event::*;
+pub use initiate_wind_down_event::*;
+pub use mark_epoch_as_finalizing_event::*;
+pub use migrate_operator_pool_event::*;
+pub use migrate_reward_record_event::*;
+pub use migrate_staking_record_event::*;
 pub use operator_auto_stake_event::*;
+pub use propose_program_admin_event::*;
+pub use queue_config_change_event::*;
+pub use release_slash_escrow_event::*;
+pub use report_equivocation_event::*;
+pub use reverse_slash_event::*;
 pub use set_halt_status_event::*;
+pub use set_instruction_pause_event::*;
 pub use slash_stake_event::*;
 pub use stake_event::*;
 pub use sweep_closed_pool_usdc_dust_event::*;
+pub use tombstone_operator_key_event::*;
 pub use unstake_event::*;
 pub use update_operator_pool_event::*;
+pub use update_pool_overview_authorities_event::*;
+pub use update_pool_overview_event::*;
+pub use wind_down_staking_record_event::*;
 pub use withdraw_operator_reward_commission_event::*;
 pub use withdraw_operator_usdc_commission_event::*;
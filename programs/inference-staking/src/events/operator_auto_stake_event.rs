@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct OperatorAutoStakeEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -26,3 +29,8 @@ pub struct OperatorAutoStakeEvent {
     // Number of shares created from the stake
     pub shares_amount: u64,
 }
+
+impl OperatorAutoStakeEvent {
+    /// Schema version of OperatorAutoStakeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ProposeProgramAdminEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this propose program admin event instruction was executed for
+    pub epoch: u64,
+
+    // Program admin that proposed the transfer
+    pub program_admin: Pubkey,
+
+    // Proposed program admin, pending acceptance
+    pub pending_program_admin: Pubkey,
+}
+
+impl ProposeProgramAdminEvent {
+    /// Schema version of ProposeProgramAdminEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
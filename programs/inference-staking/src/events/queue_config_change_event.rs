@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::UpdatePoolOverviewArgs;
+
+#[event]
+pub struct QueueConfigChangeEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this queue config change event instruction was executed for
+    pub epoch: u64,
+
+    // Pending config change that was queued
+    pub pending_config_change: Pubkey,
+
+    // Id of the config change
+    pub config_change_id: u64,
+
+    // Settings to change on the pool overview
+    pub args: UpdatePoolOverviewArgs,
+
+    // New wallet that receives the operator pool registration fees, if changed
+    pub registration_fee_payout_wallet: Option<Pubkey>,
+
+    // New destination account for slashed USDC, if changed
+    pub slashing_destination_usdc_account: Option<Pubkey>,
+
+    // New destination account for slashed tokens, if changed
+    pub slashing_destination_token_account: Option<Pubkey>,
+
+    // Timestamp from which the config change can be executed
+    pub execute_at: i64,
+}
+
+impl QueueConfigChangeEvent {
+    /// Schema version of QueueConfigChangeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
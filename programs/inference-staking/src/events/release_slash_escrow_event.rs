@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ReleaseSlashEscrowEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that was slashed
+    pub operator_pool: Pubkey,
+
+    // Epoch this release slash escrow event instruction was executed for
+    pub epoch: u64,
+
+    // Slash escrow that funds were released from
+    pub slash_escrow: Pubkey,
+
+    // Destination account for slashed tokens
+    pub destination: Pubkey,
+
+    // Destination account for slashed USDC
+    pub destination_usdc: Pubkey,
+
+    // Amount of tokens released to the slashing destination
+    pub token_amount: u64,
+
+    // Amount of USDC released to the slashing destination
+    pub usdc_amount: u64,
+
+    // Amount of tokens redistributed to the pool's non-operator delegators
+    pub redistribution_amount: u64,
+
+    // Operator shares removed so that the redistribution only accrues to delegators
+    pub operator_shares_removed: u64,
+
+    // Amount of tokens burned
+    pub burn_amount: u64,
+
+    // Amount of tokens deposited into the insurance fund
+    pub insurance_token_amount: u64,
+
+    // Amount of USDC deposited into the insurance fund
+    pub insurance_usdc_amount: u64,
+}
+
+impl ReleaseSlashEscrowEvent {
+    /// Schema version of ReleaseSlashEscrowEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
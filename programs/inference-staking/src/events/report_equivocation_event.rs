@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ReportEquivocationEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that was halted
+    pub operator_pool: Pubkey,
+
+    // Epoch this report equivocation event instruction was executed for
+    pub epoch: u64,
+
+    // Account that reported the equivocation
+    pub reporter: Pubkey,
+
+    // Operator auth key that signed the conflicting attestations
+    pub auth_key: Pubkey,
+
+    // Job or epoch the conflicting attestations were for
+    pub subject_id: [u8; 32],
+
+    // Result hash of the first attestation
+    pub first_result_hash: [u8; 32],
+
+    // Result hash of the second attestation
+    pub second_result_hash: [u8; 32],
+
+    // Hash of both attestation messages, stored as the halt evidence hash
+    pub evidence_hash: [u8; 32],
+}
+
+impl ReportEquivocationEvent {
+    /// Schema version of ReportEquivocationEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ReverseSlashEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool that the slash was reversed for
+    pub operator_pool: Pubkey,
+
+    // Epoch this reverse slash event instruction was executed for
+    pub epoch: u64,
+
+    // Slash escrow that funds were returned from
+    pub slash_escrow: Pubkey,
+
+    // Operator staking record that shares were restored to
+    pub operator_staking_record: Pubkey,
+
+    // Amount of shares restored to the operator staking record
+    pub shares_restored: u64,
+
+    // Amount of operator tokens restored as shares
+    pub token_amount_restored: u64,
+
+    // Amount of tokens restored to the whole pool
+    pub pool_token_amount_restored: u64,
+
+    // Amount of tokens restored to the pool's pending unstakes
+    pub unstaking_token_amount_restored: u64,
+
+    // Amount of USDC earnings restored to the operator staking record
+    pub usdc_restored: u64,
+
+    // Amount of reward commission restored
+    pub reward_commission_restored: u64,
+
+    // Amount of USDC commission restored
+    pub usdc_commission_restored: u64,
+}
+
+impl ReverseSlashEvent {
+    /// Schema version of ReverseSlashEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct SetHaltStatusEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -13,4 +16,18 @@ pub struct SetHaltStatusEvent {
 
     // Whether the pool is now halted
     pub is_halted: bool,
+
+    // Reason code for the halt
+    pub reason_code: u8,
+
+    // Hash of the evidence for the halt
+    pub evidence_hash: [u8; 32],
+
+    // Timestamp after which the halt can be lifted permissionlessly, if set
+    pub expires_at: Option<i64>,
+}
+
+impl SetHaltStatusEvent {
+    /// Schema version of SetHaltStatusEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SetInstructionPauseEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this set instruction pause event instruction was executed for
+    pub epoch: u64,
+
+    // Program admin or halt authority that set the pause
+    pub authority: Pubkey,
+
+    // Bitmask of the instructions that were paused or unpaused
+    pub instructions_mask: u64,
+
+    // Whether the instructions were paused
+    pub is_paused: bool,
+
+    // Timestamp after which the pause lapses, if set
+    pub expires_at: Option<i64>,
+
+    // Bitmask of all paused instructions after the update
+    pub paused_instructions: u64,
+}
+
+impl SetInstructionPauseEvent {
+    /// Schema version of SetInstructionPauseEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 
+use crate::state::ConfiscationPolicy;
+
 #[event]
 pub struct SlashStakeEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -17,10 +22,10 @@ pub struct SlashStakeEvent {
     // Authority that executed the slash
     pub authority: Pubkey,
 
-    // Destination account for slashed tokens and reward commission
+    // Escrow account for slashed tokens and reward commission
     pub destination: Pubkey,
 
-    // Destination account for slashed USDC
+    // Escrow account for slashed USDC
     pub destination_usdc: Pubkey,
 
     // Amount of shares slashed
@@ -37,4 +42,64 @@ pub struct SlashStakeEvent {
 
     // Amount of USDC commission confiscated
     pub usdc_commission_confiscated: u64,
+
+    // Percentage of the pool's total staked amount slashed, in basis points
+    pub pool_slash_bps: u16,
+
+    // Amount of tokens slashed from the whole pool, lowering the value of every share
+    pub pool_token_amount_slashed: u64,
+
+    // Percentage of pending unstakes that are still inside their delay slashed, in basis points
+    pub unstaking_slash_bps: u16,
+
+    // Amount of tokens being unstaked that were still inside their delay at the time of the
+    // slash. Delegator unstakes are reduced as they are settled.
+    pub unstaking_in_delay_at_slash: u64,
+
+    // Amount of tokens slashed from pending unstakes, including the operator's
+    pub unstaking_token_amount_slashed: u64,
+
+    // Amount of tokens slashed from the operator's pending unstake, applied when it is settled
+    pub operator_unstaking_slashed: u64,
+
+    // Confiscation policy applied to the operator's accrued USDC earnings
+    pub usdc_earnings_confiscation: ConfiscationPolicy,
+
+    // Confiscation policy applied to the reward commission fee vault
+    pub reward_commission_confiscation: ConfiscationPolicy,
+
+    // Confiscation policy applied to the USDC commission fee vault
+    pub usdc_commission_confiscation: ConfiscationPolicy,
+
+    // Slash proposal that was executed
+    pub slash_proposal: Pubkey,
+
+    // Hash of the evidence recorded on the slash proposal
+    pub evidence_hash: [u8; 32],
+
+    // Slash escrow holding the slashed funds during the appeal period
+    pub slash_escrow: Pubkey,
+
+    // Timestamp after which the escrowed funds can be released
+    pub release_at: i64,
+
+    // Amount of slashed tokens to be redistributed to non-operator delegators on release
+    pub redistribution_amount: u64,
+
+    // Amount of slashed tokens to be burned on release
+    pub burn_amount: u64,
+
+    // Amount of slashed tokens to be sent to the slashing destination on release
+    pub treasury_token_amount: u64,
+
+    // Amount of slashed tokens to be deposited into the insurance fund on release
+    pub insurance_token_amount: u64,
+
+    // Amount of confiscated USDC to be deposited into the insurance fund on release
+    pub insurance_usdc_amount: u64,
+}
+
+impl SlashStakeEvent {
+    /// Schema version of SlashStakeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
 }
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct StakeEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -25,4 +28,12 @@ pub struct StakeEvent {
 
     // Number of shares created from the stake
     pub shares_amount: u64,
+
+    // Referrer recorded on the staking record, if any
+    pub referrer: Option<Pubkey>,
+}
+
+impl StakeEvent {
+    /// Schema version of StakeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
 }
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct SweepClosedPoolUsdcDustEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -17,3 +20,8 @@ pub struct SweepClosedPoolUsdcDustEvent {
     // Amount of USDC dust swept
     pub usdc_amount_swept: u64,
 }
+
+impl SweepClosedPoolUsdcDustEvent {
+    /// Schema version of SweepClosedPoolUsdcDustEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
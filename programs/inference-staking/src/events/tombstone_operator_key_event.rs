@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TombstoneOperatorKeyEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool the banned key belongs to
+    pub operator_pool: Pubkey,
+
+    // Epoch this tombstone operator key event instruction was executed for
+    pub epoch: u64,
+
+    // Tombstone account that was created
+    pub tombstone: Pubkey,
+
+    // Key that was banned
+    pub key: Pubkey,
+
+    // Whether the banned key is the pool admin, otherwise it is an auth key
+    pub is_admin: bool,
+
+    // Slashing authority that banned the key
+    pub authority: Pubkey,
+
+    // Approved slash proposal that the ban is based on
+    pub slash_proposal: Pubkey,
+}
+
+impl TombstoneOperatorKeyEvent {
+    /// Schema version of TombstoneOperatorKeyEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
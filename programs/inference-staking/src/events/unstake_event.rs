@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct UnstakeEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -29,3 +32,8 @@ pub struct UnstakeEvent {
     // Timestamp when the unstake can be claimed
     pub unstake_at_timestamp: i64,
 }
+
+impl UnstakeEvent {
+    /// Schema version of UnstakeEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
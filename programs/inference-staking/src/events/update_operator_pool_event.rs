@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct UpdateOperatorPoolEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -11,3 +14,8 @@ pub struct UpdateOperatorPoolEvent {
     // Epoch this update operator pool event instruction was executed for
     pub epoch: u64,
 }
+
+impl UpdateOperatorPoolEvent {
+    /// Schema version of UpdateOperatorPoolEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UpdatePoolOverviewAuthoritiesEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this update pool overview authorities event instruction was executed for
+    pub epoch: u64,
+
+    // Reward distribution authorities before the update
+    pub old_reward_distribution_authorities: Vec<Pubkey>,
+
+    // Reward distribution authorities after the update
+    pub new_reward_distribution_authorities: Vec<Pubkey>,
+
+    // Halt authorities before the update
+    pub old_halt_authorities: Vec<Pubkey>,
+
+    // Halt authorities after the update
+    pub new_halt_authorities: Vec<Pubkey>,
+
+    // Slashing authorities before the update
+    pub old_slashing_authorities: Vec<Pubkey>,
+
+    // Slashing authorities after the update
+    pub new_slashing_authorities: Vec<Pubkey>,
+
+    // Epoch authorities before the update
+    pub old_epoch_authorities: Vec<Pubkey>,
+
+    // Epoch authorities after the update
+    pub new_epoch_authorities: Vec<Pubkey>,
+}
+
+impl UpdatePoolOverviewAuthoritiesEvent {
+    /// Schema version of UpdatePoolOverviewAuthoritiesEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
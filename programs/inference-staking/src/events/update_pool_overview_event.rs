@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PoolOverviewConfig;
+
+#[event]
+pub struct UpdatePoolOverviewEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Epoch this update pool overview event instruction was executed for
+    pub epoch: u64,
+
+    // Settings of the pool overview before the update
+    pub old_config: PoolOverviewConfig,
+
+    // Settings of the pool overview after the update
+    pub new_config: PoolOverviewConfig,
+}
+
+impl UpdatePoolOverviewEvent {
+    /// Schema version of UpdatePoolOverviewEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct WindDownStakingRecordEvent {
+    // Schema version of this event
+    pub version: u8,
+
+    // Instruction index this event was emitted in
+    pub instruction_index: u16,
+
+    // Operator pool this wind down staking record event instruction was executed for
+    pub operator_pool: Pubkey,
+
+    // Epoch this wind down staking record event instruction was executed for
+    pub epoch: u64,
+
+    // Staking record that was wound down
+    pub staking_record: Pubkey,
+
+    // Owner of the staking record
+    pub owner: Pubkey,
+
+    // Whether the staking record is the operator's
+    pub is_operator: bool,
+
+    // Amount of shares unstaked
+    pub shares_amount: u64,
+
+    // Amount of tokens transferred to the owner, including previously unstaked tokens
+    pub token_amount: u64,
+
+    // Amount of USDC earnings transferred to the owner
+    pub usdc_amount: u64,
+
+    // Amount of each additional reward mint transferred to the owner, in PoolOverview order
+    pub reward_mint_amounts: Vec<u64>,
+}
+
+impl WindDownStakingRecordEvent {
+    /// Schema version of WindDownStakingRecordEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct WithdrawOperatorRewardCommissionEvent {
+    // Schema version of this event
+    pub version: u8,
+
     // Instruction index this event was emitted in
     pub instruction_index: u16,
 
@@ -20,3 +23,8 @@ pub struct WithdrawOperatorRewardCommissionEvent {
     // Amount of reward commission withdrawn
     pub reward_amount_withdrawn: u64,
 }
+
+impl WithdrawOperatorRewardCommissionEvent {
+    /// Schema version of WithdrawOperatorRewardCommissionEvent. Incremented when its fields change.
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::CreateAdminAuditLogEvent,
+    state::{AdminAuditLog, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateAdminAuditLog<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        init,
+        seeds = [AdminAuditLog::SEED],
+        bump,
+        payer = payer,
+        space = 8 + AdminAuditLog::INIT_SPACE + AdminAuditLog::PADDING
+    )]
+    pub admin_audit_log: Box<Account<'info, AdminAuditLog>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to setup the AdminAuditLog singleton. Admin and authority actions must be recorded
+/// in it from then on.
+pub fn handler(ctx: Context<CreateAdminAuditLog>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreateAdminAuditLog)?;
+
+    let admin_audit_log = &mut ctx.accounts.admin_audit_log;
+    admin_audit_log.version = AdminAuditLog::VERSION;
+    admin_audit_log.bump = ctx.bumps.admin_audit_log;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    pool_overview.is_admin_audit_log_enabled = true;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreateAdminAuditLogEvent {
+        version: CreateAdminAuditLogEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        admin_audit_log: admin_audit_log.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod create_admin_audit_log;
+
+pub use create_admin_audit_log::*;
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::ApproveInsuranceClaimEvent,
+    state::{AdminActionKind, AdminAuditLog, InsuranceClaim, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveInsuranceClaim<'info> {
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceClaim::SEED, &insurance_claim.id.to_le_bytes()],
+        bump = insurance_claim.bump,
+    )]
+    pub insurance_claim: Box<Account<'info, InsuranceClaim>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ApproveInsuranceClaimArgs {
+    /// Amount of tokens to pay out. Cannot exceed the requested amount.
+    pub token_amount: u64,
+
+    /// Amount of USDC to pay out. Cannot exceed the requested amount.
+    pub usdc_amount: u64,
+}
+
+/// Instruction for the program admin to approve an InsuranceClaim. Approving zero amounts
+/// effectively rejects the claim.
+pub fn handler(ctx: Context<ApproveInsuranceClaim>, args: ApproveInsuranceClaimArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ApproveInsuranceClaim)?;
+
+    let args_hash = AdminAuditLog::hash_args(&(ctx.accounts.insurance_claim.key(), &args));
+    let ApproveInsuranceClaimArgs {
+        token_amount,
+        usdc_amount,
+    } = args;
+
+    let insurance_claim = &mut ctx.accounts.insurance_claim;
+    require!(
+        insurance_claim.approved_at.is_none(),
+        ErrorCode::InsuranceClaimAlreadyApproved
+    );
+    require_gte!(
+        insurance_claim.requested_token_amount,
+        token_amount,
+        ErrorCode::InsuranceClaimAmountExceeded
+    );
+    require_gte!(
+        insurance_claim.requested_usdc_amount,
+        usdc_amount,
+        ErrorCode::InsuranceClaimAmountExceeded
+    );
+
+    insurance_claim.approved_token_amount = token_amount;
+    insurance_claim.approved_usdc_amount = usdc_amount;
+    insurance_claim.approved_at = Some(Clock::get()?.unix_timestamp);
+
+    ctx.accounts.pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::ApproveInsuranceClaim,
+        args_hash,
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ApproveInsuranceClaimEvent {
+        version: ApproveInsuranceClaimEvent::VERSION,
+        instruction_index,
+        operator_pool: insurance_claim.operator_pool,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        insurance_claim: insurance_claim.key(),
+        claim_id: insurance_claim.id,
+        token_amount,
+        usdc_amount,
+    });
+
+    Ok(())
+}
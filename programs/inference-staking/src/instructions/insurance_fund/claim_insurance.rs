@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    constants::USDC_MINT_PUBKEY,
+    error::ErrorCode,
+    events::ClaimInsuranceEvent,
+    state::{InsuranceClaim, InsuranceFund, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimInsurance<'info> {
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::SEED],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceClaim::SEED, &insurance_claim.id.to_le_bytes()],
+        bump = insurance_claim.bump,
+        has_one = claimant,
+    )]
+    pub insurance_claim: Box<Account<'info, InsuranceClaim>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::INSURANCE_TOKEN_VAULT_SEED],
+        bump,
+    )]
+    pub insurance_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::INSURANCE_USDC_VAULT_SEED],
+        bump,
+    )]
+    pub insurance_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    /// Destination account for the token payout.
+    #[account(
+        mut,
+        token::mint = pool_overview.mint,
+    )]
+    pub token_destination: Box<Account<'info, TokenAccount>>,
+
+    /// Destination account for the USDC payout. Must be a USDC token account.
+    #[account(
+        mut,
+        constraint = usdc_destination.mint == USDC_MINT_PUBKEY @ ErrorCode::InvalidUsdcMint,
+    )]
+    pub usdc_destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction for a claimant to receive the payout of an approved InsuranceClaim.
+pub fn handler(ctx: Context<ClaimInsurance>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ClaimInsurance)?;
+
+    let insurance_claim = &mut ctx.accounts.insurance_claim;
+
+    // Check that global withdrawal has not been halted.
+    require!(
+        !ctx.accounts.pool_overview.is_withdrawal_halted,
+        ErrorCode::WithdrawalsHalted
+    );
+    require!(
+        insurance_claim.approved_at.is_some(),
+        ErrorCode::InsuranceClaimNotApproved
+    );
+    require!(
+        insurance_claim.paid_at.is_none(),
+        ErrorCode::InsuranceClaimAlreadyPaid
+    );
+
+    let token_amount = insurance_claim.approved_token_amount;
+    let usdc_amount = insurance_claim.approved_usdc_amount;
+    require_gte!(
+        ctx.accounts.insurance_token_account.amount,
+        token_amount,
+        ErrorCode::InsufficientInsuranceFundBalance
+    );
+    require_gte!(
+        ctx.accounts.insurance_usdc_account.amount,
+        usdc_amount,
+        ErrorCode::InsufficientInsuranceFundBalance
+    );
+
+    let pool_overview_bump = ctx.accounts.pool_overview.bump;
+
+    if token_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.insurance_token_account.to_account_info(),
+                    to: ctx.accounts.token_destination.to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[&[PoolOverview::SEED, &[pool_overview_bump]]],
+            ),
+            token_amount,
+        )?;
+    }
+
+    if usdc_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.insurance_usdc_account.to_account_info(),
+                    to: ctx.accounts.usdc_destination.to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[&[PoolOverview::SEED, &[pool_overview_bump]]],
+            ),
+            usdc_amount,
+        )?;
+    }
+
+    insurance_claim.paid_at = Some(Clock::get()?.unix_timestamp);
+
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.total_token_paid = insurance_fund
+        .total_token_paid
+        .checked_add(token_amount)
+        .unwrap();
+    insurance_fund.total_usdc_paid = insurance_fund
+        .total_usdc_paid
+        .checked_add(usdc_amount)
+        .unwrap();
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ClaimInsuranceEvent {
+        version: ClaimInsuranceEvent::VERSION,
+        instruction_index,
+        operator_pool: insurance_claim.operator_pool,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        insurance_claim: insurance_claim.key(),
+        claim_id: insurance_claim.id,
+        claimant: insurance_claim.claimant,
+        token_amount,
+        usdc_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::CreateInsuranceClaimEvent,
+    state::{
+        InsuranceClaim, InsuranceFund, OperatorPool, PausableInstruction, PoolOverview,
+        StakingRecord,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateInsuranceClaim<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::SEED],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        seeds = [
+            StakingRecord::SEED,
+            operator_pool.key().as_ref(),
+            claimant.key().as_ref()
+        ],
+        bump,
+        has_one = operator_pool,
+        constraint = staking_record.key() != operator_pool.operator_staking_record
+          @ ErrorCode::OperatorInsuranceClaim,
+        constraint = staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub staking_record: Box<Account<'info, StakingRecord>>,
+
+    #[account(
+        init,
+        seeds = [InsuranceClaim::SEED, &insurance_fund.claim_count.to_le_bytes()],
+        bump,
+        payer = payer,
+        space = 8 + InsuranceClaim::INIT_SPACE + InsuranceClaim::PADDING
+    )]
+    pub insurance_claim: Box<Account<'info, InsuranceClaim>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateInsuranceClaimArgs {
+    /// Hash of the off-chain description of the loss.
+    pub evidence_hash: [u8; 32],
+
+    /// Amount of tokens requested from the InsuranceFund.
+    pub token_amount: u64,
+
+    /// Amount of USDC requested from the InsuranceFund.
+    pub usdc_amount: u64,
+}
+
+/// Instruction for a delegator to file a claim against the InsuranceFund for losses in an
+/// OperatorPool, e.g. from a pool-wide slash or an Operator insolvency event.
+pub fn handler(ctx: Context<CreateInsuranceClaim>, args: CreateInsuranceClaimArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreateInsuranceClaim)?;
+
+    let CreateInsuranceClaimArgs {
+        evidence_hash,
+        token_amount,
+        usdc_amount,
+    } = args;
+
+    require!(
+        token_amount > 0 || usdc_amount > 0,
+        ErrorCode::InvalidAmount
+    );
+
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    let insurance_claim = &mut ctx.accounts.insurance_claim;
+    insurance_claim.version = InsuranceClaim::VERSION;
+    insurance_claim.bump = ctx.bumps.insurance_claim;
+    insurance_claim.id = insurance_fund.claim_count;
+    insurance_claim.claimant = ctx.accounts.claimant.key();
+    insurance_claim.operator_pool = ctx.accounts.operator_pool.key();
+    insurance_claim.staking_record = ctx.accounts.staking_record.key();
+    insurance_claim.evidence_hash = evidence_hash;
+    insurance_claim.requested_token_amount = token_amount;
+    insurance_claim.requested_usdc_amount = usdc_amount;
+    insurance_claim.created_at = Clock::get()?.unix_timestamp;
+
+    insurance_fund.claim_count = insurance_fund.claim_count.checked_add(1).unwrap();
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreateInsuranceClaimEvent {
+        version: CreateInsuranceClaimEvent::VERSION,
+        instruction_index,
+        operator_pool: insurance_claim.operator_pool,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        insurance_claim: insurance_claim.key(),
+        claim_id: insurance_claim.id,
+        claimant: insurance_claim.claimant,
+        staking_record: insurance_claim.staking_record,
+        evidence_hash,
+        token_amount,
+        usdc_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    constants::USDC_MINT_PUBKEY,
+    error::ErrorCode,
+    events::CreateInsuranceFundEvent,
+    state::{InsuranceFund, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateInsuranceFund<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin,
+        has_one = mint,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        init,
+        seeds = [InsuranceFund::SEED],
+        bump,
+        payer = payer,
+        space = 8 + InsuranceFund::INIT_SPACE + InsuranceFund::PADDING
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        init,
+        seeds = [InsuranceFund::INSURANCE_TOKEN_VAULT_SEED],
+        bump,
+        payer = payer,
+        token::mint = mint,
+        token::authority = pool_overview
+    )]
+    pub insurance_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        seeds = [InsuranceFund::INSURANCE_USDC_VAULT_SEED],
+        bump,
+        payer = payer,
+        token::mint = usdc_mint,
+        token::authority = pool_overview
+    )]
+    pub insurance_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT_PUBKEY @ ErrorCode::InvalidUsdcMint
+    )]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to setup the InsuranceFund singleton and its vaults.
+pub fn handler(ctx: Context<CreateInsuranceFund>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreateInsuranceFund)?;
+
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.version = InsuranceFund::VERSION;
+    insurance_fund.bump = ctx.bumps.insurance_fund;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreateInsuranceFundEvent {
+        version: CreateInsuranceFundEvent::VERSION,
+        instruction_index,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        insurance_fund: insurance_fund.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    error::ErrorCode,
+    events::DepositInsuranceContributionsEvent,
+    state::{InsuranceFund, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositInsuranceContributions<'info> {
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::SEED],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::GLOBAL_TOKEN_REWARD_VAULT_SEED],
+        bump,
+    )]
+    pub reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::GLOBAL_USDC_EARNINGS_VAULT_SEED],
+        bump,
+    )]
+    pub usdc_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::INSURANCE_TOKEN_VAULT_SEED],
+        bump,
+    )]
+    pub insurance_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::INSURANCE_USDC_VAULT_SEED],
+        bump,
+    )]
+    pub insurance_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to move commissions set aside for the InsuranceFund from the global vaults into the
+/// InsuranceFund vaults. Permissionless.
+pub fn handler(ctx: Context<DepositInsuranceContributions>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::DepositInsuranceContributions)?;
+
+    let token_amount = ctx.accounts.pool_overview.pending_insurance_rewards;
+    let usdc_amount = ctx.accounts.pool_overview.pending_insurance_usdc;
+    require!(
+        token_amount > 0 || usdc_amount > 0,
+        ErrorCode::NoPendingInsuranceContributions
+    );
+
+    let pool_overview_bump = ctx.accounts.pool_overview.bump;
+
+    if token_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_token_account.to_account_info(),
+                    to: ctx.accounts.insurance_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[&[PoolOverview::SEED, &[pool_overview_bump]]],
+            ),
+            token_amount,
+        )?;
+    }
+
+    if usdc_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.usdc_token_account.to_account_info(),
+                    to: ctx.accounts.insurance_usdc_account.to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[&[PoolOverview::SEED, &[pool_overview_bump]]],
+            ),
+            usdc_amount,
+        )?;
+    }
+
+    // Insurance contributions are held in the global vaults until deposited.
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    pool_overview.unclaimed_rewards = pool_overview
+        .unclaimed_rewards
+        .checked_sub(token_amount)
+        .unwrap();
+    pool_overview.unclaimed_usdc = pool_overview
+        .unclaimed_usdc
+        .checked_sub(usdc_amount)
+        .unwrap();
+    pool_overview.pending_insurance_rewards = 0;
+    pool_overview.pending_insurance_usdc = 0;
+
+    ctx.accounts
+        .insurance_fund
+        .record_deposit(token_amount, usdc_amount);
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(DepositInsuranceContributionsEvent {
+        version: DepositInsuranceContributionsEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        token_amount,
+        usdc_amount,
+    });
+
+    Ok(())
+}
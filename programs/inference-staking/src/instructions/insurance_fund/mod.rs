@@ -0,0 +1,11 @@
+pub mod approve_insurance_claim;
+pub mod claim_insurance;
+pub mod create_insurance_claim;
+pub mod create_insurance_fund;
+pub mod deposit_insurance_contributions;
+
+pub use approve_insurance_claim::*;
+pub use claim_insurance::*;
+pub use create_insurance_claim::*;
+pub use create_insurance_fund::*;
+pub use deposit_insurance_contributions::*;
@@ -2,9 +2,13 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
 use crate::{
-    error::ErrorCode, events::ChangeOperatorAdminEvent, state::OperatorPool, PoolOverview,
+    error::ErrorCode,
+    events::ChangeOperatorAdminEvent,
+    state::{OperatorPool, PausableInstruction, Tombstone},
+    PoolOverview,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ChangeOperatorAdmin<'info> {
     pub admin: Signer<'info>,
@@ -26,15 +30,30 @@ pub struct ChangeOperatorAdmin<'info> {
         ],
         bump = operator_pool.bump,
         has_one = admin,
+        constraint = operator_pool.tombstoned_at.is_none() @ ErrorCode::OperatorPoolTombstoned,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
 
+    /// CHECK: Tombstone PDA of the new admin, which must not have been created.
+    #[account(
+        seeds = [Tombstone::SEED, new_admin.key().as_ref()],
+        bump,
+        constraint = new_admin_tombstone.data_is_empty() @ ErrorCode::KeyTombstoned,
+    )]
+    pub new_admin_tombstone: UncheckedAccount<'info>,
+
     /// CHECK: This is a system account that is used to get the current instruction index.
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<ChangeOperatorAdmin>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ChangeOperatorAdmin)?;
+
     let operator_pool = &mut ctx.accounts.operator_pool;
     let old_admin = operator_pool.admin;
     let new_admin = ctx.accounts.new_admin.key();
@@ -44,7 +63,8 @@ pub fn handler(ctx: Context<ChangeOperatorAdmin>) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(ChangeOperatorAdminEvent {
+    emit_cpi!(ChangeOperatorAdminEvent {
+        version: ChangeOperatorAdminEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool.key(),
         epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
@@ -4,9 +4,10 @@ use anchor_lang::solana_program::sysvar::instructions::load_current_index_checke
 use crate::{
     error::ErrorCode,
     events::ChangeOperatorStakingRecordEvent,
-    state::{OperatorPool, PoolOverview, StakingRecord},
+    state::{OperatorPool, PausableInstruction, PoolOverview, StakingRecord},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ChangeOperatorStakingRecord<'info> {
     /// Admin of the OperatorPool
@@ -29,6 +30,8 @@ pub struct ChangeOperatorStakingRecord<'info> {
         ],
         bump = operator_pool.bump,
         has_one = admin,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
 
@@ -57,6 +60,10 @@ pub struct ChangeOperatorStakingRecord<'info> {
 /// be unable to withdraw their stake because it would move them below the minimum operator share
 /// requirement.
 pub fn handler(ctx: Context<ChangeOperatorStakingRecord>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ChangeOperatorStakingRecord)?;
+
     let min_operator_token_stake = ctx.accounts.pool_overview.min_operator_token_stake;
     let operator_stake = ctx
         .accounts
@@ -68,6 +75,12 @@ pub fn handler(ctx: Context<ChangeOperatorStakingRecord>) -> Result<()> {
         ErrorCode::MinOperatorTokenStakeNotMet
     );
 
+    // Operators cannot be referred to their own pool.
+    require!(
+        ctx.accounts.new_staking_record.referrer.is_none(),
+        ErrorCode::InvalidReferrer
+    );
+
     let operator_pool = &mut ctx.accounts.operator_pool;
     let old_staking_record = operator_pool.operator_staking_record;
     let new_staking_record = ctx.accounts.new_staking_record.key();
@@ -77,7 +90,8 @@ pub fn handler(ctx: Context<ChangeOperatorStakingRecord>) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(ChangeOperatorStakingRecordEvent {
+    emit_cpi!(ChangeOperatorStakingRecordEvent {
+        version: ChangeOperatorStakingRecordEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool.key(),
         epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
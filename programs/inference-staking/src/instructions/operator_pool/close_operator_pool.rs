@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
 use crate::{
     error::ErrorCode,
-    state::{OperatorPool, PoolOverview},
+    events::CloseOperatorPoolEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseOperatorPool<'info> {
     pub admin: Signer<'info>,
@@ -18,11 +21,21 @@ pub struct CloseOperatorPool<'info> {
     #[account(
         mut,
         has_one = admin,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<CloseOperatorPool>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CloseOperatorPool)?;
+
     let pool_overview = &ctx.accounts.pool_overview;
     let operator_pool = &mut ctx.accounts.operator_pool;
 
@@ -45,7 +58,19 @@ pub fn handler(ctx: Context<CloseOperatorPool>) -> Result<()> {
         true => pool_overview.completed_reward_epoch.checked_add(1).unwrap(),
         false => pool_overview.completed_reward_epoch,
     };
-    operator_pool.closed_at_epoch = Some(current_epoch.checked_add(1).unwrap());
+    let closed_at_epoch = current_epoch.checked_add(1).unwrap();
+    operator_pool.closed_at_epoch = Some(closed_at_epoch);
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CloseOperatorPoolEvent {
+        version: CloseOperatorPoolEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: pool_overview.completed_reward_epoch + 1,
+        closed_at_epoch,
+    });
 
     Ok(())
 }
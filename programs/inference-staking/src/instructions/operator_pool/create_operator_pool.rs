@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::{
     constants::USDC_MINT_PUBKEY,
     error::ErrorCode,
-    state::{OperatorPool, StakingRecord},
+    events::CreateOperatorPoolEvent,
+    state::{OperatorPool, PausableInstruction, StakingRecord, Tombstone},
     PoolOverview,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateOperatorPool<'info> {
     #[account(mut)]
@@ -37,7 +40,7 @@ pub struct CreateOperatorPool<'info> {
         ],
         bump,
         payer = payer,
-        space = 8 + StakingRecord::INIT_SPACE
+        space = 8 + StakingRecord::INIT_SPACE + StakingRecord::PADDING
     )]
     pub staking_record: Box<Account<'info, StakingRecord>>,
 
@@ -47,6 +50,7 @@ pub struct CreateOperatorPool<'info> {
         bump = pool_overview.bump,
         has_one = mint,
         constraint = pool_overview.allow_pool_creation @ ErrorCode::PoolCreationDisabled,
+        constraint = !pool_overview.is_winding_down() @ ErrorCode::ProgramWindingDown,
     )]
     pub pool_overview: Box<Account<'info, PoolOverview>>,
 
@@ -115,6 +119,10 @@ pub struct CreateOperatorPool<'info> {
     pub token_program: Program<'info, Token>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -132,6 +140,10 @@ pub struct CreateOperatorPoolArgs {
 
 /// Instruction to setup an OperatorPool.
 pub fn handler(ctx: Context<CreateOperatorPool>, args: CreateOperatorPoolArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreateOperatorPool)?;
+
     let CreateOperatorPoolArgs {
         auto_stake_fees,
         reward_commission_rate_bps,
@@ -144,11 +156,11 @@ pub fn handler(ctx: Context<CreateOperatorPool>, args: CreateOperatorPoolArgs) -
         usdc_commission_rate_bps,
     } = args;
 
-    OperatorPool::validate_commission_rate(reward_commission_rate_bps)?;
-    OperatorPool::validate_commission_rate(usdc_commission_rate_bps)?;
-
     let pool_overview = &mut ctx.accounts.pool_overview;
 
+    pool_overview.validate_commission_rate(reward_commission_rate_bps)?;
+    pool_overview.validate_commission_rate(usdc_commission_rate_bps)?;
+
     // Transfer registration fee if it's set above zero.
     let registration_fee = pool_overview.operator_pool_registration_fee;
     if registration_fee > 0 {
@@ -192,6 +204,12 @@ pub fn handler(ctx: Context<CreateOperatorPool>, args: CreateOperatorPoolArgs) -
         operator_pool.operator_auth_keys = operator_auth_keys;
     }
 
+    // Tombstoned keys cannot operate a new pool. Tombstone PDAs of the admin and each auth key are
+    // passed as remaining accounts, in that order.
+    let mut keys = vec![operator_pool.admin];
+    keys.extend_from_slice(&operator_pool.operator_auth_keys);
+    Tombstone::require_not_tombstoned(&keys, ctx.remaining_accounts)?;
+
     // We derive the "current epoch" like this and use this to initialize the operator
     // pool's joined_at and reward_last_claimed_epoch fields to avoid the edge cases
     // where an operator joins during reward finalization for an epoch and is not included
@@ -208,6 +226,7 @@ pub fn handler(ctx: Context<CreateOperatorPool>, args: CreateOperatorPoolArgs) -
     operator_pool.reward_last_claimed_epoch = current_epoch;
 
     let staking_record = &mut ctx.accounts.staking_record;
+    staking_record.version = StakingRecord::VERSION;
     staking_record.owner = ctx.accounts.admin.key();
     staking_record.operator_pool = operator_pool.key();
     staking_record.last_settled_usdc_per_share = 0;
@@ -215,5 +234,23 @@ pub fn handler(ctx: Context<CreateOperatorPool>, args: CreateOperatorPoolArgs) -
 
     operator_pool.validate_pool_profile_fields()?;
 
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreateOperatorPoolEvent {
+        version: CreateOperatorPoolEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: pool_overview.completed_reward_epoch + 1,
+        admin: operator_pool.admin,
+        operator_staking_record: operator_pool.operator_staking_record,
+        registration_fee,
+        auto_stake_fees,
+        reward_commission_rate_bps,
+        usdc_commission_rate_bps,
+        allow_delegation,
+        operator_auth_keys: operator_pool.operator_auth_keys.clone(),
+    });
+
     Ok(())
 }
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    error::ErrorCode,
+    events::DistributeOperatorUsdcCommissionEvent,
+    operator_pool_signer_seeds,
+    state::{OperatorPool, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DistributeOperatorUsdcCommission<'info> {
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Account<'info, PoolOverview>,
+
+    #[account(
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Account<'info, OperatorPool>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::POOL_USDC_COMMISSION_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
+        bump,
+    )]
+    pub usdc_fee_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Split all USDC fees collected in the OperatorPool's USDC Fee TokenAccount across the commission recipients.
+/// Permissionless, the token account of each recipient must be passed in as remaining
+/// accounts, in the order of OperatorPool.commission_recipients.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeOperatorUsdcCommission<'info>>,
+) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::DistributeOperatorUsdcCommission)?;
+
+    require!(
+        !ctx.accounts.pool_overview.is_withdrawal_halted,
+        ErrorCode::WithdrawalsHalted
+    );
+    // Check that commission is only distributed if pool is not halted.
+    require!(
+        ctx.accounts.operator_pool.halted_at_timestamp.is_none(),
+        ErrorCode::OperatorPoolHalted
+    );
+
+    let operator_pool = &ctx.accounts.operator_pool;
+    require!(
+        !operator_pool.commission_recipients.is_empty(),
+        ErrorCode::CommissionSplitNotConfigured
+    );
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        operator_pool.commission_recipients.len(),
+        ErrorCode::InvalidCommissionRecipientAccount
+    );
+
+    let fees_amount = ctx.accounts.usdc_fee_token_account.amount;
+    let split = operator_pool.calc_commission_split(fees_amount);
+
+    let mut destinations = Vec::with_capacity(split.len());
+    for (i, commission_recipient) in operator_pool.commission_recipients.iter().enumerate() {
+        let destination_info = &ctx.remaining_accounts[i];
+        require_keys_eq!(
+            *destination_info.owner,
+            Token::id(),
+            ErrorCode::InvalidCommissionRecipientAccount
+        );
+        let destination =
+            TokenAccount::try_deserialize(&mut &destination_info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            destination.mint,
+            ctx.accounts.usdc_fee_token_account.mint,
+            ErrorCode::InvalidCommissionRecipientAccount
+        );
+        require_keys_eq!(
+            destination.owner,
+            commission_recipient.recipient,
+            ErrorCode::InvalidCommissionRecipientAccount
+        );
+
+        if split[i] > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.usdc_fee_token_account.to_account_info(),
+                        to: destination_info.clone(),
+                        authority: operator_pool.to_account_info(),
+                    },
+                    &[operator_pool_signer_seeds!(operator_pool)],
+                ),
+                split[i],
+            )?;
+        }
+        destinations.push(destination_info.key());
+    }
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(DistributeOperatorUsdcCommissionEvent {
+        version: DistributeOperatorUsdcCommissionEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        destinations,
+        amounts: split,
+        usdc_amount_distributed: fees_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::ExpireHaltEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExpireHalt<'info> {
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Account<'info, PoolOverview>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Account<'info, OperatorPool>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to lift the halt of an OperatorPool once its expiry has passed, provided the pool
+/// has not been slashed since it was halted. Permissionless.
+pub fn handler(ctx: Context<ExpireHalt>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ExpireHalt)?;
+
+    let operator_pool = &mut ctx.accounts.operator_pool;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require!(
+        operator_pool.is_halt_expired(current_timestamp),
+        ErrorCode::HaltNotExpired
+    );
+    require!(
+        !operator_pool.is_slashed_since_halt(),
+        ErrorCode::SlashedSinceHalt
+    );
+
+    let halted_at = operator_pool.halted_at_timestamp.unwrap();
+    let reason_code = operator_pool.halt_reason_code;
+    let evidence_hash = operator_pool.halt_evidence_hash;
+    operator_pool.clear_halt();
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ExpireHaltEvent {
+        version: ExpireHaltEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        halted_at,
+        reason_code,
+        evidence_hash,
+    });
+
+    Ok(())
+}
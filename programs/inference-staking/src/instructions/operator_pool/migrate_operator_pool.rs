@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    events::MigrateOperatorPoolEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateOperatorPool<'info> {
+    /// Payer of the rent for the additional account space.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        realloc = 8 + OperatorPool::INIT_SPACE + OperatorPool::PADDING,
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Permissionless instruction to reallocate an OperatorPool to the current layout and upgrade it
+/// to the current version.
+pub fn handler(ctx: Context<MigrateOperatorPool>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::MigrateOperatorPool)?;
+
+    let operator_pool = &mut ctx.accounts.operator_pool;
+    let from_version = operator_pool.migrate(ctx.accounts.pool_overview.completed_reward_epoch)?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(MigrateOperatorPoolEvent {
+        version: MigrateOperatorPoolEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        payer: ctx.accounts.payer.key(),
+        from_version,
+        to_version: operator_pool.version,
+    });
+
+    Ok(())
+}
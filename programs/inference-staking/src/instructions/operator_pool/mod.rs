@@ -2,9 +2,15 @@ pub mod change_operator_admin;
 pub mod change_operator_staking_record;
 pub mod close_operator_pool;
 pub mod create_operator_pool;
+pub mod distribute_operator_reward_commission;
+pub mod distribute_operator_usdc_commission;
+pub mod expire_halt;
+pub mod migrate_operator_pool;
+pub mod report_equivocation;
 pub mod set_halt_status;
 pub mod slash_stake;
 pub mod sweep_closed_pool_usdc_dust;
+pub mod tombstone_operator_key;
 pub mod update_operator_pool;
 pub mod withdraw_operator_reward_commission;
 pub mod withdraw_operator_usdc_commission;
@@ -13,9 +19,15 @@ pub use change_operator_admin::*;
 pub use change_operator_staking_record::*;
 pub use close_operator_pool::*;
 pub use create_operator_pool::*;
+pub use distribute_operator_reward_commission::*;
+pub use distribute_operator_usdc_commission::*;
+pub use expire_halt::*;
+pub use migrate_operator_pool::*;
+pub use report_equivocation::*;
 pub use set_halt_status::*;
 pub use slash_stake::*;
 pub use sweep_closed_pool_usdc_dust::*;
+pub use tombstone_operator_key::*;
 pub use update_operator_pool::*;
 pub use withdraw_operator_reward_commission::*;
 pub use withdraw_operator_usdc_commission::*;
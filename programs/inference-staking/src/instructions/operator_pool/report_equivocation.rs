@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::{
+    constants::{ATTESTATION_MESSAGE_LENGTH, HALT_REASON_EQUIVOCATION},
+    error::ErrorCode,
+    events::ReportEquivocationEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview},
+};
+
+/// Size of the ed25519 precompile instruction header with a single signature.
+const ED25519_HEADER_LENGTH: usize = 16;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReportEquivocation<'info> {
+    pub reporter: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Account<'info, PoolOverview>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Account<'info, OperatorPool>,
+
+    /// CHECK: This is a system account that is used to load the ed25519 signature verification
+    /// instructions and the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReportEquivocationArgs {
+    /// Index in the transaction of the ed25519 program instruction verifying the first message.
+    pub first_instruction_index: u8,
+
+    /// Index in the transaction of the ed25519 program instruction verifying the second message.
+    pub second_instruction_index: u8,
+}
+
+/// An attestation message signed by an operator auth key and verified by the ed25519 program.
+struct SignedAttestation {
+    signer: Pubkey,
+    operator_pool: Pubkey,
+    subject_id: [u8; 32],
+    result_hash: [u8; 32],
+    message: Vec<u8>,
+}
+
+/// Loads the signer and attestation message of an ed25519 program instruction. The instruction
+/// must verify a single signature with all data contained in the instruction itself.
+fn load_signed_attestation(
+    instructions: &AccountInfo,
+    instruction_index: u8,
+) -> Result<SignedAttestation> {
+    let instruction = load_instruction_at_checked(instruction_index.into(), instructions)?;
+    require_keys_eq!(
+        instruction.program_id,
+        ed25519_program::ID,
+        ErrorCode::InvalidEquivocationProof
+    );
+
+    let data = instruction.data;
+    require!(
+        data.len() >= ED25519_HEADER_LENGTH && data[0] == 1,
+        ErrorCode::InvalidEquivocationProof
+    );
+    let read_u16 =
+        |offset: usize| usize::from(u16::from_le_bytes([data[offset], data[offset + 1]]));
+
+    // Offsets of signature, public key and message must all refer to this instruction.
+    for instruction_index_offset in [4, 8, 14] {
+        require_eq!(
+            read_u16(instruction_index_offset),
+            usize::from(u16::MAX),
+            ErrorCode::InvalidEquivocationProof
+        );
+    }
+    let public_key_offset = read_u16(6);
+    let message_offset = read_u16(10);
+    let message_length = read_u16(12);
+    require_eq!(
+        message_length,
+        ATTESTATION_MESSAGE_LENGTH,
+        ErrorCode::InvalidEquivocationProof
+    );
+
+    let signer = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(ErrorCode::InvalidEquivocationProof)?;
+    let message = data
+        .get(message_offset..message_offset + message_length)
+        .ok_or(ErrorCode::InvalidEquivocationProof)?;
+
+    Ok(SignedAttestation {
+        signer: Pubkey::try_from(signer).unwrap(),
+        operator_pool: Pubkey::try_from(&message[0..32]).unwrap(),
+        subject_id: message[32..64].try_into().unwrap(),
+        result_hash: message[64..96].try_into().unwrap(),
+        message: message.to_vec(),
+    })
+}
+
+/// Instruction to report two conflicting attestations signed by the same operator auth key,
+/// verified by ed25519 program instructions in the same transaction. Conflicting attestations
+/// are for the same job or epoch with different result hashes. The OperatorPool is halted
+/// without expiry, so it can be slashed. Permissionless.
+pub fn handler(ctx: Context<ReportEquivocation>, args: ReportEquivocationArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ReportEquivocation)?;
+
+    let ReportEquivocationArgs {
+        first_instruction_index,
+        second_instruction_index,
+    } = args;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let first = load_signed_attestation(&instructions, first_instruction_index)?;
+    let second = load_signed_attestation(&instructions, second_instruction_index)?;
+
+    let operator_pool = &mut ctx.accounts.operator_pool;
+    let operator_pool_key = operator_pool.key();
+
+    // Both attestations must be signed by the same auth key of this pool, for the same subject,
+    // with different results.
+    require_keys_eq!(
+        first.signer,
+        second.signer,
+        ErrorCode::InvalidEquivocationProof
+    );
+    require!(
+        operator_pool.operator_auth_keys.contains(&first.signer),
+        ErrorCode::InvalidEquivocationProof
+    );
+    require_keys_eq!(
+        first.operator_pool,
+        operator_pool_key,
+        ErrorCode::InvalidEquivocationProof
+    );
+    require_keys_eq!(
+        second.operator_pool,
+        operator_pool_key,
+        ErrorCode::InvalidEquivocationProof
+    );
+    require!(
+        first.subject_id == second.subject_id && first.result_hash != second.result_hash,
+        ErrorCode::InvalidEquivocationProof
+    );
+
+    // Halt the pool with the equivocation as evidence. An existing halt is kept, but its expiry is
+    // removed so that it cannot be lifted permissionlessly.
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let evidence_hash = hashv(&[&first.message, &second.message]).to_bytes();
+    if operator_pool.halted_at_timestamp.is_none() {
+        operator_pool.halted_at_timestamp = Some(current_timestamp);
+    }
+    operator_pool.halt_reason_code = HALT_REASON_EQUIVOCATION;
+    operator_pool.halt_evidence_hash = evidence_hash;
+    operator_pool.halt_expires_at = None;
+
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ReportEquivocationEvent {
+        version: ReportEquivocationEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool_key,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        reporter: ctx.accounts.reporter.key(),
+        auth_key: first.signer,
+        subject_id: first.subject_id,
+        first_result_hash: first.result_hash,
+        second_result_hash: second.result_hash,
+        evidence_hash,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
 use crate::{
     error::ErrorCode,
     events::SetHaltStatusEvent,
-    state::{OperatorPool, PoolOverview},
+    state::{
+        AdminActionKind, AdminAuditLog, AuthorityRole, OperatorPool, PausableInstruction,
+        PendingAction, PoolOverview,
+    },
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SetHaltStatus<'info> {
     pub authority: Signer<'info>,
@@ -23,9 +28,27 @@ pub struct SetHaltStatus<'info> {
         mut,
         seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
         bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
 
+    /// Approved PendingAction, required if the halt approval threshold is over 1.
+    #[account(
+        mut,
+        seeds = [PendingAction::SEED, &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Option<Box<Account<'info, PendingAction>>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
     /// CHECK: This is a system account that is used to get the current instruction index.
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions: AccountInfo<'info>,
@@ -35,25 +58,94 @@ pub struct SetHaltStatus<'info> {
 pub struct SetHaltStatusArgs {
     /// Whether the OperatorPool should be halted.
     pub is_halted: bool,
+
+    /// Reason code for the halt. Ignored when unhalting.
+    pub reason_code: u8,
+
+    /// Hash of the evidence for the halt, for example an IPFS CID digest. Ignored when unhalting.
+    pub evidence_hash: [u8; 32],
+
+    /// Timestamp after which the halt can be lifted permissionlessly if the pool has not been
+    /// slashed. Ignored when unhalting.
+    pub expires_at: Option<i64>,
+}
+
+impl SetHaltStatusArgs {
+    /// Hash approved in a PendingAction to set the halt status of an OperatorPool with these args.
+    pub fn action_hash(&self, operator_pool: &Pubkey) -> [u8; 32] {
+        let args = borsh::to_vec(self).unwrap();
+        hashv(&[b"set_halt_status", operator_pool.as_ref(), &args]).to_bytes()
+    }
 }
 
 pub fn handler(ctx: Context<SetHaltStatus>, args: SetHaltStatusArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::SetHaltStatus)?;
+
+    let action_hash = args.action_hash(&ctx.accounts.operator_pool.key());
+    if ctx
+        .accounts
+        .pool_overview
+        .requires_pending_action(AuthorityRole::Halt)
+    {
+        let pending_action = ctx
+            .accounts
+            .pending_action
+            .as_mut()
+            .ok_or(ErrorCode::PendingActionRequired)?;
+        pending_action.execute(
+            &ctx.accounts.pool_overview,
+            AuthorityRole::Halt,
+            action_hash,
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+
+    let SetHaltStatusArgs {
+        is_halted,
+        reason_code,
+        evidence_hash,
+        expires_at,
+    } = args;
     let operator_pool = &mut ctx.accounts.operator_pool;
 
-    if args.is_halted {
-        operator_pool.halted_at_timestamp = Some(Clock::get()?.unix_timestamp);
+    if is_halted {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if let Some(expires_at) = expires_at {
+            require_gt!(expires_at, current_timestamp, ErrorCode::InvalidHaltExpiry);
+        }
+        // Updating the halt of an already halted pool keeps the original halt timestamp, so the
+        // slashing delay is not restarted.
+        if operator_pool.halted_at_timestamp.is_none() {
+            operator_pool.halted_at_timestamp = Some(current_timestamp);
+        }
+        operator_pool.halt_reason_code = reason_code;
+        operator_pool.halt_evidence_hash = evidence_hash;
+        operator_pool.halt_expires_at = expires_at;
     } else {
-        operator_pool.halted_at_timestamp = None;
+        operator_pool.clear_halt();
     }
 
+    ctx.accounts.pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.authority.key(),
+        AdminActionKind::SetHaltStatus,
+        action_hash,
+    )?;
+
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(SetHaltStatusEvent {
+    emit_cpi!(SetHaltStatusEvent {
+        version: SetHaltStatusEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool.key(),
         epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
-        is_halted: args.is_halted,
+        is_halted,
+        reason_code: operator_pool.halt_reason_code,
+        evidence_hash: operator_pool.halt_evidence_hash,
+        expires_at: operator_pool.halt_expires_at,
     });
 
     Ok(())
@@ -1,17 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::{
     constants::USDC_MINT_PUBKEY,
     error::ErrorCode,
     events::SlashStakeEvent,
     operator_pool_signer_seeds,
-    state::{OperatorPool, PoolOverview, StakingRecord},
+    state::{
+        AdminActionKind, AdminAuditLog, OperatorPool, PausableInstruction, PoolOverview,
+        SlashEscrow, SlashProposal, SlashStakeArgs, StakingRecord,
+    },
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SlashStake<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub authority: Signer<'info>,
 
     #[account(
@@ -20,85 +27,155 @@ pub struct SlashStake<'info> {
         constraint = pool_overview.slashing_authorities.contains(authority.key)
           @ ErrorCode::InvalidSlashingAuthority,
     )]
-    pub pool_overview: Account<'info, PoolOverview>,
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
 
     #[account(
         mut,
         seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
         bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
-    pub operator_pool: Account<'info, OperatorPool>,
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
 
     #[account(
         mut,
         address = operator_pool.operator_staking_record,
+        constraint = operator_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_staking_record: Box<Account<'info, StakingRecord>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SlashProposal::SEED,
+            operator_pool.key().as_ref(),
+            slash_proposal.evidence_hash.as_ref()
+        ],
+        bump = slash_proposal.bump,
+        has_one = operator_pool,
     )]
-    pub operator_staking_record: Account<'info, StakingRecord>,
+    pub slash_proposal: Box<Account<'info, SlashProposal>>,
 
     #[account(
         mut,
         seeds = [OperatorPool::POOL_STAKED_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
         bump,
     )]
-    pub staked_token_account: Account<'info, TokenAccount>,
+    pub staked_token_account: Box<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
         seeds = [OperatorPool::POOL_DELEGATOR_USDC_EARNINGS_VAULT_SEED, operator_pool.key().as_ref()],
         bump,
     )]
-    pub pool_usdc_vault: Account<'info, TokenAccount>,
+    pub pool_usdc_vault: Box<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
         seeds = [OperatorPool::POOL_REWARD_COMMISSION_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
         bump,
     )]
-    pub reward_fee_token_account: Account<'info, TokenAccount>,
+    pub reward_fee_token_account: Box<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
         seeds = [OperatorPool::POOL_USDC_COMMISSION_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
         bump,
     )]
-    pub usdc_fee_token_account: Account<'info, TokenAccount>,
+    pub usdc_fee_token_account: Box<Account<'info, TokenAccount>>,
 
-    // Destination for slashed tokens - must match pool_overview configuration
     #[account(
-    mut,
-    address = pool_overview.slashing_destination_token_account,
-)]
-    pub slashing_destination_token_account: Account<'info, TokenAccount>,
+        init,
+        seeds = [SlashEscrow::SEED, slash_proposal.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + SlashEscrow::INIT_SPACE + SlashEscrow::PADDING
+    )]
+    pub slash_escrow: Box<Account<'info, SlashEscrow>>,
 
-    // Destination for slashed USDC - must match pool_overview configuration
+    // Escrow for slashed tokens, held until the appeal period has ended
     #[account(
-        mut,
-        constraint = slashing_destination_usdc_account.mint == USDC_MINT_PUBKEY,
-        address = pool_overview.slashing_destination_usdc_account,
+        init,
+        seeds = [SlashEscrow::ESCROW_TOKEN_VAULT_SEED, slash_escrow.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = mint,
+        token::authority = pool_overview
     )]
-    pub slashing_destination_usdc_account: Account<'info, TokenAccount>,
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    // Escrow for slashed USDC, held until the appeal period has ended
+    #[account(
+        init,
+        seeds = [SlashEscrow::ESCROW_USDC_VAULT_SEED, slash_escrow.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = usdc_mint,
+        token::authority = pool_overview
+    )]
+    pub escrow_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = pool_overview.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT_PUBKEY @ ErrorCode::InvalidUsdcMint
+    )]
+    pub usdc_mint: Box<Account<'info, Mint>>,
 
     pub token_program: Program<'info, Token>,
 
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    pub system_program: Program<'info, System>,
+
     /// CHECK: This is a system account that is used to get the current instruction index.
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions: AccountInfo<'info>,
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize)]
-pub struct SlashStakeArgs {
-    /// Amount of shares to slash from Operator's stake.
-    pub shares_amount: u64,
-}
+/// Instruction to slash an Operator's stake, as approved in a SlashProposal. The SlashProposal
+/// takes the place of a PendingAction, so no PendingAction is required.
+pub fn handler(ctx: Context<SlashStake>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::SlashStake)?;
 
-/// Instruction to slash an Operator's stake.
-pub fn handler(ctx: Context<SlashStake>, args: SlashStakeArgs) -> Result<()> {
     let operator_pool = &mut ctx.accounts.operator_pool;
     let operator_staking_record = &mut ctx.accounts.operator_staking_record;
     let pool_overview = &ctx.accounts.pool_overview;
+    let slash_proposal = &mut ctx.accounts.slash_proposal;
 
-    let shares_amount = args.shares_amount;
-    require_gt!(shares_amount, 0, ErrorCode::InvalidAmount);
+    // Ensure the proposal has not been executed and has enough approvals from current
+    // slashing authorities.
+    require!(
+        slash_proposal.executed_at.is_none(),
+        ErrorCode::SlashProposalAlreadyExecuted
+    );
+    require_gte!(
+        slash_proposal.count_valid_approvals(&pool_overview.slashing_authorities),
+        pool_overview.required_slashing_approvals(),
+        ErrorCode::SlashProposalNotApproved
+    );
+
+    let args = slash_proposal.args.clone();
+    args.validate()?;
+    let SlashStakeArgs {
+        shares_amount,
+        pool_slash_bps,
+        unstaking_slash_bps,
+        usdc_earnings_confiscation,
+        reward_commission_confiscation,
+        usdc_commission_confiscation,
+    } = args;
 
     // Ensure the pool is halted
     require!(
@@ -137,13 +214,62 @@ pub fn handler(ctx: Context<SlashStake>, args: SlashStakeArgs) -> Result<()> {
         .checked_sub(shares_amount)
         .unwrap();
 
-    // Confiscate any accrued USDC the operator may have
-    let mut usdc_confiscated = 0;
-    if operator_staking_record.accrued_usdc_earnings > 0 {
-        let usdc_amount = operator_staking_record.accrued_usdc_earnings;
+    // Slash the whole pool proportionally. Check that the delegator share of the loss does not
+    // exceed the max. ratio to the operator share, including any operator shares slashed above.
+    let (operator_pool_loss, delegator_loss) = operator_pool.calc_pool_slash_losses(
+        operator_staking_record,
+        pool_slash_bps,
+        unstaking_slash_bps,
+        current_timestamp,
+    );
+    let operator_loss = slashed_token_amount
+        .checked_add(operator_pool_loss)
+        .unwrap();
+    if let Some(max_delegator_slash_ratio_bps) = pool_overview.max_delegator_slash_ratio_bps {
+        require_gte!(
+            u128::from(operator_loss)
+                .checked_mul(max_delegator_slash_ratio_bps.into())
+                .unwrap(),
+            u128::from(delegator_loss).checked_mul(10_000).unwrap(),
+            ErrorCode::DelegatorSlashRatioExceeded
+        );
+    }
+
+    let pool_token_amount_slashed = operator_pool.slash_pool_tokens(pool_slash_bps);
+
+    // Slash pending unstakes that are still inside their delay. The slashed tokens are escrowed
+    // now, while StakingRecords, including the operator's, are reduced lazily when settled after
+    // the escrow is resolved. Unstakes that are already claimable are not slashed.
+    let unstaking_in_delay_at_slash = operator_pool.calc_slashable_unstaking(current_timestamp);
+    let mut unstaking_token_amount_slashed = 0;
+    let mut operator_unstaking_slashed = 0;
+    if unstaking_slash_bps > 0 {
+        require!(
+            operator_pool.unstaking_slash_escrow.is_none(),
+            ErrorCode::UnstakingSlashUnresolved
+        );
+        let operator_unstaking_slashed_before =
+            operator_pool.calc_unstaking_slash(operator_staking_record);
+        unstaking_token_amount_slashed =
+            operator_pool.slash_unstaking(unstaking_slash_bps, current_timestamp)?;
+        operator_unstaking_slashed = operator_pool
+            .calc_unstaking_slash(operator_staking_record)
+            .checked_sub(operator_unstaking_slashed_before)
+            .unwrap();
+        operator_pool.unstaking_slash_escrow = Some(ctx.accounts.slash_escrow.key());
+    }
+    let total_token_amount_slashed = slashed_token_amount
+        .checked_add(pool_token_amount_slashed)
+        .unwrap()
+        .checked_add(unstaking_token_amount_slashed)
+        .unwrap();
 
+    // Confiscate accrued USDC the operator may have, according to the confiscation policy
+    let usdc_confiscated =
+        usdc_earnings_confiscation.calc_amount(operator_staking_record.accrued_usdc_earnings);
+    if usdc_confiscated > 0 {
         require!(
-            ctx.accounts.pool_usdc_vault.amount >= usdc_amount,
+            ctx.accounts.pool_usdc_vault.amount >= usdc_confiscated,
             ErrorCode::InsufficientPoolUsdcVaultBalance
         );
 
@@ -152,95 +278,184 @@ pub fn handler(ctx: Context<SlashStake>, args: SlashStakeArgs) -> Result<()> {
                 ctx.accounts.token_program.to_account_info(),
                 Transfer {
                     from: ctx.accounts.pool_usdc_vault.to_account_info(),
-                    to: ctx
-                        .accounts
-                        .slashing_destination_usdc_account
-                        .to_account_info(),
+                    to: ctx.accounts.escrow_usdc_account.to_account_info(),
                     authority: operator_pool.to_account_info(),
                 },
                 &[operator_pool_signer_seeds!(operator_pool)],
             ),
-            usdc_amount,
+            usdc_confiscated,
         )?;
 
-        usdc_confiscated = usdc_amount;
-        // Reset accrued USDC earnings since we've confiscated all USDC earnings
-        operator_staking_record.accrued_usdc_earnings = 0;
+        // Deduct confiscated USDC from accrued USDC earnings
+        operator_staking_record.accrued_usdc_earnings = operator_staking_record
+            .accrued_usdc_earnings
+            .checked_sub(usdc_confiscated)
+            .unwrap();
     }
 
-    // Confiscate any reward commission tokens the operator may have
-    let available_reward_commission = ctx.accounts.reward_fee_token_account.amount;
-    if available_reward_commission > 0 {
+    // Confiscate reward commission tokens the operator may have, according to the confiscation policy
+    let reward_commission_confiscated =
+        reward_commission_confiscation.calc_amount(ctx.accounts.reward_fee_token_account.amount);
+    if reward_commission_confiscated > 0 {
         token::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 Transfer {
                     from: ctx.accounts.reward_fee_token_account.to_account_info(),
-                    to: ctx
-                        .accounts
-                        .slashing_destination_token_account
-                        .to_account_info(),
+                    to: ctx.accounts.escrow_token_account.to_account_info(),
                     authority: operator_pool.to_account_info(),
                 },
                 &[operator_pool_signer_seeds!(operator_pool)],
             ),
-            available_reward_commission,
+            reward_commission_confiscated,
         )?;
     }
 
-    // Confiscate any USDC commission fees the operator may have
-    let available_usdc_commission = ctx.accounts.usdc_fee_token_account.amount;
-    if available_usdc_commission > 0 {
+    // Confiscate USDC commission fees the operator may have, according to the confiscation policy
+    let usdc_commission_confiscated =
+        usdc_commission_confiscation.calc_amount(ctx.accounts.usdc_fee_token_account.amount);
+    if usdc_commission_confiscated > 0 {
         token::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 Transfer {
                     from: ctx.accounts.usdc_fee_token_account.to_account_info(),
-                    to: ctx
-                        .accounts
-                        .slashing_destination_usdc_account
-                        .to_account_info(),
+                    to: ctx.accounts.escrow_usdc_account.to_account_info(),
+                    authority: operator_pool.to_account_info(),
+                },
+                &[operator_pool_signer_seeds!(operator_pool)],
+            ),
+            usdc_commission_confiscated,
+        )?;
+    }
+
+    // Transfer slashed tokens to escrow account
+    if total_token_amount_slashed > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.staked_token_account.to_account_info(),
+                    to: ctx.accounts.escrow_token_account.to_account_info(),
                     authority: operator_pool.to_account_info(),
                 },
                 &[operator_pool_signer_seeds!(operator_pool)],
             ),
-            available_usdc_commission,
+            total_token_amount_slashed,
         )?;
     }
 
-    // Transfer slashed tokens to destination account
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.staked_token_account.to_account_info(),
-                to: ctx
-                    .accounts
-                    .slashing_destination_token_account
-                    .to_account_info(),
-                authority: operator_pool.to_account_info(),
-            },
-            &[operator_pool_signer_seeds!(operator_pool)],
-        ),
-        slashed_token_amount,
+    slash_proposal.executed_at = Some(current_timestamp);
+    operator_pool.last_slashed_at = Some(current_timestamp);
+
+    // Split slashed tokens between redistribution to delegators, burn, the InsuranceFund and the
+    // slashing destination. Confiscated USDC is split between the InsuranceFund and the slashing
+    // destination. The split is executed when the escrow is released.
+    let total_tokens_escrowed = total_token_amount_slashed
+        .checked_add(reward_commission_confiscated)
+        .unwrap();
+    let total_usdc_escrowed = usdc_confiscated
+        .checked_add(usdc_commission_confiscated)
+        .unwrap();
+    let calc_split_amount = |amount: u64, bps: u16| {
+        u64::try_from(
+            u128::from(amount)
+                .checked_mul(bps.into())
+                .unwrap()
+                .checked_div(10_000)
+                .unwrap(),
+        )
+        .unwrap()
+    };
+    let redistribution_amount = calc_split_amount(
+        total_tokens_escrowed,
+        pool_overview.slash_redistribution_bps,
+    );
+    let burn_amount = calc_split_amount(total_tokens_escrowed, pool_overview.slash_burn_bps);
+    let insurance_token_amount =
+        calc_split_amount(total_tokens_escrowed, pool_overview.insurance_slash_bps);
+    let insurance_usdc_amount =
+        calc_split_amount(total_usdc_escrowed, pool_overview.insurance_slash_bps);
+    let treasury_token_amount = total_tokens_escrowed
+        .checked_sub(redistribution_amount)
+        .unwrap()
+        .checked_sub(burn_amount)
+        .unwrap()
+        .checked_sub(insurance_token_amount)
+        .unwrap();
+
+    // Record slashed amounts in escrow, so the slash can be reversed during the appeal period.
+    let release_at = current_timestamp
+        .checked_add(
+            pool_overview
+                .slashing_appeal_period_seconds
+                .try_into()
+                .unwrap(),
+        )
+        .unwrap();
+    let slash_escrow = &mut ctx.accounts.slash_escrow;
+    slash_escrow.version = SlashEscrow::VERSION;
+    slash_escrow.bump = ctx.bumps.slash_escrow;
+    slash_escrow.operator_pool = operator_pool_key;
+    slash_escrow.slash_proposal = slash_proposal.key();
+    slash_escrow.operator_staking_record = operator_staking_record_key;
+    slash_escrow.shares_slashed = shares_amount;
+    slash_escrow.token_amount_slashed = slashed_token_amount;
+    slash_escrow.pool_token_amount_slashed = pool_token_amount_slashed;
+    slash_escrow.usdc_confiscated = usdc_confiscated;
+    slash_escrow.reward_commission_confiscated = reward_commission_confiscated;
+    slash_escrow.usdc_commission_confiscated = usdc_commission_confiscated;
+    slash_escrow.redistribution_amount = redistribution_amount;
+    slash_escrow.burn_amount = burn_amount;
+    slash_escrow.insurance_token_amount = insurance_token_amount;
+    slash_escrow.insurance_usdc_amount = insurance_usdc_amount;
+    slash_escrow.slashed_at = current_timestamp;
+    slash_escrow.release_at = release_at;
+    slash_escrow.unstaking_token_amount_slashed = unstaking_token_amount_slashed;
+    slash_escrow.proposer = slash_proposal.proposer;
+
+    ctx.accounts.pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.authority.key(),
+        AdminActionKind::SlashStake,
+        AdminAuditLog::hash_args(&slash_proposal.key()),
     )?;
 
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(SlashStakeEvent {
+    emit_cpi!(SlashStakeEvent {
+        version: SlashStakeEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool_key,
         epoch: pool_overview.completed_reward_epoch + 1,
         operator_staking_record: operator_staking_record_key,
         authority: authority_key,
-        destination: ctx.accounts.slashing_destination_token_account.key(),
-        destination_usdc: ctx.accounts.slashing_destination_usdc_account.key(),
+        destination: ctx.accounts.escrow_token_account.key(),
+        destination_usdc: ctx.accounts.escrow_usdc_account.key(),
         shares_slashed: shares_amount,
         token_amount_slashed: slashed_token_amount,
         usdc_confiscated,
-        reward_commission_confiscated: available_reward_commission,
-        usdc_commission_confiscated: available_usdc_commission,
+        reward_commission_confiscated,
+        usdc_commission_confiscated,
+        pool_slash_bps,
+        pool_token_amount_slashed,
+        unstaking_slash_bps,
+        unstaking_in_delay_at_slash,
+        unstaking_token_amount_slashed,
+        operator_unstaking_slashed,
+        usdc_earnings_confiscation,
+        reward_commission_confiscation,
+        usdc_commission_confiscation,
+        slash_proposal: slash_proposal.key(),
+        evidence_hash: slash_proposal.evidence_hash,
+        slash_escrow: slash_escrow.key(),
+        release_at,
+        redistribution_amount,
+        burn_amount,
+        treasury_token_amount,
+        insurance_token_amount,
+        insurance_usdc_amount,
     });
 
     Ok(())
@@ -7,9 +7,10 @@ use crate::{
     error::ErrorCode,
     events::SweepClosedPoolUsdcDustEvent,
     operator_pool_signer_seeds,
-    state::{OperatorPool, PoolOverview},
+    state::{OperatorPool, PausableInstruction, PoolOverview},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SweepClosedPoolUsdcDust<'info> {
     /// The admin of the OperatorPool, who is authorized to sweep the vault.
@@ -21,6 +22,8 @@ pub struct SweepClosedPoolUsdcDust<'info> {
         has_one = admin,
         // The pool must be in a closed state.
         constraint = operator_pool.closed_at_epoch.is_some() @ ErrorCode::PoolClosedEpochInvalid,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
 
@@ -55,6 +58,10 @@ pub struct SweepClosedPoolUsdcDust<'info> {
 }
 
 pub fn handler(ctx: Context<SweepClosedPoolUsdcDust>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::SweepClosedPoolUsdcDust)?;
+
     let operator_pool = &ctx.accounts.operator_pool;
     let operator_usdc_vault = &mut ctx.accounts.operator_usdc_vault;
     let pool_overview = &ctx.accounts.pool_overview;
@@ -100,7 +107,8 @@ pub fn handler(ctx: Context<SweepClosedPoolUsdcDust>) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(SweepClosedPoolUsdcDustEvent {
+    emit_cpi!(SweepClosedPoolUsdcDustEvent {
+        version: SweepClosedPoolUsdcDustEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool.key(),
         epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
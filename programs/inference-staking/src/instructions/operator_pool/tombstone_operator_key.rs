@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::TombstoneOperatorKeyEvent,
+    state::{
+        AdminActionKind, AdminAuditLog, OperatorPool, PausableInstruction, PoolOverview,
+        SlashProposal, Tombstone,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: TombstoneOperatorKeyArgs)]
+pub struct TombstoneOperatorKey<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        constraint = pool_overview.slashing_authorities.contains(authority.key)
+          @ ErrorCode::InvalidSlashingAuthority,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        seeds = [
+            SlashProposal::SEED,
+            operator_pool.key().as_ref(),
+            slash_proposal.evidence_hash.as_ref()
+        ],
+        bump = slash_proposal.bump,
+        has_one = operator_pool,
+    )]
+    pub slash_proposal: Box<Account<'info, SlashProposal>>,
+
+    #[account(
+        init,
+        seeds = [Tombstone::SEED, args.key.as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + Tombstone::INIT_SPACE + Tombstone::PADDING
+    )]
+    pub tombstone: Box<Account<'info, Tombstone>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TombstoneOperatorKeyArgs {
+    /// Admin key or auth key of the OperatorPool to ban.
+    pub key: Pubkey,
+}
+
+/// Instruction for a slashing authority to ban the admin key or an auth key of an OperatorPool.
+/// Requires a SlashProposal of the pool that is approved by enough slashing authorities, so a
+/// single authority cannot ban keys. The pool is marked as tombstoned on the first banned key.
+pub fn handler(ctx: Context<TombstoneOperatorKey>, args: TombstoneOperatorKeyArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::TombstoneOperatorKey)?;
+
+    let args_hash = AdminAuditLog::hash_args(&args);
+    let TombstoneOperatorKeyArgs { key } = args;
+    let operator_pool = &mut ctx.accounts.operator_pool;
+    let pool_overview = &ctx.accounts.pool_overview;
+    let slash_proposal = &ctx.accounts.slash_proposal;
+
+    require_gte!(
+        slash_proposal.count_valid_approvals(&pool_overview.slashing_authorities),
+        pool_overview.required_slashing_approvals(),
+        ErrorCode::SlashProposalNotApproved
+    );
+
+    require!(
+        key == operator_pool.admin || operator_pool.operator_auth_keys.contains(&key),
+        ErrorCode::InvalidTombstoneKey
+    );
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let tombstone = &mut ctx.accounts.tombstone;
+    tombstone.version = Tombstone::VERSION;
+    tombstone.bump = ctx.bumps.tombstone;
+    tombstone.key = key;
+    tombstone.operator_pool = operator_pool.key();
+    tombstone.authority = ctx.accounts.authority.key();
+    tombstone.slash_proposal = slash_proposal.key();
+    tombstone.tombstoned_at = current_timestamp;
+
+    if operator_pool.tombstoned_at.is_none() {
+        operator_pool.tombstoned_at = Some(current_timestamp);
+    }
+
+    ctx.accounts.pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.authority.key(),
+        AdminActionKind::TombstoneOperatorKey,
+        args_hash,
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(TombstoneOperatorKeyEvent {
+        version: TombstoneOperatorKeyEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        tombstone: tombstone.key(),
+        key,
+        is_admin: key == operator_pool.admin,
+        authority: tombstone.authority,
+        slash_proposal: tombstone.slash_proposal,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
+use crate::error::ErrorCode;
 use crate::events::UpdateOperatorPoolEvent;
-use crate::state::{OperatorPool, PoolOverview};
+use crate::state::{
+    CommissionRecipient, OperatorPool, PausableInstruction, PoolOverview, ScheduledCommissionRate,
+    Tombstone,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateOperatorPool<'info> {
     pub admin: Signer<'info>,
@@ -22,6 +27,8 @@ pub struct UpdateOperatorPool<'info> {
         ],
         bump = operator_pool.bump,
         has_one = admin,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
 
@@ -30,16 +37,12 @@ pub struct UpdateOperatorPool<'info> {
     pub instructions: AccountInfo<'info>,
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize)]
-pub struct NewCommissionRateSetting {
-    pub rate_bps: Option<u16>,
-}
-
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct UpdateOperatorPoolArgs {
-    /// If provided, the new commission rates will become active after the next epoch's reward claim for this pool
-    pub new_reward_commission_rate_bps: Option<NewCommissionRateSetting>,
-    pub new_usdc_commission_rate_bps: Option<NewCommissionRateSetting>,
+    /// If provided, replaces the scheduled commission rate changes. Each rate becomes active from its
+    /// effective epoch, subject to the notice periods configured on PoolOverview.
+    pub reward_commission_rate_schedule: Option<Vec<ScheduledCommissionRate>>,
+    pub usdc_commission_rate_schedule: Option<Vec<ScheduledCommissionRate>>,
     pub allow_delegation: Option<bool>,
     pub auto_stake_fees: Option<bool>,
     pub name: Option<String>,
@@ -47,12 +50,20 @@ pub struct UpdateOperatorPoolArgs {
     pub website_url: Option<String>,
     pub avatar_image_url: Option<String>,
     pub operator_auth_keys: Option<Vec<Pubkey>>,
+    /// If provided, replaces the commission split. An empty list disables the split.
+    pub commission_recipients: Option<Vec<CommissionRecipient>>,
+    /// Share of commission earned on referred shares that is paid to referrers, in basis points.
+    pub referral_commission_share_bps: Option<u16>,
 }
 
 pub fn handler(ctx: Context<UpdateOperatorPool>, args: UpdateOperatorPoolArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::UpdateOperatorPool)?;
+
     let UpdateOperatorPoolArgs {
-        new_reward_commission_rate_bps,
-        new_usdc_commission_rate_bps,
+        reward_commission_rate_schedule,
+        usdc_commission_rate_schedule,
         allow_delegation,
         auto_stake_fees,
         name,
@@ -60,6 +71,8 @@ pub fn handler(ctx: Context<UpdateOperatorPool>, args: UpdateOperatorPoolArgs) -
         website_url,
         avatar_image_url,
         operator_auth_keys,
+        commission_recipients,
+        referral_commission_share_bps,
     } = args;
 
     let operator_pool = &mut ctx.accounts.operator_pool;
@@ -88,31 +101,54 @@ pub fn handler(ctx: Context<UpdateOperatorPool>, args: UpdateOperatorPoolArgs) -
         operator_pool.auto_stake_fees = auto_stake_fees;
     }
 
-    if let Some(new_reward_rate_setting) = new_reward_commission_rate_bps {
-        if let Some(new_commission_rate_bps) = new_reward_rate_setting.rate_bps {
-            OperatorPool::validate_commission_rate(new_commission_rate_bps)?;
-        }
-        operator_pool.new_reward_commission_rate_bps = new_reward_rate_setting.rate_bps;
+    let pool_overview = &ctx.accounts.pool_overview;
+
+    if let Some(schedule) = reward_commission_rate_schedule {
+        pool_overview.validate_commission_rate_schedule(
+            operator_pool.reward_commission_rate_bps,
+            &operator_pool.reward_commission_rate_schedule,
+            &schedule,
+        )?;
+        operator_pool.reward_commission_rate_schedule = schedule;
     }
 
-    if let Some(new_usdc_rate_setting) = new_usdc_commission_rate_bps {
-        if let Some(new_usdc_rate_bps) = new_usdc_rate_setting.rate_bps {
-            OperatorPool::validate_commission_rate(new_usdc_rate_bps)?;
-        }
-        operator_pool.new_usdc_commission_rate_bps = new_usdc_rate_setting.rate_bps;
+    if let Some(schedule) = usdc_commission_rate_schedule {
+        pool_overview.validate_commission_rate_schedule(
+            operator_pool.usdc_commission_rate_bps,
+            &operator_pool.usdc_commission_rate_schedule,
+            &schedule,
+        )?;
+        operator_pool.usdc_commission_rate_schedule = schedule;
     }
 
     if let Some(operator_auth_keys) = operator_auth_keys {
         OperatorPool::validate_operator_auth_keys(&operator_auth_keys)?;
+        // Tombstone PDAs of each auth key are passed as remaining accounts, in the same order.
+        Tombstone::require_not_tombstoned(&operator_auth_keys, ctx.remaining_accounts)?;
         operator_pool.operator_auth_keys = operator_auth_keys;
     }
 
+    if let Some(commission_recipients) = commission_recipients {
+        OperatorPool::validate_commission_recipients(&commission_recipients)?;
+        operator_pool.commission_recipients = commission_recipients;
+    }
+
+    if let Some(referral_commission_share_bps) = referral_commission_share_bps {
+        require_gte!(
+            10_000,
+            referral_commission_share_bps,
+            ErrorCode::InvalidReferralCommissionShare
+        );
+        operator_pool.referral_commission_share_bps = referral_commission_share_bps;
+    }
+
     operator_pool.validate_pool_profile_fields()?;
 
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(UpdateOperatorPoolEvent {
+    emit_cpi!(UpdateOperatorPoolEvent {
+        version: UpdateOperatorPoolEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool.key(),
         epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
@@ -6,9 +6,10 @@ use crate::{
     error::ErrorCode,
     events::WithdrawOperatorUsdcCommissionEvent,
     operator_pool_signer_seeds,
-    state::{OperatorPool, PoolOverview},
+    state::{OperatorPool, PausableInstruction, PoolOverview},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct WithdrawOperatorUsdcCommission<'info> {
     pub admin: Signer<'info>,
@@ -24,6 +25,8 @@ pub struct WithdrawOperatorUsdcCommission<'info> {
         bump = operator_pool.bump,
         // Admin must sign to invoke this instruction
         has_one = admin,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
 
@@ -48,6 +51,10 @@ pub struct WithdrawOperatorUsdcCommission<'info> {
 /// Send all USDC fees collected in the OperatorPool's USDC Fee TokenAccount to the destination.
 /// Must be signed by the OperatorPool's admin.
 pub fn handler(ctx: Context<WithdrawOperatorUsdcCommission>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::WithdrawOperatorUsdcCommission)?;
+
     require!(
         !ctx.accounts.pool_overview.is_withdrawal_halted,
         ErrorCode::WithdrawalsHalted
@@ -57,6 +64,11 @@ pub fn handler(ctx: Context<WithdrawOperatorUsdcCommission>) -> Result<()> {
         ctx.accounts.operator_pool.halted_at_timestamp.is_none(),
         ErrorCode::OperatorPoolHalted
     );
+    // Check that commission is not split, as it must then be distributed to all recipients.
+    require!(
+        ctx.accounts.operator_pool.commission_recipients.is_empty(),
+        ErrorCode::CommissionSplitConfigured
+    );
 
     // Transfer all USDC fees from USDC Fee TokenAccount to selected destination TokenAccount.
     let fees_amount = ctx.accounts.usdc_fee_token_account.amount;
@@ -76,7 +88,8 @@ pub fn handler(ctx: Context<WithdrawOperatorUsdcCommission>) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(WithdrawOperatorUsdcCommissionEvent {
+    emit_cpi!(WithdrawOperatorUsdcCommissionEvent {
+        version: WithdrawOperatorUsdcCommissionEvent::VERSION,
         instruction_index,
         operator_pool: ctx.accounts.operator_pool.key(),
         epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
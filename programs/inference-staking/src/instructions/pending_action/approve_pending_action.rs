@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::ApprovePendingActionEvent,
+    state::{PausableInstruction, PendingAction, PoolOverview, MAX_APPROVALS},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApprovePendingAction<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        constraint = pool_overview.authorities(pending_action.role).contains(authority.key)
+          @ ErrorCode::InvalidActionAuthority,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [PendingAction::SEED, &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction for an authority to approve a PendingAction of its role.
+pub fn handler(ctx: Context<ApprovePendingAction>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ApprovePendingAction)?;
+
+    let authority = ctx.accounts.authority.key();
+    let pool_overview = &ctx.accounts.pool_overview;
+    let pending_action = &mut ctx.accounts.pending_action;
+
+    require!(
+        pending_action.executed_at.is_none(),
+        ErrorCode::PendingActionAlreadyExecuted
+    );
+
+    // Drop approvals of keys that are no longer authorities of the role, to make space.
+    let authorities = pool_overview.authorities(pending_action.role);
+    pending_action
+        .approvals
+        .retain(|approval| authorities.contains(approval));
+    require!(
+        !pending_action.approvals.contains(&authority),
+        ErrorCode::DuplicateActionApproval
+    );
+    require_gt!(
+        MAX_APPROVALS,
+        pending_action.approvals.len(),
+        ErrorCode::ApprovalsExceeded
+    );
+    pending_action.approvals.push(authority);
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ApprovePendingActionEvent {
+        version: ApprovePendingActionEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        pending_action: pending_action.key(),
+        action_id: pending_action.id,
+        authority,
+        approvals: u8::try_from(pending_action.approvals.len()).unwrap(),
+    });
+
+    Ok(())
+}
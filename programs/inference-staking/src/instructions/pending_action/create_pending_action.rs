@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::CreatePendingActionEvent,
+    state::{AuthorityRole, PausableInstruction, PendingAction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreatePendingActionArgs)]
+pub struct CreatePendingAction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        constraint = pool_overview.authorities(args.role).contains(authority.key)
+          @ ErrorCode::InvalidActionAuthority,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        init,
+        seeds = [
+            PendingAction::SEED,
+            &pool_overview.pending_action_count.to_le_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + PendingAction::INIT_SPACE + PendingAction::PADDING
+    )]
+    pub pending_action: Box<Account<'info, PendingAction>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreatePendingActionArgs {
+    /// Authority role that must approve the action.
+    pub role: AuthorityRole,
+
+    /// Hash of the gated instruction and its arguments.
+    pub action_hash: [u8; 32],
+}
+
+/// Instruction for an authority to stage an action that requires approval by multiple
+/// authorities of its role. The proposer's approval is recorded.
+pub fn handler(ctx: Context<CreatePendingAction>, args: CreatePendingActionArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreatePendingAction)?;
+
+    let CreatePendingActionArgs { role, action_hash } = args;
+
+    let authority = ctx.accounts.authority.key();
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    let pending_action = &mut ctx.accounts.pending_action;
+    pending_action.version = PendingAction::VERSION;
+    pending_action.bump = ctx.bumps.pending_action;
+    pending_action.id = pool_overview.pending_action_count;
+    pending_action.role = role;
+    pending_action.action_hash = action_hash;
+    pending_action.proposer = authority;
+    pending_action.approvals = vec![authority];
+    pending_action.created_at = Clock::get()?.unix_timestamp;
+    pending_action.executed_at = None;
+
+    pool_overview.pending_action_count = pool_overview.pending_action_count.checked_add(1).unwrap();
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreatePendingActionEvent {
+        version: CreatePendingActionEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        pending_action: pending_action.key(),
+        action_id: pending_action.id,
+        role,
+        action_hash,
+        proposer: authority,
+    });
+
+    Ok(())
+}
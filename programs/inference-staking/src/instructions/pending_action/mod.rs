@@ -0,0 +1,5 @@
+pub mod approve_pending_action;
+pub mod create_pending_action;
+
+pub use approve_pending_action::*;
+pub use create_pending_action::*;
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::AcceptProgramAdminEvent,
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction},
+    PoolOverview,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptProgramAdmin<'info> {
+    pub new_program_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        constraint = pool_overview.pending_program_admin == Some(new_program_admin.key())
+          @ ErrorCode::InvalidPendingProgramAdmin,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction for the pending program admin to accept the program admin role.
+pub fn handler(ctx: Context<AcceptProgramAdmin>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::AcceptProgramAdmin)?;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    let old_program_admin = pool_overview.program_admin;
+    let new_program_admin = ctx.accounts.new_program_admin.key();
+
+    pool_overview.program_admin = new_program_admin;
+    pool_overview.pending_program_admin = None;
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        new_program_admin,
+        AdminActionKind::AcceptProgramAdmin,
+        AdminAuditLog::hash_args(&old_program_admin),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(AcceptProgramAdminEvent {
+        version: AcceptProgramAdminEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        old_program_admin,
+        new_program_admin,
+    });
+
+    Ok(())
+}
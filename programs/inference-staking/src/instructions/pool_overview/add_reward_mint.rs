@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    constants::USDC_MINT_PUBKEY,
+    error::ErrorCode,
+    events::AddRewardMintEvent,
+    state::{
+        AdminActionKind, AdminAuditLog, PausableInstruction, PoolOverview, RewardMint,
+        MAX_REWARD_MINTS,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddRewardMint<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        constraint = reward_mint.key() != pool_overview.mint @ ErrorCode::InvalidRewardMint,
+        constraint = reward_mint.key() != USDC_MINT_PUBKEY @ ErrorCode::InvalidRewardMint,
+    )]
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        seeds = [PoolOverview::GLOBAL_REWARD_MINT_VAULT_SEED, reward_mint.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = reward_mint,
+        token::authority = pool_overview
+    )]
+    pub reward_mint_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to register an additional reward mint on PoolOverview and create its global vault.
+pub fn handler(ctx: Context<AddRewardMint>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::AddRewardMint)?;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    let reward_mint = ctx.accounts.reward_mint.key();
+
+    require_gt!(
+        MAX_REWARD_MINTS,
+        pool_overview.reward_mints.len(),
+        ErrorCode::RewardMintsExceeded
+    );
+    require!(
+        pool_overview.reward_mint_index(&reward_mint).is_none(),
+        ErrorCode::InvalidRewardMint
+    );
+
+    pool_overview.reward_mints.push(RewardMint {
+        mint: reward_mint,
+        unclaimed_amount: 0,
+    });
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::AddRewardMint,
+        AdminAuditLog::hash_args(&reward_mint),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(AddRewardMintEvent {
+        version: AddRewardMintEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        reward_mint,
+        reward_mint_index: u8::try_from(pool_overview.reward_mints.len() - 1).unwrap(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::CancelConfigChangeEvent,
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction, PendingConfigChange},
+    PoolOverview,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [PendingConfigChange::SEED, &pending_config_change.id.to_le_bytes()],
+        bump = pending_config_change.bump,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    /// CHECK: Account that paid rent for the PendingConfigChange, validated by has_one.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction for the program admin to cancel a queued change of PoolOverview settings.
+pub fn handler(ctx: Context<CancelConfigChange>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CancelConfigChange)?;
+
+    let pending_config_change = &ctx.accounts.pending_config_change;
+
+    ctx.accounts.pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::CancelConfigChange,
+        AdminAuditLog::hash_args(&pending_config_change.args),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CancelConfigChangeEvent {
+        version: CancelConfigChangeEvent::VERSION,
+        instruction_index,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        pending_config_change: pending_config_change.key(),
+        config_change_id: pending_config_change.id,
+    });
+
+    Ok(())
+}
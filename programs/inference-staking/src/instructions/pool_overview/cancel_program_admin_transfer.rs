@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::CancelProgramAdminTransferEvent,
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction},
+    PoolOverview,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelProgramAdminTransfer<'info> {
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction for the program admin to cancel a pending program admin transfer.
+pub fn handler(ctx: Context<CancelProgramAdminTransfer>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CancelProgramAdminTransfer)?;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    let pending_program_admin = pool_overview
+        .pending_program_admin
+        .take()
+        .ok_or(ErrorCode::NoPendingProgramAdmin)?;
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::CancelProgramAdminTransfer,
+        AdminAuditLog::hash_args(&pending_program_admin),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CancelProgramAdminTransferEvent {
+        version: CancelProgramAdminTransferEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        program_admin: pool_overview.program_admin,
+        pending_program_admin,
+    });
+
+    Ok(())
+}
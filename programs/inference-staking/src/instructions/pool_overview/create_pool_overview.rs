@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::{
     constants::{self, MIN_SLASHING_DELAY_SECONDS},
     error::ErrorCode,
+    events::CreatePoolOverviewEvent,
     PoolOverview,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreatePoolOverview<'info> {
     #[account(mut)]
@@ -62,6 +65,10 @@ pub struct CreatePoolOverview<'info> {
     pub token_program: Program<'info, Token>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 /// Instruction to setup a PoolOverview singleton. To be called after initial program deployment.
@@ -79,5 +86,17 @@ pub fn handler(ctx: Context<CreatePoolOverview>) -> Result<()> {
         ctx.accounts.slashing_destination_usdc_account.key();
     pool_overview.slashing_delay_seconds = MIN_SLASHING_DELAY_SECONDS;
 
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreatePoolOverviewEvent {
+        version: CreatePoolOverviewEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        program_admin: pool_overview.program_admin,
+        mint: pool_overview.mint,
+        config: pool_overview.config(),
+    });
+
     Ok(())
 }
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::{ExecuteConfigChangeEvent, InitiateWindDownEvent},
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction, PendingConfigChange},
+    PoolOverview,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    /// Signer that executes the config change. Any key can execute it.
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [PendingConfigChange::SEED, &pending_config_change.id.to_le_bytes()],
+        bump = pending_config_change.bump,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    /// CHECK: Account that paid rent for the PendingConfigChange, validated by has_one.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to execute a queued change of PoolOverview settings once the config change
+/// timelock has passed. Permissionless.
+pub fn handler(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ExecuteConfigChange)?;
+
+    let pending_config_change = &ctx.accounts.pending_config_change;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require_gte!(
+        current_timestamp,
+        pending_config_change.execute_at,
+        ErrorCode::ConfigChangeNotMatured
+    );
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    let old_config = pool_overview.config();
+    pending_config_change.args.clone().apply(pool_overview)?;
+    pool_overview.update_destinations(
+        pending_config_change.registration_fee_payout_wallet,
+        pending_config_change.slashing_destination_usdc_account,
+        pending_config_change.slashing_destination_token_account,
+    );
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        pending_config_change.queued_by,
+        AdminActionKind::ExecuteConfigChange,
+        AdminAuditLog::hash_args(&pending_config_change.args),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ExecuteConfigChangeEvent {
+        version: ExecuteConfigChangeEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        pending_config_change: pending_config_change.key(),
+        config_change_id: pending_config_change.id,
+        old_config,
+        new_config: pool_overview.config(),
+    });
+
+    if let Some(final_epoch) = pending_config_change.args.wind_down_final_epoch {
+        emit_cpi!(InitiateWindDownEvent {
+            version: InitiateWindDownEvent::VERSION,
+            instruction_index,
+            epoch: pool_overview.completed_reward_epoch + 1,
+            program_admin: pool_overview.program_admin,
+            final_epoch,
+            wind_down_at: current_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::InitiateWindDownEvent,
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction},
+    PoolOverview,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitiateWindDown<'info> {
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin,
+        constraint = !pool_overview.is_winding_down() @ ErrorCode::ProgramWindingDown,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitiateWindDownArgs {
+    /// Last epoch that rewards are distributed for. Must not be before the current epoch.
+    pub final_epoch: u64,
+}
+
+/// Instruction for the program admin to put the program into wind-down. Wind-down is terminal:
+/// new stakes and OperatorPools are no longer allowed, unstaking has no delay, and any
+/// StakingRecord can be exited permissionlessly. Once a config change timelock is set, wind-down
+/// must instead be queued as a config change with wind_down_final_epoch.
+pub fn handler(ctx: Context<InitiateWindDown>, args: InitiateWindDownArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::InitiateWindDown)?;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+
+    // Wind-down must be queued as a config change while a config change timelock is set.
+    require!(
+        pool_overview.config_change_timelock_seconds == 0,
+        ErrorCode::ConfigChangeRequiresTimelock
+    );
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    pool_overview.initiate_wind_down(args.final_epoch, current_timestamp)?;
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::InitiateWindDown,
+        AdminAuditLog::hash_args(&args),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(InitiateWindDownEvent {
+        version: InitiateWindDownEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        program_admin: ctx.accounts.program_admin.key(),
+        final_epoch: args.final_epoch,
+        wind_down_at: current_timestamp,
+    });
+
+    Ok(())
+}
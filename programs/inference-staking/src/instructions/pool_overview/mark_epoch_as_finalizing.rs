@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
-use crate::{error::ErrorCode, PoolOverview};
+use crate::{
+    error::ErrorCode, events::MarkEpochAsFinalizingEvent, state::PausableInstruction, PoolOverview,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MarkEpochIsFinalizing<'info> {
     pub authority: Signer<'info>,
@@ -10,10 +14,14 @@ pub struct MarkEpochIsFinalizing<'info> {
         mut,
         seeds = [PoolOverview::SEED],
         bump = pool_overview.bump,
-        constraint = pool_overview.reward_distribution_authorities.contains(authority.key)
-            @ ErrorCode::InvalidRewardDistributionAuthority,
+        constraint = pool_overview.is_epoch_authority(authority.key)
+            @ ErrorCode::InvalidEpochAuthority,
     )]
     pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -23,6 +31,10 @@ pub struct MarkEpochIsFinalizingArgs {
 
 /// Instruction to mark an epoch as finalizing.
 pub fn handler(ctx: Context<MarkEpochIsFinalizing>, args: MarkEpochIsFinalizingArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::MarkEpochAsFinalizing)?;
+
     let pool_overview = &mut ctx.accounts.pool_overview;
 
     // We explicitly check the epoch that are marking as finalizing, to avoid
@@ -33,7 +45,26 @@ pub fn handler(ctx: Context<MarkEpochIsFinalizing>, args: MarkEpochIsFinalizingA
         ErrorCode::EpochIsFinalizingEpochInvalid
     );
 
+    // No epochs are finalized after the wind-down final epoch.
+    if let Some(final_epoch) = pool_overview.wind_down_final_epoch {
+        require_gte!(
+            final_epoch,
+            args.expected_epoch,
+            ErrorCode::EpochAfterWindDownFinalEpoch
+        );
+    }
+
     pool_overview.is_epoch_finalizing = true;
 
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(MarkEpochAsFinalizingEvent {
+        version: MarkEpochAsFinalizingEvent::VERSION,
+        instruction_index,
+        epoch: args.expected_epoch,
+        authority: ctx.accounts.authority.key(),
+    });
+
     Ok(())
 }
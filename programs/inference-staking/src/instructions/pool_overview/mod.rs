@@ -1,9 +1,27 @@
+pub mod accept_program_admin;
+pub mod add_reward_mint;
+pub mod cancel_config_change;
+pub mod cancel_program_admin_transfer;
 pub mod create_pool_overview;
+pub mod execute_config_change;
+pub mod initiate_wind_down;
 pub mod mark_epoch_as_finalizing;
+pub mod propose_program_admin;
+pub mod queue_config_change;
+pub mod set_instruction_pause;
 pub mod update_pool_overview;
 pub mod update_pool_overview_authorities;
 
+pub use accept_program_admin::*;
+pub use add_reward_mint::*;
+pub use cancel_config_change::*;
+pub use cancel_program_admin_transfer::*;
 pub use create_pool_overview::*;
+pub use execute_config_change::*;
+pub use initiate_wind_down::*;
 pub use mark_epoch_as_finalizing::*;
+pub use propose_program_admin::*;
+pub use queue_config_change::*;
+pub use set_instruction_pause::*;
 pub use update_pool_overview::*;
 pub use update_pool_overview_authorities::*;
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::ProposeProgramAdminEvent,
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction},
+    PoolOverview,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeProgramAdmin<'info> {
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeProgramAdminArgs {
+    /// Key that must accept to become the program admin.
+    pub new_program_admin: Pubkey,
+}
+
+/// Instruction for the program admin to propose a new program admin. Replaces any pending
+/// proposal. The new program admin takes over once it accepts.
+pub fn handler(ctx: Context<ProposeProgramAdmin>, args: ProposeProgramAdminArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ProposeProgramAdmin)?;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    pool_overview.pending_program_admin = Some(args.new_program_admin);
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::ProposeProgramAdmin,
+        AdminAuditLog::hash_args(&args),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ProposeProgramAdminEvent {
+        version: ProposeProgramAdminEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        program_admin: pool_overview.program_admin,
+        pending_program_admin: args.new_program_admin,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::QueueConfigChangeEvent,
+    instructions::UpdatePoolOverviewArgs,
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction, PendingConfigChange},
+    PoolOverview,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        init,
+        seeds = [
+            PendingConfigChange::SEED,
+            &pool_overview.config_change_count.to_le_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + PendingConfigChange::INIT_SPACE + PendingConfigChange::PADDING
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    /// CHECK: This is the wallet address that receives the operator pool registration fees.
+    pub registration_fee_payout_wallet: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This is the destination account for slashed USDC tokens.
+    pub slashing_destination_usdc_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This is the destination account for slashed tokens.
+    pub slashing_destination_token_account: Option<UncheckedAccount<'info>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to queue a change of PoolOverview settings, which can be executed by anyone once
+/// the config change timelock has passed.
+pub fn handler(ctx: Context<QueueConfigChange>, args: UpdatePoolOverviewArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::QueueConfigChange)?;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+
+    // Validate the change against current settings, so that invalid changes are rejected early.
+    let mut updated_pool_overview = PoolOverview::clone(pool_overview);
+    args.clone().apply(&mut updated_pool_overview)?;
+
+    let args_hash = AdminAuditLog::hash_args(&args);
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let execute_at = current_timestamp
+        .checked_add(
+            pool_overview
+                .config_change_timelock_seconds
+                .try_into()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let pending_config_change = &mut ctx.accounts.pending_config_change;
+    pending_config_change.version = PendingConfigChange::VERSION;
+    pending_config_change.bump = ctx.bumps.pending_config_change;
+    pending_config_change.id = pool_overview.config_change_count;
+    pending_config_change.rent_payer = ctx.accounts.payer.key();
+    pending_config_change.args = args.clone();
+    pending_config_change.registration_fee_payout_wallet = ctx
+        .accounts
+        .registration_fee_payout_wallet
+        .as_ref()
+        .map(|account| account.key());
+    pending_config_change.slashing_destination_usdc_account = ctx
+        .accounts
+        .slashing_destination_usdc_account
+        .as_ref()
+        .map(|account| account.key());
+    pending_config_change.slashing_destination_token_account = ctx
+        .accounts
+        .slashing_destination_token_account
+        .as_ref()
+        .map(|account| account.key());
+    pending_config_change.queued_at = current_timestamp;
+    pending_config_change.execute_at = execute_at;
+    pending_config_change.queued_by = ctx.accounts.program_admin.key();
+
+    pool_overview.config_change_count = pool_overview.config_change_count.checked_add(1).unwrap();
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::QueueConfigChange,
+        args_hash,
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(QueueConfigChangeEvent {
+        version: QueueConfigChangeEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        pending_config_change: pending_config_change.key(),
+        config_change_id: pending_config_change.id,
+        args,
+        registration_fee_payout_wallet: pending_config_change.registration_fee_payout_wallet,
+        slashing_destination_usdc_account: pending_config_change.slashing_destination_usdc_account,
+        slashing_destination_token_account: pending_config_change
+            .slashing_destination_token_account,
+        execute_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::SetInstructionPauseEvent,
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction, PoolOverview},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetInstructionPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        constraint = pool_overview.program_admin == authority.key()
+            || pool_overview.halt_authorities.contains(authority.key)
+            @ ErrorCode::InvalidPauseAuthority,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetInstructionPauseArgs {
+    /// Bitmask of the instructions to pause or unpause, indexed by PausableInstruction.
+    pub instructions_mask: u64,
+
+    /// Whether the instructions should be paused.
+    pub is_paused: bool,
+
+    /// Timestamp after which the pause lapses. Ignored when unpausing.
+    pub expires_at: Option<i64>,
+}
+
+/// Instruction for the program admin or a halt authority to pause individual instructions. Only
+/// the program admin can unpause instructions or shorten an active pause.
+pub fn handler(ctx: Context<SetInstructionPause>, args: SetInstructionPauseArgs) -> Result<()> {
+    let args_hash = AdminAuditLog::hash_args(&args);
+    let SetInstructionPauseArgs {
+        instructions_mask,
+        is_paused,
+        expires_at,
+    } = args;
+
+    require!(
+        instructions_mask != 0 && instructions_mask & !PausableInstruction::ALL_MASK == 0,
+        ErrorCode::InvalidPauseMask
+    );
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    if is_paused {
+        if let Some(expires_at) = expires_at {
+            require_gt!(expires_at, current_timestamp, ErrorCode::InvalidPauseExpiry);
+        }
+    }
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+
+    // Halt authorities can only pause, so that a single halt authority cannot lift a pause set by
+    // the program admin or another halt authority.
+    if pool_overview.program_admin != ctx.accounts.authority.key() {
+        require!(
+            is_paused
+                && !pool_overview.shortens_instruction_pause(
+                    instructions_mask,
+                    expires_at,
+                    current_timestamp
+                ),
+            ErrorCode::InvalidUnpauseAuthority
+        );
+    }
+    pool_overview.set_instruction_pause(instructions_mask, is_paused, expires_at);
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.authority.key(),
+        AdminActionKind::SetInstructionPause,
+        args_hash,
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(SetInstructionPauseEvent {
+        version: SetInstructionPauseEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        authority: ctx.accounts.authority.key(),
+        instructions_mask,
+        is_paused,
+        expires_at: if is_paused { expires_at } else { None },
+        paused_instructions: pool_overview.paused_instructions,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
-use crate::{constants::MIN_SLASHING_DELAY_SECONDS, error::ErrorCode, PoolOverview};
+use crate::{
+    constants::MIN_SLASHING_DELAY_SECONDS,
+    error::ErrorCode,
+    events::{InitiateWindDownEvent, UpdatePoolOverviewEvent},
+    state::{AdminActionKind, AdminAuditLog, OperatorPool, PausableInstruction, MAX_APPROVALS},
+    PoolOverview,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdatePoolOverview<'info> {
     pub program_admin: Signer<'info>,
@@ -22,9 +30,21 @@ pub struct UpdatePoolOverview<'info> {
 
     /// CHECK: This is the destination account for slashed tokens.
     pub slashing_destination_token_account: Option<UncheckedAccount<'info>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default, PartialEq, InitSpace)]
 pub struct UpdatePoolOverviewArgs {
     pub is_staking_halted: Option<bool>,
     pub is_withdrawal_halted: Option<bool>,
@@ -35,77 +55,310 @@ pub struct UpdatePoolOverviewArgs {
     pub delegator_unstake_delay_seconds: Option<u64>,
     pub operator_unstake_delay_seconds: Option<u64>,
     pub slashing_delay_seconds: Option<u64>,
+    pub max_commission_rate_bps: Option<u16>,
+    pub max_commission_increase_bps: Option<u16>,
+    /// Removes the program-wide max. commission rate. Cannot be combined with setting it.
+    pub clear_max_commission_rate_bps: bool,
+    /// Removes the max. commission increase per change. Cannot be combined with setting it.
+    pub clear_max_commission_increase_bps: bool,
+    pub commission_increase_notice_epochs: Option<u64>,
+    pub commission_decrease_notice_epochs: Option<u64>,
+    pub max_delegator_slash_ratio_bps: Option<u32>,
+    /// Removes the max. delegator slash ratio. Cannot be combined with setting it.
+    pub clear_max_delegator_slash_ratio_bps: bool,
+    pub slashing_approval_threshold: Option<u8>,
+    pub slashing_appeal_period_seconds: Option<u64>,
+    pub slash_redistribution_bps: Option<u16>,
+    pub slash_burn_bps: Option<u16>,
+    pub insurance_slash_bps: Option<u16>,
+    pub insurance_commission_bps: Option<u16>,
+    pub config_change_timelock_seconds: Option<u64>,
+    pub reward_distribution_approval_threshold: Option<u8>,
+    pub halt_approval_threshold: Option<u8>,
+    /// Puts the program into wind-down with this final reward epoch. Wind-down cannot be reverted.
+    pub wind_down_final_epoch: Option<u64>,
 }
 
-/// Instruction to update settings on PoolOverview.
-pub fn handler(ctx: Context<UpdatePoolOverview>, args: UpdatePoolOverviewArgs) -> Result<()> {
-    let UpdatePoolOverviewArgs {
-        is_staking_halted,
-        is_withdrawal_halted,
-        is_accrue_reward_halted,
-        allow_pool_creation,
-        operator_pool_registration_fee,
-        min_operator_token_stake,
-        delegator_unstake_delay_seconds,
-        operator_unstake_delay_seconds,
-        slashing_delay_seconds,
-    } = args;
+impl UpdatePoolOverviewArgs {
+    /// Whether any setting other than the emergency halts is changed. These changes must be
+    /// queued in a PendingConfigChange once a config change timelock is set.
+    pub fn has_timelocked_changes(&self) -> bool {
+        let mut timelocked_changes = self.clone();
+        timelocked_changes.is_staking_halted = None;
+        timelocked_changes.is_withdrawal_halted = None;
+        timelocked_changes.is_accrue_reward_halted = None;
+        timelocked_changes != Self::default()
+    }
 
-    let pool_overview = &mut ctx.accounts.pool_overview;
+    /// Applies the changed settings to PoolOverview.
+    pub fn apply(self, pool_overview: &mut PoolOverview) -> Result<()> {
+        let UpdatePoolOverviewArgs {
+            is_staking_halted,
+            is_withdrawal_halted,
+            is_accrue_reward_halted,
+            allow_pool_creation,
+            operator_pool_registration_fee,
+            min_operator_token_stake,
+            delegator_unstake_delay_seconds,
+            operator_unstake_delay_seconds,
+            slashing_delay_seconds,
+            max_commission_rate_bps,
+            max_commission_increase_bps,
+            clear_max_commission_rate_bps,
+            clear_max_commission_increase_bps,
+            commission_increase_notice_epochs,
+            commission_decrease_notice_epochs,
+            max_delegator_slash_ratio_bps,
+            clear_max_delegator_slash_ratio_bps,
+            slashing_approval_threshold,
+            slashing_appeal_period_seconds,
+            slash_redistribution_bps,
+            slash_burn_bps,
+            insurance_slash_bps,
+            insurance_commission_bps,
+            config_change_timelock_seconds,
+            reward_distribution_approval_threshold,
+            halt_approval_threshold,
+            wind_down_final_epoch,
+        } = self;
 
-    if let Some(min_operator_token_stake) = min_operator_token_stake {
-        pool_overview.min_operator_token_stake = min_operator_token_stake;
-    }
+        if let Some(min_operator_token_stake) = min_operator_token_stake {
+            pool_overview.min_operator_token_stake = min_operator_token_stake;
+        }
 
-    if let Some(is_staking_halted) = is_staking_halted {
-        pool_overview.is_staking_halted = is_staking_halted;
-    }
+        if let Some(is_staking_halted) = is_staking_halted {
+            pool_overview.is_staking_halted = is_staking_halted;
+        }
 
-    if let Some(is_withdrawal_halted) = is_withdrawal_halted {
-        pool_overview.is_withdrawal_halted = is_withdrawal_halted;
-    }
+        if let Some(is_withdrawal_halted) = is_withdrawal_halted {
+            pool_overview.is_withdrawal_halted = is_withdrawal_halted;
+        }
 
-    if let Some(is_accrue_reward_halted) = is_accrue_reward_halted {
-        pool_overview.is_accrue_reward_halted = is_accrue_reward_halted;
-    }
+        if let Some(is_accrue_reward_halted) = is_accrue_reward_halted {
+            pool_overview.is_accrue_reward_halted = is_accrue_reward_halted;
+        }
 
-    if let Some(allow_pool_creation) = allow_pool_creation {
-        pool_overview.allow_pool_creation = allow_pool_creation;
-    }
+        if let Some(allow_pool_creation) = allow_pool_creation {
+            pool_overview.allow_pool_creation = allow_pool_creation;
+        }
 
-    if let Some(operator_pool_registration_fee) = operator_pool_registration_fee {
-        pool_overview.operator_pool_registration_fee = operator_pool_registration_fee;
-    }
+        if let Some(operator_pool_registration_fee) = operator_pool_registration_fee {
+            pool_overview.operator_pool_registration_fee = operator_pool_registration_fee;
+        }
 
-    if let Some(delegator_unstake_delay_seconds) = delegator_unstake_delay_seconds {
-        pool_overview.delegator_unstake_delay_seconds = delegator_unstake_delay_seconds;
-    }
+        if let Some(delegator_unstake_delay_seconds) = delegator_unstake_delay_seconds {
+            pool_overview.delegator_unstake_delay_seconds = delegator_unstake_delay_seconds;
+        }
+
+        if let Some(operator_unstake_delay_seconds) = operator_unstake_delay_seconds {
+            pool_overview.operator_unstake_delay_seconds = operator_unstake_delay_seconds;
+        }
+
+        if let Some(slashing_delay_seconds) = slashing_delay_seconds {
+            require!(
+                slashing_delay_seconds >= MIN_SLASHING_DELAY_SECONDS,
+                ErrorCode::InvalidSlashingDelay
+            );
+            pool_overview.slashing_delay_seconds = slashing_delay_seconds;
+        }
+
+        if let Some(max_commission_rate_bps) = max_commission_rate_bps {
+            require!(
+                !clear_max_commission_rate_bps,
+                ErrorCode::ConflictingConfigChange
+            );
+            OperatorPool::validate_commission_rate(max_commission_rate_bps)?;
+            pool_overview.max_commission_rate_bps = Some(max_commission_rate_bps);
+        } else if clear_max_commission_rate_bps {
+            pool_overview.max_commission_rate_bps = None;
+        }
+
+        if let Some(max_commission_increase_bps) = max_commission_increase_bps {
+            require!(
+                !clear_max_commission_increase_bps,
+                ErrorCode::ConflictingConfigChange
+            );
+            OperatorPool::validate_commission_rate(max_commission_increase_bps)?;
+            pool_overview.max_commission_increase_bps = Some(max_commission_increase_bps);
+        } else if clear_max_commission_increase_bps {
+            pool_overview.max_commission_increase_bps = None;
+        }
+
+        if let Some(commission_increase_notice_epochs) = commission_increase_notice_epochs {
+            pool_overview.commission_increase_notice_epochs = commission_increase_notice_epochs;
+        }
+
+        if let Some(commission_decrease_notice_epochs) = commission_decrease_notice_epochs {
+            pool_overview.commission_decrease_notice_epochs = commission_decrease_notice_epochs;
+        }
+
+        if let Some(max_delegator_slash_ratio_bps) = max_delegator_slash_ratio_bps {
+            require!(
+                !clear_max_delegator_slash_ratio_bps,
+                ErrorCode::ConflictingConfigChange
+            );
+            pool_overview.max_delegator_slash_ratio_bps = Some(max_delegator_slash_ratio_bps);
+        } else if clear_max_delegator_slash_ratio_bps {
+            pool_overview.max_delegator_slash_ratio_bps = None;
+        }
+
+        if let Some(slashing_approval_threshold) = slashing_approval_threshold {
+            require_gte!(
+                MAX_APPROVALS,
+                usize::from(slashing_approval_threshold),
+                ErrorCode::InvalidSlashingApprovalThreshold
+            );
+            pool_overview.slashing_approval_threshold = slashing_approval_threshold;
+        }
+
+        if let Some(slashing_appeal_period_seconds) = slashing_appeal_period_seconds {
+            pool_overview.slashing_appeal_period_seconds = slashing_appeal_period_seconds;
+        }
 
-    if let Some(operator_unstake_delay_seconds) = operator_unstake_delay_seconds {
-        pool_overview.operator_unstake_delay_seconds = operator_unstake_delay_seconds;
+        if let Some(slash_redistribution_bps) = slash_redistribution_bps {
+            pool_overview.slash_redistribution_bps = slash_redistribution_bps;
+        }
+
+        if let Some(slash_burn_bps) = slash_burn_bps {
+            pool_overview.slash_burn_bps = slash_burn_bps;
+        }
+
+        if let Some(insurance_slash_bps) = insurance_slash_bps {
+            pool_overview.insurance_slash_bps = insurance_slash_bps;
+        }
+
+        if let Some(insurance_commission_bps) = insurance_commission_bps {
+            require_gte!(
+                10_000,
+                insurance_commission_bps,
+                ErrorCode::InvalidInsuranceCommissionShare
+            );
+            pool_overview.insurance_commission_bps = insurance_commission_bps;
+        }
+
+        // Redistributed, burned and insured shares of slashed tokens cannot exceed the total.
+        require_gte!(
+            10_000,
+            u32::from(pool_overview.slash_redistribution_bps)
+                + u32::from(pool_overview.slash_burn_bps)
+                + u32::from(pool_overview.insurance_slash_bps),
+            ErrorCode::InvalidSlashSplit
+        );
+
+        // Commission increases must always have at least as much notice as decreases.
+        require_gte!(
+            pool_overview.commission_increase_notice_epochs,
+            pool_overview.commission_decrease_notice_epochs,
+            ErrorCode::InvalidCommissionNoticePeriod
+        );
+
+        if let Some(reward_distribution_approval_threshold) = reward_distribution_approval_threshold
+        {
+            require_gte!(
+                MAX_APPROVALS,
+                usize::from(reward_distribution_approval_threshold),
+                ErrorCode::InvalidApprovalThreshold
+            );
+            pool_overview.reward_distribution_approval_threshold =
+                reward_distribution_approval_threshold;
+        }
+
+        if let Some(halt_approval_threshold) = halt_approval_threshold {
+            require_gte!(
+                MAX_APPROVALS,
+                usize::from(halt_approval_threshold),
+                ErrorCode::InvalidApprovalThreshold
+            );
+            pool_overview.halt_approval_threshold = halt_approval_threshold;
+        }
+
+        if let Some(config_change_timelock_seconds) = config_change_timelock_seconds {
+            pool_overview.config_change_timelock_seconds = config_change_timelock_seconds;
+        }
+
+        pool_overview.validate_approval_thresholds()?;
+
+        if let Some(wind_down_final_epoch) = wind_down_final_epoch {
+            pool_overview
+                .initiate_wind_down(wind_down_final_epoch, Clock::get()?.unix_timestamp)?;
+        }
+
+        Ok(())
     }
+}
+
+/// Instruction to update settings on PoolOverview. Once a config change timelock is set, only the
+/// emergency halts can be changed instantly, and other changes must be queued.
+pub fn handler(ctx: Context<UpdatePoolOverview>, args: UpdatePoolOverviewArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::UpdatePoolOverview)?;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
 
-    if let Some(slashing_delay_seconds) = slashing_delay_seconds {
+    let registration_fee_payout_wallet = ctx
+        .accounts
+        .registration_fee_payout_wallet
+        .as_ref()
+        .map(|account| account.key());
+    let slashing_destination_usdc_account = ctx
+        .accounts
+        .slashing_destination_usdc_account
+        .as_ref()
+        .map(|account| account.key());
+    let slashing_destination_token_account = ctx
+        .accounts
+        .slashing_destination_token_account
+        .as_ref()
+        .map(|account| account.key());
+
+    if pool_overview.config_change_timelock_seconds > 0 {
         require!(
-            slashing_delay_seconds >= MIN_SLASHING_DELAY_SECONDS,
-            ErrorCode::InvalidSlashingDelay
+            !args.has_timelocked_changes()
+                && registration_fee_payout_wallet.is_none()
+                && slashing_destination_usdc_account.is_none()
+                && slashing_destination_token_account.is_none(),
+            ErrorCode::ConfigChangeRequiresTimelock
         );
-        pool_overview.slashing_delay_seconds = slashing_delay_seconds;
     }
 
-    let registration_fee_payout_wallet = &ctx.accounts.registration_fee_payout_wallet;
-    if let Some(registration_fee_payout_wallet) = registration_fee_payout_wallet {
-        pool_overview.registration_fee_payout_wallet = registration_fee_payout_wallet.key();
-    }
+    let args_hash = AdminAuditLog::hash_args(&args);
+    let old_config = pool_overview.config();
+    let wind_down_final_epoch = args.wind_down_final_epoch;
+    args.apply(pool_overview)?;
+    pool_overview.update_destinations(
+        registration_fee_payout_wallet,
+        slashing_destination_usdc_account,
+        slashing_destination_token_account,
+    );
 
-    let slashing_destination_usdc_account = &ctx.accounts.slashing_destination_usdc_account;
-    if let Some(slashing_destination_usdc_account) = slashing_destination_usdc_account {
-        pool_overview.slashing_destination_usdc_account = slashing_destination_usdc_account.key();
-    }
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::UpdatePoolOverview,
+        args_hash,
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(UpdatePoolOverviewEvent {
+        version: UpdatePoolOverviewEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        old_config,
+        new_config: pool_overview.config(),
+    });
 
-    let slashing_destination_token_account = &ctx.accounts.slashing_destination_token_account;
-    if let Some(slashing_destination_token_account) = slashing_destination_token_account {
-        pool_overview.slashing_destination_token_account = slashing_destination_token_account.key();
+    if let Some(final_epoch) = wind_down_final_epoch {
+        emit_cpi!(InitiateWindDownEvent {
+            version: InitiateWindDownEvent::VERSION,
+            instruction_index,
+            epoch: pool_overview.completed_reward_epoch + 1,
+            program_admin: ctx.accounts.program_admin.key(),
+            final_epoch,
+            wind_down_at: pool_overview.wind_down_at.unwrap(),
+        });
     }
 
     Ok(())
@@ -1,22 +1,46 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
-use crate::{error::ErrorCode, PoolOverview};
+use crate::{
+    error::ErrorCode,
+    events::UpdatePoolOverviewAuthoritiesEvent,
+    state::{AdminActionKind, AdminAuditLog, PausableInstruction, MAX_AUTHORITIES},
+    PoolOverview,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: UpdatePoolOverviewAuthoritiesArgs)]
 pub struct UpdatePoolOverviewAuthorities<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub program_admin: Signer<'info>,
 
     #[account(
         mut,
         seeds = [PoolOverview::SEED],
         bump = pool_overview.bump,
-        has_one = program_admin @ ErrorCode::InvalidProgramAdmin
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin,
+        realloc = args.required_space(&pool_overview),
+        realloc::payer = payer,
+        realloc::zero = false,
     )]
     pub pool_overview: Box<Account<'info, PoolOverview>>,
 
-    // Optional new admin that must be a signer if provided
-    #[account(signer)]
-    pub new_program_admin: Option<AccountInfo<'info>>,
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -24,39 +48,121 @@ pub struct UpdatePoolOverviewAuthoritiesArgs {
     pub new_reward_distribution_authorities: Option<Vec<Pubkey>>,
     pub new_halt_authorities: Option<Vec<Pubkey>>,
     pub new_slashing_authorities: Option<Vec<Pubkey>>,
+    pub new_epoch_authorities: Option<Vec<Pubkey>>,
+}
+
+impl UpdatePoolOverviewAuthoritiesArgs {
+    /// Returns the PoolOverview account space required to hold the authorities after the update.
+    pub fn required_space(&self, pool_overview: &PoolOverview) -> usize {
+        let len = |new: &Option<Vec<Pubkey>>, current: &Vec<Pubkey>| {
+            new.as_ref().map_or(current.len(), Vec::len)
+        };
+        PoolOverview::space_with_authorities(&[
+            len(
+                &self.new_reward_distribution_authorities,
+                &pool_overview.reward_distribution_authorities,
+            ),
+            len(&self.new_halt_authorities, &pool_overview.halt_authorities),
+            len(
+                &self.new_slashing_authorities,
+                &pool_overview.slashing_authorities,
+            ),
+            len(
+                &self.new_epoch_authorities,
+                &pool_overview.epoch_authorities,
+            ),
+        ])
+    }
 }
 
 /// Instruction to update authorities on PoolOverview.
+///
+/// The PoolOverview account is reallocated to fit the authority lists, with the difference in
+/// rent paid to or refunded to the payer.
 pub fn handler(
     ctx: Context<UpdatePoolOverviewAuthorities>,
     args: UpdatePoolOverviewAuthoritiesArgs,
 ) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::UpdatePoolOverviewAuthorities)?;
+
+    let args_hash = AdminAuditLog::hash_args(&args);
     let UpdatePoolOverviewAuthoritiesArgs {
         new_reward_distribution_authorities,
         new_halt_authorities,
         new_slashing_authorities,
+        new_epoch_authorities,
     } = args;
 
     let pool_overview = &mut ctx.accounts.pool_overview;
-
-    if let Some(new_program_admin_info) = &ctx.accounts.new_program_admin {
-        pool_overview.program_admin = new_program_admin_info.key();
-    }
+    let old_reward_distribution_authorities = pool_overview.reward_distribution_authorities.clone();
+    let old_halt_authorities = pool_overview.halt_authorities.clone();
+    let old_slashing_authorities = pool_overview.slashing_authorities.clone();
+    let old_epoch_authorities = pool_overview.epoch_authorities.clone();
 
     if let Some(authorities) = new_reward_distribution_authorities {
-        require_gte!(5, authorities.len(), ErrorCode::AuthoritiesExceeded);
+        require_gte!(
+            MAX_AUTHORITIES,
+            authorities.len(),
+            ErrorCode::AuthoritiesExceeded
+        );
         pool_overview.reward_distribution_authorities = authorities;
     }
 
     if let Some(authorities) = new_slashing_authorities {
-        require_gte!(5, authorities.len(), ErrorCode::AuthoritiesExceeded);
+        require_gte!(
+            MAX_AUTHORITIES,
+            authorities.len(),
+            ErrorCode::AuthoritiesExceeded
+        );
         pool_overview.slashing_authorities = authorities;
     }
 
     if let Some(authorities) = new_halt_authorities {
-        require_gte!(5, authorities.len(), ErrorCode::AuthoritiesExceeded);
+        require_gte!(
+            MAX_AUTHORITIES,
+            authorities.len(),
+            ErrorCode::AuthoritiesExceeded
+        );
         pool_overview.halt_authorities = authorities;
     }
 
+    if let Some(authorities) = new_epoch_authorities {
+        require_gte!(
+            MAX_AUTHORITIES,
+            authorities.len(),
+            ErrorCode::AuthoritiesExceeded
+        );
+        pool_overview.epoch_authorities = authorities;
+    }
+
+    // Removing authorities cannot leave an approval threshold that can no longer be met.
+    pool_overview.validate_approval_thresholds()?;
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::UpdatePoolOverviewAuthorities,
+        args_hash,
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(UpdatePoolOverviewAuthoritiesEvent {
+        version: UpdatePoolOverviewAuthoritiesEvent::VERSION,
+        instruction_index,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        old_reward_distribution_authorities,
+        new_reward_distribution_authorities: pool_overview.reward_distribution_authorities.clone(),
+        old_halt_authorities,
+        new_halt_authorities: pool_overview.halt_authorities.clone(),
+        old_slashing_authorities,
+        new_slashing_authorities: pool_overview.slashing_authorities.clone(),
+        old_epoch_authorities,
+        new_epoch_authorities: pool_overview.epoch_authorities.clone(),
+    });
+
     Ok(())
 }
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    constants::USDC_MINT_PUBKEY,
+    error::ErrorCode,
+    events::ClaimReferralEarningsEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview, ReferralRecord},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralEarnings<'info> {
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            ReferralRecord::SEED,
+            operator_pool.key().as_ref(),
+            referrer.key().as_ref()
+        ],
+        bump = referral_record.bump,
+        has_one = referrer,
+        has_one = operator_pool,
+    )]
+    pub referral_record: Box<Account<'info, ReferralRecord>>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::GLOBAL_TOKEN_REWARD_VAULT_SEED],
+        bump,
+    )]
+    pub reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::GLOBAL_USDC_EARNINGS_VAULT_SEED],
+        bump,
+    )]
+    pub usdc_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Destination account for the reward token earnings.
+    #[account(
+        mut,
+        token::mint = pool_overview.mint,
+    )]
+    pub reward_destination: Box<Account<'info, TokenAccount>>,
+
+    /// Destination account for the USDC earnings. Must be a USDC token account.
+    #[account(
+        mut,
+        constraint = usdc_destination.mint == USDC_MINT_PUBKEY @ ErrorCode::InvalidUsdcMint,
+    )]
+    pub usdc_destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to claim referral reward token and USDC earnings from a ReferralRecord.
+pub fn handler(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ClaimReferralEarnings)?;
+
+    let operator_pool = &ctx.accounts.operator_pool;
+    let referral_record = &mut ctx.accounts.referral_record;
+
+    // Check that global withdrawal has not been halted.
+    require!(
+        !ctx.accounts.pool_overview.is_withdrawal_halted,
+        ErrorCode::WithdrawalsHalted
+    );
+
+    // Ensure all token rewards have been claimed, so that referral earnings are up to date.
+    require_gte!(
+        operator_pool.reward_last_claimed_epoch,
+        ctx.accounts.pool_overview.completed_reward_epoch,
+        ErrorCode::UnclaimedRewards
+    );
+
+    operator_pool.settle_referral_earnings(referral_record)?;
+
+    let reward_amount = referral_record.accrued_reward_earnings;
+    let usdc_amount = referral_record.accrued_usdc_earnings;
+    require!(
+        reward_amount > 0 || usdc_amount > 0,
+        ErrorCode::NoReferralEarningsToClaim
+    );
+
+    let pool_overview_bump = ctx.accounts.pool_overview.bump;
+
+    if reward_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_token_account.to_account_info(),
+                    to: ctx.accounts.reward_destination.to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[&[PoolOverview::SEED, &[pool_overview_bump]]],
+            ),
+            reward_amount,
+        )?;
+    }
+
+    if usdc_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.usdc_token_account.to_account_info(),
+                    to: ctx.accounts.usdc_destination.to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[&[PoolOverview::SEED, &[pool_overview_bump]]],
+            ),
+            usdc_amount,
+        )?;
+    }
+
+    // Reset available balances
+    referral_record.accrued_reward_earnings = 0;
+    referral_record.accrued_usdc_earnings = 0;
+
+    // Referral earnings are held in the global vaults until claimed.
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    pool_overview.unclaimed_rewards = pool_overview
+        .unclaimed_rewards
+        .checked_sub(reward_amount)
+        .unwrap();
+    pool_overview.unclaimed_usdc = pool_overview
+        .unclaimed_usdc
+        .checked_sub(usdc_amount)
+        .unwrap();
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ClaimReferralEarningsEvent {
+        version: ClaimReferralEarningsEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: pool_overview.completed_reward_epoch + 1,
+        referral_record: referral_record.key(),
+        referrer: ctx.accounts.referrer.key(),
+        reward_amount,
+        usdc_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::CreateReferralRecordEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview, ReferralRecord},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateReferralRecord<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Wallet that receives referral earnings. Any account can be a referrer.
+    pub referrer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        init,
+        seeds = [
+            ReferralRecord::SEED,
+            operator_pool.key().as_ref(),
+            referrer.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + ReferralRecord::INIT_SPACE + ReferralRecord::PADDING
+    )]
+    pub referral_record: Box<Account<'info, ReferralRecord>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to setup a ReferralRecord for a referrer of an OperatorPool.
+pub fn handler(ctx: Context<CreateReferralRecord>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreateReferralRecord)?;
+
+    let referral_record = &mut ctx.accounts.referral_record;
+    referral_record.version = ReferralRecord::VERSION;
+    referral_record.bump = ctx.bumps.referral_record;
+    referral_record.referrer = ctx.accounts.referrer.key();
+    referral_record.operator_pool = ctx.accounts.operator_pool.key();
+    referral_record.last_settled_referral_reward_per_share = ctx
+        .accounts
+        .operator_pool
+        .cumulative_referral_reward_per_share;
+    referral_record.last_settled_referral_usdc_per_share = ctx
+        .accounts
+        .operator_pool
+        .cumulative_referral_usdc_per_share;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreateReferralRecordEvent {
+        version: CreateReferralRecordEvent::VERSION,
+        instruction_index,
+        operator_pool: referral_record.operator_pool,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        referral_record: referral_record.key(),
+        referrer: referral_record.referrer,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod claim_referral_earnings;
+pub mod create_referral_record;
+
+pub use claim_referral_earnings::*;
+pub use create_referral_record::*;
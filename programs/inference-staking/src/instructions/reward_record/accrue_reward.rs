@@ -6,8 +6,9 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::constants::USDC_PRECISION_FACTOR;
 use crate::error::ErrorCode;
 use crate::events::{AccrueRewardEvent, OperatorAutoStakeEvent};
-use crate::state::{OperatorPool, PoolOverview, RewardRecord, StakingRecord};
+use crate::state::{OperatorPool, PausableInstruction, PoolOverview, RewardRecord, StakingRecord};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AccrueReward<'info> {
     #[account(
@@ -24,7 +25,9 @@ pub struct AccrueReward<'info> {
             &reward_record.epoch.to_le_bytes()
         ],
         bump,
-        constraint = reward_record.epoch == operator_pool.reward_last_claimed_epoch + 1
+        constraint = reward_record.epoch == operator_pool.reward_last_claimed_epoch + 1,
+        constraint = reward_record.version == RewardRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub reward_record: Box<Account<'info, RewardRecord>>,
 
@@ -36,12 +39,16 @@ pub struct AccrueReward<'info> {
         ],
         bump = operator_pool.bump,
         has_one = operator_staking_record,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Box<Account<'info, OperatorPool>>,
 
     #[account(
         mut,
         address = operator_pool.operator_staking_record,
+        constraint = operator_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_staking_record: Box<Account<'info, StakingRecord>>,
 
@@ -101,20 +108,32 @@ pub struct AccrueRewardArgs {
     pub proof_path: Vec<bool>,
     pub reward_amount: u64,
     pub usdc_amount: u64,
+    /// Amount issued to the pool for each additional reward mint, in RewardRecord order.
+    pub reward_mint_amounts: Vec<u64>,
 }
 
 /// Instruction to accrue reward issued for an OperatorPool.
 pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::AccrueReward)?;
+
     let AccrueRewardArgs {
         merkle_index,
         proof,
         proof_path,
         reward_amount,
         usdc_amount,
+        reward_mint_amounts,
     } = args;
 
     let reward_record = &ctx.accounts.reward_record;
     let operator_pool = &mut ctx.accounts.operator_pool;
+    require_eq!(
+        reward_mint_amounts.len(),
+        reward_record.reward_mint_payouts.len(),
+        ErrorCode::InvalidRewardMintAmounts
+    );
     reward_record.verify_proof(
         merkle_index,
         operator_pool.key(),
@@ -122,6 +141,7 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
         proof_path,
         reward_amount,
         usdc_amount,
+        &reward_mint_amounts,
     )?;
 
     let pool_overview = &ctx.accounts.pool_overview;
@@ -143,7 +163,11 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
     let mut total_rewards_transferred = 0;
     let mut total_usdc_transferred = 0;
 
-    let reward_commission = u64::try_from(
+    // Apply pending commission rates that take effect from this epoch.
+    operator_pool.update_reward_commission_rate(reward_record.epoch);
+    operator_pool.update_usdc_commission_rate(reward_record.epoch);
+
+    let total_reward_commission = u64::try_from(
         u128::from(reward_amount)
             .checked_mul(operator_pool.reward_commission_rate_bps.into())
             .unwrap()
@@ -151,10 +175,10 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
             .unwrap(),
     )
     .unwrap();
-    let delegator_rewards = reward_amount.checked_sub(reward_commission).unwrap();
+    let delegator_rewards = reward_amount.checked_sub(total_reward_commission).unwrap();
 
     // Calculate USDC split
-    let usdc_commission = u64::try_from(
+    let total_usdc_commission = u64::try_from(
         u128::from(usdc_amount)
             .checked_mul(operator_pool.usdc_commission_rate_bps.into())
             .unwrap()
@@ -163,7 +187,33 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
     )
     .unwrap();
 
-    let usdc_delegator_amount = usdc_amount.checked_sub(usdc_commission).unwrap();
+    let usdc_delegator_amount = usdc_amount.checked_sub(total_usdc_commission).unwrap();
+
+    // Calculate the InsuranceFund share of commission. Insurance contributions remain in the
+    // global vaults until deposited into the InsuranceFund.
+    let insurance_reward_commission =
+        pool_overview.calc_insurance_commission(total_reward_commission);
+    let insurance_usdc_commission = pool_overview.calc_insurance_commission(total_usdc_commission);
+    let total_reward_commission = total_reward_commission
+        .checked_sub(insurance_reward_commission)
+        .unwrap();
+    let total_usdc_commission = total_usdc_commission
+        .checked_sub(insurance_usdc_commission)
+        .unwrap();
+
+    // Calculate the referral share of commission earned on referred shares. Referral earnings
+    // remain in the global vaults until claimed by referrers.
+    let referral_reward_commission =
+        operator_pool.calc_referral_commission(total_reward_commission);
+    let referral_usdc_commission = operator_pool.calc_referral_commission(total_usdc_commission);
+    operator_pool.accrue_referral_commission(referral_reward_commission, referral_usdc_commission);
+
+    let reward_commission = total_reward_commission
+        .checked_sub(referral_reward_commission)
+        .unwrap();
+    let usdc_commission = total_usdc_commission
+        .checked_sub(referral_usdc_commission)
+        .unwrap();
 
     // Always accumulate rewards for correct accounting regardless if fund transfers occur or not
     operator_pool.accrued_rewards = operator_pool
@@ -182,6 +232,11 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
         .accrued_delegator_usdc
         .checked_add(usdc_delegator_amount)
         .unwrap();
+    for (i, reward_mint_amount) in reward_mint_amounts.iter().enumerate() {
+        operator_pool.accrued_reward_mint_amounts[i] = operator_pool.accrued_reward_mint_amounts[i]
+            .checked_add(*reward_mint_amount)
+            .unwrap();
+    }
 
     operator_pool.reward_last_claimed_epoch = operator_pool
         .reward_last_claimed_epoch
@@ -216,7 +271,8 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
                 .checked_add(new_shares)
                 .unwrap();
 
-            emit!(OperatorAutoStakeEvent {
+            emit_cpi!(OperatorAutoStakeEvent {
+                version: OperatorAutoStakeEvent::VERSION,
                 instruction_index,
                 operator_pool: operator_pool.key(),
                 epoch: pool_overview.completed_reward_epoch + 1,
@@ -302,16 +358,36 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
                 .unwrap();
         }
 
-        // Update USDC commission rate if new rate is pending
-        if let Some(new_rate) = operator_pool.new_usdc_commission_rate_bps {
-            operator_pool.usdc_commission_rate_bps = new_rate;
-            operator_pool.new_usdc_commission_rate_bps = None;
+        // Update cumulative reward per share index for each additional reward mint. These
+        // rewards remain in the global reward mint vaults until claimed by share owners.
+        for i in 0..operator_pool.accrued_reward_mint_amounts.len() {
+            let accrued_reward_mint_amount = operator_pool.accrued_reward_mint_amounts[i];
+            if accrued_reward_mint_amount == 0 {
+                continue;
+            }
+
+            if operator_pool.total_shares > 0 {
+                let reward_per_share_increase = (accrued_reward_mint_amount as u128)
+                    .checked_mul(USDC_PRECISION_FACTOR)
+                    .unwrap()
+                    .checked_div(operator_pool.total_shares as u128)
+                    .unwrap();
+
+                operator_pool.cumulative_reward_mint_per_share[i] = operator_pool
+                    .cumulative_reward_mint_per_share[i]
+                    .checked_add(reward_per_share_increase)
+                    .unwrap();
+            } else {
+                // No shares to distribute to, so credit the Operator to avoid stranding the
+                // amount in the global reward mint vault.
+                operator_staking_record.accrued_reward_mint_earnings[i] = operator_staking_record
+                    .accrued_reward_mint_earnings[i]
+                    .checked_add(accrued_reward_mint_amount)
+                    .unwrap();
+            }
+            operator_pool.accrued_reward_mint_amounts[i] = 0;
         }
 
-        // Update commission rates if necessary
-        operator_pool.update_reward_commission_rate();
-        operator_pool.update_usdc_commission_rate();
-
         // Update unclaimed token rewards
         let pool_overview = &mut ctx.accounts.pool_overview;
         pool_overview.unclaimed_rewards = pool_overview
@@ -343,7 +419,18 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
         operator_pool.accrued_delegator_usdc = 0;
     }
 
-    emit!(AccrueRewardEvent {
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    pool_overview.pending_insurance_rewards = pool_overview
+        .pending_insurance_rewards
+        .checked_add(insurance_reward_commission)
+        .unwrap();
+    pool_overview.pending_insurance_usdc = pool_overview
+        .pending_insurance_usdc
+        .checked_add(insurance_usdc_commission)
+        .unwrap();
+
+    emit_cpi!(AccrueRewardEvent {
+        version: AccrueRewardEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool.key(),
         epoch: reward_record.epoch,
@@ -353,6 +440,11 @@ pub fn handler(ctx: Context<AccrueReward>, args: AccrueRewardArgs) -> Result<()>
         operator_reward_commission: reward_commission,
         delegator_usdc_earnings: usdc_delegator_amount,
         operator_usdc_commission: usdc_commission,
+        reward_mint_amounts,
+        referral_reward_commission,
+        referral_usdc_commission,
+        insurance_reward_commission,
+        insurance_usdc_commission,
     });
 
     Ok(())
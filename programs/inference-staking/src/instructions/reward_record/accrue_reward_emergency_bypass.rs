@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
 use crate::error::ErrorCode;
-use crate::state::{OperatorPool, PoolOverview, RewardRecord};
+use crate::events::AccrueRewardEmergencyBypassEvent;
+use crate::state::{
+    AdminActionKind, AdminAuditLog, OperatorPool, PausableInstruction, PoolOverview, RewardRecord,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AccrueRewardEmergencyBypass<'info> {
     /// Only the pool admin can execute this emergency bypass
@@ -24,6 +29,8 @@ pub struct AccrueRewardEmergencyBypass<'info> {
         constraint = operator_pool.halted_at_timestamp.is_none() @ ErrorCode::OperatorPoolHalted,
         constraint = operator_pool.closed_at_epoch.is_none() @ ErrorCode::ClosedPool,
         has_one = admin,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Box<Account<'info, OperatorPool>>,
 
@@ -35,6 +42,8 @@ pub struct AccrueRewardEmergencyBypass<'info> {
         ],
         bump,
         constraint = current_pool_reward_record.epoch == operator_pool.reward_last_claimed_epoch + 1 @ ErrorCode::InvalidEmergencyBypassEpoch,
+        constraint = current_pool_reward_record.version == RewardRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub current_pool_reward_record: Box<Account<'info, RewardRecord>>,
 
@@ -46,8 +55,22 @@ pub struct AccrueRewardEmergencyBypass<'info> {
         ],
         bump,
         constraint = next_pool_reward_record.epoch == operator_pool.reward_last_claimed_epoch + 2 @ ErrorCode::InvalidEmergencyBypassEpoch,
+        constraint = next_pool_reward_record.version == RewardRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub next_pool_reward_record: Box<Account<'info, RewardRecord>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 /// Emergency instruction to bypass a reward accrual when an operator pool
@@ -55,6 +78,10 @@ pub struct AccrueRewardEmergencyBypass<'info> {
 /// to increment the reward_last_claimed_epoch to prevent the pool from
 /// getting stranded.
 pub fn handler(ctx: Context<AccrueRewardEmergencyBypass>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::AccrueRewardEmergencyBypass)?;
+
     let operator_pool = &mut ctx.accounts.operator_pool;
     let pool_overview = &ctx.accounts.pool_overview;
 
@@ -71,5 +98,23 @@ pub fn handler(ctx: Context<AccrueRewardEmergencyBypass>) -> Result<()> {
         .checked_add(1)
         .unwrap();
 
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.admin.key(),
+        AdminActionKind::AccrueRewardEmergencyBypass,
+        AdminAuditLog::hash_args(&(operator_pool.key(), operator_pool.reward_last_claimed_epoch)),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(AccrueRewardEmergencyBypassEvent {
+        version: AccrueRewardEmergencyBypassEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: pool_overview.completed_reward_epoch + 1,
+        bypassed_epoch: operator_pool.reward_last_claimed_epoch,
+    });
+
     Ok(())
 }
@@ -1,10 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{Token, TokenAccount};
 
 use crate::emissions::get_expected_reward_emissions_for_epoch;
 use crate::error::ErrorCode;
-use crate::state::{PoolOverview, RewardRecord};
+use crate::events::CreateRewardRecordEvent;
+use crate::state::{
+    AdminActionKind, AdminAuditLog, AuthorityRole, PausableInstruction, PendingAction,
+    PoolOverview, RewardRecord,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateRewardRecord<'info> {
     #[account(mut)]
@@ -46,7 +53,27 @@ pub struct CreateRewardRecord<'info> {
     )]
     pub usdc_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// Approved PendingAction, required if the reward distribution approval threshold is over 1.
+    #[account(
+        mut,
+        seeds = [PendingAction::SEED, &pending_action.id.to_le_bytes()],
+        bump = pending_action.bump,
+    )]
+    pub pending_action: Option<Box<Account<'info, PendingAction>>>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
     pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -54,25 +81,77 @@ pub struct CreateRewardRecordArgs {
     pub merkle_roots: Vec<[u8; 32]>,
     pub total_rewards: u64,
     pub total_usdc_payout: u64,
+    /// Amount issued for each additional reward mint, in PoolOverview registry order.
+    pub reward_mint_payouts: Vec<u64>,
+}
+
+impl CreateRewardRecordArgs {
+    /// Hash approved in a PendingAction to create the RewardRecord for an epoch with these args.
+    pub fn action_hash(&self, epoch: u64) -> [u8; 32] {
+        let args = borsh::to_vec(self).unwrap();
+        hashv(&[b"create_reward_record", &epoch.to_le_bytes(), &args]).to_bytes()
+    }
 }
 
 /// Instruction to setup a RewardRecord.
+///
+/// The global vault of each registered reward mint must be passed in as remaining accounts,
+/// in PoolOverview registry order, to verify that sufficient reward tokens are funded.
 pub fn handler(ctx: Context<CreateRewardRecord>, args: CreateRewardRecordArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreateRewardRecord)?;
+
+    let epoch = ctx
+        .accounts
+        .pool_overview
+        .completed_reward_epoch
+        .checked_add(1)
+        .unwrap();
+
+    let action_hash = args.action_hash(epoch);
+    if ctx
+        .accounts
+        .pool_overview
+        .requires_pending_action(AuthorityRole::RewardDistribution)
+    {
+        let pending_action = ctx
+            .accounts
+            .pending_action
+            .as_mut()
+            .ok_or(ErrorCode::PendingActionRequired)?;
+        pending_action.execute(
+            &ctx.accounts.pool_overview,
+            AuthorityRole::RewardDistribution,
+            action_hash,
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+
     let CreateRewardRecordArgs {
         merkle_roots,
         total_rewards,
         total_usdc_payout,
+        reward_mint_payouts,
     } = args;
 
     let pool_overview = &mut ctx.accounts.pool_overview;
     let reward_record = &mut ctx.accounts.reward_record;
 
-    let epoch = pool_overview.completed_reward_epoch.checked_add(1).unwrap();
+    require_eq!(
+        reward_mint_payouts.len(),
+        pool_overview.reward_mints.len(),
+        ErrorCode::InvalidRewardMintAmounts
+    );
 
     // If no merkle roots are provided then reward amounts must be zero.
     if merkle_roots.is_empty() {
         require_eq!(total_rewards, 0);
         require_eq!(total_usdc_payout, 0);
+        require!(
+            reward_mint_payouts.iter().all(|amount| *amount == 0),
+            ErrorCode::InvalidRewardMintAmounts
+        );
     } else {
         // If merkle roots are provided, verify that total_rewards matches expected emissions
         let expected_rewards = get_expected_reward_emissions_for_epoch(epoch)?;
@@ -89,6 +168,7 @@ pub fn handler(ctx: Context<CreateRewardRecord>, args: CreateRewardRecordArgs) -
     reward_record.total_rewards = total_rewards;
     reward_record.total_usdc_payout = total_usdc_payout;
     reward_record.epoch_finalized_at = Clock::get()?.unix_timestamp;
+    reward_record.reward_mint_payouts = reward_mint_payouts;
 
     // Update unclaimed rewards to include new epoch rewards.
     pool_overview.unclaimed_rewards = pool_overview
@@ -120,5 +200,72 @@ pub fn handler(ctx: Context<CreateRewardRecord>, args: CreateRewardRecordArgs) -
         ErrorCode::InsufficientUsdc
     );
 
+    // Update unclaimed amounts and ensure that there's sufficient tokens funded for
+    // each additional reward mint.
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        pool_overview.reward_mints.len(),
+        ErrorCode::InvalidRewardMintVault
+    );
+    for (i, reward_mint) in pool_overview.reward_mints.iter_mut().enumerate() {
+        reward_mint.unclaimed_amount = reward_mint
+            .unclaimed_amount
+            .checked_add(reward_record.reward_mint_payouts[i])
+            .unwrap();
+
+        let vault_info = &ctx.remaining_accounts[i];
+        let (expected_vault, _) = Pubkey::find_program_address(
+            &[
+                PoolOverview::GLOBAL_REWARD_MINT_VAULT_SEED,
+                reward_mint.mint.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            vault_info.key(),
+            expected_vault,
+            ErrorCode::InvalidRewardMintVault
+        );
+        require_keys_eq!(
+            *vault_info.owner,
+            Token::id(),
+            ErrorCode::InvalidRewardMintVault
+        );
+
+        let vault = TokenAccount::try_deserialize(&mut &vault_info.try_borrow_data()?[..])?;
+        require_gte!(
+            vault.amount,
+            reward_mint.unclaimed_amount,
+            ErrorCode::InsufficientRewardMintTokens
+        );
+    }
+
+    pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.authority.key(),
+        AdminActionKind::CreateRewardRecord,
+        action_hash,
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreateRewardRecordEvent {
+        version: CreateRewardRecordEvent::VERSION,
+        instruction_index,
+        epoch,
+        reward_record: reward_record.key(),
+        authority: ctx.accounts.authority.key(),
+        merkle_roots: reward_record.merkle_roots.clone(),
+        total_rewards: reward_record.total_rewards,
+        total_usdc_payout: reward_record.total_usdc_payout,
+        reward_mint_payouts: reward_record.reward_mint_payouts.clone(),
+        pending_action: ctx
+            .accounts
+            .pending_action
+            .as_ref()
+            .map(|pending_action| pending_action.key()),
+    });
+
     Ok(())
 }
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    events::MigrateRewardRecordEvent,
+    state::{PausableInstruction, PoolOverview, RewardRecord},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateRewardRecord<'info> {
+    /// Payer of the rent for the additional account space.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [RewardRecord::SEED, &reward_record.epoch.to_le_bytes()],
+        bump,
+        realloc = 8 + RewardRecord::INIT_SPACE + RewardRecord::PADDING,
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub reward_record: Box<Account<'info, RewardRecord>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Permissionless instruction to reallocate a RewardRecord to the current layout and upgrade it
+/// to the current version.
+pub fn handler(ctx: Context<MigrateRewardRecord>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::MigrateRewardRecord)?;
+
+    let reward_record = &mut ctx.accounts.reward_record;
+    let from_version = reward_record.migrate()?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(MigrateRewardRecordEvent {
+        version: MigrateRewardRecordEvent::VERSION,
+        instruction_index,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        reward_record: reward_record.key(),
+        reward_record_epoch: reward_record.epoch,
+        payer: ctx.accounts.payer.key(),
+        from_version,
+        to_version: reward_record.version,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 pub mod accrue_reward;
 pub mod accrue_reward_emergency_bypass;
 pub mod create_reward_record;
+pub mod migrate_reward_record;
 
 pub use accrue_reward::*;
 pub use accrue_reward_emergency_bypass::*;
 pub use create_reward_record::*;
+pub use migrate_reward_record::*;
@@ -0,0 +1,5 @@
+pub mod release_slash_escrow;
+pub mod reverse_slash;
+
+pub use release_slash_escrow::*;
+pub use reverse_slash::*;
@@ -0,0 +1,330 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::{
+    constants::USDC_MINT_PUBKEY,
+    error::ErrorCode,
+    events::ReleaseSlashEscrowEvent,
+    state::{
+        AdminActionKind, AdminAuditLog, InsuranceFund, OperatorPool, PausableInstruction,
+        PoolOverview, SlashEscrow, StakingRecord,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleaseSlashEscrow<'info> {
+    /// Signer that releases the escrow. Any key can release it.
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        mut,
+        address = operator_pool.operator_staking_record,
+        constraint = operator_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_staking_record: Box<Account<'info, StakingRecord>>,
+
+    #[account(
+        mut,
+        seeds = [SlashEscrow::SEED, slash_escrow.slash_proposal.as_ref()],
+        bump = slash_escrow.bump,
+        has_one = operator_pool,
+    )]
+    pub slash_escrow: Box<Account<'info, SlashEscrow>>,
+
+    #[account(
+        mut,
+        seeds = [SlashEscrow::ESCROW_TOKEN_VAULT_SEED, slash_escrow.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SlashEscrow::ESCROW_USDC_VAULT_SEED, slash_escrow.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::POOL_STAKED_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
+        bump,
+    )]
+    pub staked_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = pool_overview.mint,
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// InsuranceFund that receives its share of the slash. Required if the escrow has an
+    /// insurance share.
+    #[account(
+        mut,
+        seeds = [InsuranceFund::SEED],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Option<Box<Account<'info, InsuranceFund>>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::INSURANCE_TOKEN_VAULT_SEED],
+        bump,
+    )]
+    pub insurance_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [InsuranceFund::INSURANCE_USDC_VAULT_SEED],
+        bump,
+    )]
+    pub insurance_usdc_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // Destination for slashed tokens - must match pool_overview configuration
+    #[account(
+        mut,
+        address = pool_overview.slashing_destination_token_account,
+    )]
+    pub slashing_destination_token_account: Box<Account<'info, TokenAccount>>,
+
+    // Destination for slashed USDC - must match pool_overview configuration
+    #[account(
+        mut,
+        constraint = slashing_destination_usdc_account.mint == USDC_MINT_PUBKEY,
+        address = pool_overview.slashing_destination_usdc_account,
+    )]
+    pub slashing_destination_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to release escrowed slashed funds once the appeal period has ended. Slashed tokens
+/// are split between the pool's non-operator delegators, burn, the InsuranceFund and the slashing
+/// destination. USDC is split between the InsuranceFund and the slashing destination.
+/// Permissionless.
+pub fn handler(ctx: Context<ReleaseSlashEscrow>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ReleaseSlashEscrow)?;
+
+    let slash_escrow = &mut ctx.accounts.slash_escrow;
+
+    require!(!slash_escrow.is_resolved(), ErrorCode::SlashEscrowResolved);
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require_gte!(
+        current_timestamp,
+        slash_escrow.release_at,
+        ErrorCode::SlashAppealPeriodNotEnded
+    );
+
+    let pool_overview_seeds: &[&[u8]] = &[PoolOverview::SEED, &[ctx.accounts.pool_overview.bump]];
+
+    // Redistribute to the pool's non-operator delegators. If the pool has no delegator stake
+    // left, the redistribution share is sent to the slashing destination instead.
+    let operator_pool = &mut ctx.accounts.operator_pool;
+    let operator_staking_record = &mut ctx.accounts.operator_staking_record;
+    let operator_tokens =
+        operator_pool.calc_tokens_for_share_amount(operator_staking_record.shares);
+    let redistribution_amount = if operator_pool.total_staked_amount > operator_tokens {
+        slash_escrow.redistribution_amount
+    } else {
+        0
+    };
+    let mut operator_shares_removed = 0;
+    if redistribution_amount > 0 {
+        operator_shares_removed = operator_pool
+            .redistribute_to_delegators(operator_staking_record, redistribution_amount)?;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_token_account.to_account_info(),
+                    to: ctx.accounts.staked_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[pool_overview_seeds],
+            ),
+            redistribution_amount,
+        )?;
+    }
+
+    let burn_amount = slash_escrow.burn_amount;
+    if burn_amount > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.escrow_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[pool_overview_seeds],
+            ),
+            burn_amount,
+        )?;
+    }
+
+    let insurance_token_amount = slash_escrow.insurance_token_amount;
+    let insurance_usdc_amount = slash_escrow.insurance_usdc_amount;
+    if insurance_token_amount > 0 || insurance_usdc_amount > 0 {
+        let (Some(insurance_fund), Some(insurance_token_account), Some(insurance_usdc_account)) = (
+            ctx.accounts.insurance_fund.as_mut(),
+            ctx.accounts.insurance_token_account.as_ref(),
+            ctx.accounts.insurance_usdc_account.as_ref(),
+        ) else {
+            return err!(ErrorCode::InsuranceFundRequired);
+        };
+
+        if insurance_token_amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.escrow_token_account.to_account_info(),
+                        to: insurance_token_account.to_account_info(),
+                        authority: ctx.accounts.pool_overview.to_account_info(),
+                    },
+                    &[pool_overview_seeds],
+                ),
+                insurance_token_amount,
+            )?;
+        }
+
+        if insurance_usdc_amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.escrow_usdc_account.to_account_info(),
+                        to: insurance_usdc_account.to_account_info(),
+                        authority: ctx.accounts.pool_overview.to_account_info(),
+                    },
+                    &[pool_overview_seeds],
+                ),
+                insurance_usdc_amount,
+            )?;
+        }
+        insurance_fund.record_deposit(insurance_token_amount, insurance_usdc_amount);
+    }
+
+    // Remaining escrowed tokens are sent to the slashing destination.
+    let token_amount = ctx
+        .accounts
+        .escrow_token_account
+        .amount
+        .checked_sub(redistribution_amount)
+        .unwrap()
+        .checked_sub(burn_amount)
+        .unwrap()
+        .checked_sub(insurance_token_amount)
+        .unwrap();
+    if token_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_token_account.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .slashing_destination_token_account
+                        .to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[pool_overview_seeds],
+            ),
+            token_amount,
+        )?;
+    }
+
+    let usdc_amount = ctx
+        .accounts
+        .escrow_usdc_account
+        .amount
+        .checked_sub(insurance_usdc_amount)
+        .unwrap();
+    if usdc_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_usdc_account.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .slashing_destination_usdc_account
+                        .to_account_info(),
+                    authority: ctx.accounts.pool_overview.to_account_info(),
+                },
+                &[pool_overview_seeds],
+            ),
+            usdc_amount,
+        )?;
+    }
+
+    // Pending unstakes can be settled against the unstaking slash from now on.
+    let operator_pool = &mut ctx.accounts.operator_pool;
+    if operator_pool.unstaking_slash_escrow == Some(slash_escrow.key()) {
+        operator_pool.release_unstaking_slash();
+    }
+
+    slash_escrow.released_at = Some(current_timestamp);
+
+    ctx.accounts.pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        slash_escrow.proposer,
+        AdminActionKind::ReleaseSlashEscrow,
+        AdminAuditLog::hash_args(&slash_escrow.key()),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ReleaseSlashEscrowEvent {
+        version: ReleaseSlashEscrowEvent::VERSION,
+        instruction_index,
+        operator_pool: slash_escrow.operator_pool,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        slash_escrow: slash_escrow.key(),
+        destination: ctx.accounts.slashing_destination_token_account.key(),
+        destination_usdc: ctx.accounts.slashing_destination_usdc_account.key(),
+        token_amount,
+        usdc_amount,
+        redistribution_amount,
+        operator_shares_removed,
+        burn_amount,
+        insurance_token_amount,
+        insurance_usdc_amount,
+    });
+
+    Ok(())
+}
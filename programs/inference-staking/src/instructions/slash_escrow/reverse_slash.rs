@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    error::ErrorCode,
+    events::ReverseSlashEvent,
+    state::{
+        AdminActionKind, AdminAuditLog, OperatorPool, PausableInstruction, PoolOverview,
+        SlashEscrow, StakingRecord,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReverseSlash<'info> {
+    pub program_admin: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        has_one = program_admin @ ErrorCode::InvalidProgramAdmin
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        mut,
+        has_one = operator_pool,
+        constraint = operator_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_staking_record: Box<Account<'info, StakingRecord>>,
+
+    #[account(
+        mut,
+        seeds = [SlashEscrow::SEED, slash_escrow.slash_proposal.as_ref()],
+        bump = slash_escrow.bump,
+        has_one = operator_pool,
+        has_one = operator_staking_record,
+    )]
+    pub slash_escrow: Box<Account<'info, SlashEscrow>>,
+
+    #[account(
+        mut,
+        seeds = [SlashEscrow::ESCROW_TOKEN_VAULT_SEED, slash_escrow.key().as_ref()],
+        bump,
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SlashEscrow::ESCROW_USDC_VAULT_SEED, slash_escrow.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::POOL_STAKED_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
+        bump,
+    )]
+    pub staked_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::POOL_DELEGATOR_USDC_EARNINGS_VAULT_SEED, operator_pool.key().as_ref()],
+        bump,
+    )]
+    pub pool_usdc_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::POOL_REWARD_COMMISSION_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
+        bump,
+    )]
+    pub reward_fee_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::POOL_USDC_COMMISSION_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
+        bump,
+    )]
+    pub usdc_fee_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// AdminAuditLog that the action is recorded in. Required once the AdminAuditLog is created.
+    #[account(
+        mut,
+        seeds = [AdminAuditLog::SEED],
+        bump = admin_audit_log.bump,
+    )]
+    pub admin_audit_log: Option<Box<Account<'info, AdminAuditLog>>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to reverse a slash during its appeal period. Escrowed funds are returned to the
+/// pool and shares are restored to the Operator's StakingRecord. Staking is not blocked while a
+/// slash is unresolved, so the restored pool-wide slash is credited to every share held at
+/// reversal time, including shares staked after the slash.
+pub fn handler(ctx: Context<ReverseSlash>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ReverseSlash)?;
+
+    let slash_escrow = &mut ctx.accounts.slash_escrow;
+    let operator_pool = &mut ctx.accounts.operator_pool;
+    let operator_staking_record = &mut ctx.accounts.operator_staking_record;
+
+    require!(!slash_escrow.is_resolved(), ErrorCode::SlashEscrowResolved);
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require_gt!(
+        slash_escrow.release_at,
+        current_timestamp,
+        ErrorCode::SlashAppealPeriodEnded
+    );
+
+    // Restore the pool-wide slash first, so that shares are restored at the unslashed share value.
+    operator_pool.total_staked_amount = operator_pool
+        .total_staked_amount
+        .checked_add(slash_escrow.pool_token_amount_slashed)
+        .unwrap();
+
+    // Restore the slashed pending unstakes. StakingRecords are not settled against the slash while
+    // the escrow is unresolved, so removing the slash restores each pending unstake.
+    if operator_pool.unstaking_slash_escrow == Some(slash_escrow.key()) {
+        operator_pool.reverse_unstaking_slash(slash_escrow.unstaking_token_amount_slashed);
+    }
+
+    // Restore Operator's slashed tokens as shares. Staking settles USDC and reward mint earnings
+    // first, so restored shares only earn from this point on.
+    let shares_restored =
+        operator_pool.stake_tokens(operator_staking_record, slash_escrow.token_amount_slashed)?;
+    operator_staking_record.shares = operator_staking_record
+        .shares
+        .checked_add(shares_restored)
+        .unwrap();
+
+    // Restore confiscated USDC earnings to the Operator's StakingRecord.
+    operator_staking_record.accrued_usdc_earnings = operator_staking_record
+        .accrued_usdc_earnings
+        .checked_add(slash_escrow.usdc_confiscated)
+        .unwrap();
+
+    let pool_overview_seeds: &[&[u8]] = &[PoolOverview::SEED, &[ctx.accounts.pool_overview.bump]];
+    let transfers = [
+        (
+            &ctx.accounts.escrow_token_account,
+            &ctx.accounts.staked_token_account,
+            slash_escrow
+                .token_amount_slashed
+                .checked_add(slash_escrow.pool_token_amount_slashed)
+                .unwrap()
+                .checked_add(slash_escrow.unstaking_token_amount_slashed)
+                .unwrap(),
+        ),
+        (
+            &ctx.accounts.escrow_token_account,
+            &ctx.accounts.reward_fee_token_account,
+            slash_escrow.reward_commission_confiscated,
+        ),
+        (
+            &ctx.accounts.escrow_usdc_account,
+            &ctx.accounts.usdc_fee_token_account,
+            slash_escrow.usdc_commission_confiscated,
+        ),
+        (
+            &ctx.accounts.escrow_usdc_account,
+            &ctx.accounts.pool_usdc_vault,
+            slash_escrow.usdc_confiscated,
+        ),
+    ];
+    for (from, to, amount) in transfers {
+        if amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: from.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: ctx.accounts.pool_overview.to_account_info(),
+                    },
+                    &[pool_overview_seeds],
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    slash_escrow.reversed_at = Some(current_timestamp);
+
+    ctx.accounts.pool_overview.record_admin_action(
+        ctx.accounts.admin_audit_log.as_deref_mut(),
+        ctx.accounts.program_admin.key(),
+        AdminActionKind::ReverseSlash,
+        AdminAuditLog::hash_args(&slash_escrow.key()),
+    )?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ReverseSlashEvent {
+        version: ReverseSlashEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        slash_escrow: slash_escrow.key(),
+        operator_staking_record: operator_staking_record.key(),
+        shares_restored,
+        token_amount_restored: slash_escrow.token_amount_slashed,
+        pool_token_amount_restored: slash_escrow.pool_token_amount_slashed,
+        unstaking_token_amount_restored: slash_escrow.unstaking_token_amount_slashed,
+        usdc_restored: slash_escrow.usdc_confiscated,
+        reward_commission_restored: slash_escrow.reward_commission_confiscated,
+        usdc_commission_restored: slash_escrow.usdc_commission_confiscated,
+    });
+
+    Ok(())
+}
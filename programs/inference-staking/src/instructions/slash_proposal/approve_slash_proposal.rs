@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::ApproveSlashProposalEvent,
+    state::{PausableInstruction, PoolOverview, SlashProposal, MAX_APPROVALS},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveSlashProposal<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        constraint = pool_overview.slashing_authorities.contains(authority.key)
+          @ ErrorCode::InvalidSlashingAuthority,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [
+            SlashProposal::SEED,
+            slash_proposal.operator_pool.as_ref(),
+            slash_proposal.evidence_hash.as_ref()
+        ],
+        bump = slash_proposal.bump,
+    )]
+    pub slash_proposal: Box<Account<'info, SlashProposal>>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction for a slashing authority to approve a SlashProposal.
+pub fn handler(ctx: Context<ApproveSlashProposal>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ApproveSlashProposal)?;
+
+    let authority = ctx.accounts.authority.key();
+    let pool_overview = &ctx.accounts.pool_overview;
+    let slash_proposal = &mut ctx.accounts.slash_proposal;
+
+    require!(
+        slash_proposal.executed_at.is_none(),
+        ErrorCode::SlashProposalAlreadyExecuted
+    );
+
+    // Drop approvals of keys that are no longer slashing authorities, to make space.
+    slash_proposal
+        .approvals
+        .retain(|approval| pool_overview.slashing_authorities.contains(approval));
+    require!(
+        !slash_proposal.approvals.contains(&authority),
+        ErrorCode::DuplicateSlashApproval
+    );
+    require_gt!(
+        MAX_APPROVALS,
+        slash_proposal.approvals.len(),
+        ErrorCode::ApprovalsExceeded
+    );
+    slash_proposal.approvals.push(authority);
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ApproveSlashProposalEvent {
+        version: ApproveSlashProposalEvent::VERSION,
+        instruction_index,
+        operator_pool: slash_proposal.operator_pool,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        slash_proposal: slash_proposal.key(),
+        authority,
+        approvals: u8::try_from(slash_proposal.approvals.len()).unwrap(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+
+use crate::{
+    error::ErrorCode,
+    events::CreateSlashProposalEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview, SlashProposal, SlashStakeArgs},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: CreateSlashProposalArgs)]
+pub struct CreateSlashProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        constraint = pool_overview.slashing_authorities.contains(authority.key)
+          @ ErrorCode::InvalidSlashingAuthority,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        init,
+        seeds = [
+            SlashProposal::SEED,
+            operator_pool.key().as_ref(),
+            args.evidence_hash.as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + SlashProposal::INIT_SPACE + SlashProposal::PADDING
+    )]
+    pub slash_proposal: Box<Account<'info, SlashProposal>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSlashProposalArgs {
+    /// Hash of the off-chain evidence for the slash.
+    pub evidence_hash: [u8; 32],
+
+    /// Slash to execute once the proposal is approved.
+    pub slash_args: SlashStakeArgs,
+}
+
+/// Instruction to propose a slash of an OperatorPool. The proposer's approval is recorded.
+pub fn handler(ctx: Context<CreateSlashProposal>, args: CreateSlashProposalArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreateSlashProposal)?;
+
+    let CreateSlashProposalArgs {
+        evidence_hash,
+        slash_args,
+    } = args;
+    slash_args.validate()?;
+
+    let authority = ctx.accounts.authority.key();
+    let slash_proposal = &mut ctx.accounts.slash_proposal;
+    slash_proposal.version = SlashProposal::VERSION;
+    slash_proposal.bump = ctx.bumps.slash_proposal;
+    slash_proposal.operator_pool = ctx.accounts.operator_pool.key();
+    slash_proposal.proposer = authority;
+    slash_proposal.evidence_hash = evidence_hash;
+    slash_proposal.args = slash_args;
+    slash_proposal.approvals = vec![authority];
+    slash_proposal.created_at = Clock::get()?.unix_timestamp;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreateSlashProposalEvent {
+        version: CreateSlashProposalEvent::VERSION,
+        instruction_index,
+        operator_pool: slash_proposal.operator_pool,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        slash_proposal: slash_proposal.key(),
+        proposer: authority,
+        evidence_hash,
+    });
+
+    Ok(())
+}
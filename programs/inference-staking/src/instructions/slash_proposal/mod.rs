@@ -0,0 +1,5 @@
+pub mod approve_slash_proposal;
+pub mod create_slash_proposal;
+
+pub use approve_slash_proposal::*;
+pub use create_slash_proposal::*;
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
+use crate::error::ErrorCode;
 use crate::events::CancelUnstakeEvent;
-use crate::state::{OperatorPool, PoolOverview, StakingRecord};
+use crate::state::{
+    OperatorPool, PausableInstruction, PoolOverview, ReferralRecord, StakingRecord,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CancelUnstake<'info> {
     pub owner: Signer<'info>,
@@ -14,16 +18,29 @@ pub struct CancelUnstake<'info> {
     )]
     pub pool_overview: Box<Account<'info, PoolOverview>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
     pub operator_pool: Box<Account<'info, OperatorPool>>,
 
     #[account(
         mut,
         has_one = owner,
         has_one = operator_pool,
+        constraint = owner_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub owner_staking_record: Box<Account<'info, StakingRecord>>,
 
+    /// ReferralRecord of the StakingRecord's referrer. Required if the StakingRecord has a referrer.
+    #[account(
+        mut,
+        has_one = operator_pool @ ErrorCode::InvalidReferralRecord,
+    )]
+    pub referral_record: Option<Box<Account<'info, ReferralRecord>>>,
+
     /// CHECK: This is a system account that is used to get the current instruction index.
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions: AccountInfo<'info>,
@@ -31,14 +48,28 @@ pub struct CancelUnstake<'info> {
 
 /// Instruction to cancel unstaking of tokens from an OperatorPool.
 pub fn handler(ctx: Context<CancelUnstake>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CancelUnstake)?;
+
     let operator_pool = &mut ctx.accounts.operator_pool;
     let pool_overview = &ctx.accounts.pool_overview;
 
+    // Check that the program is not winding down, as cancelling restakes the tokens.
+    require!(
+        !pool_overview.is_winding_down(),
+        ErrorCode::ProgramWindingDown
+    );
+
     // Check that all rewards have been claimed for pool closure conditions.
     operator_pool.check_unclaimed_rewards(pool_overview.completed_reward_epoch)?;
 
     let staking_record = &mut ctx.accounts.owner_staking_record;
 
+    // Apply any unstaking slashes before restaking.
+    operator_pool.settle_unstaking_slash(staking_record)?;
+    operator_pool.remove_unstaking_from_cohort(staking_record);
+
     // Store values before mutations
     let operator_pool_key = operator_pool.key();
     let staking_record_key = staking_record.key();
@@ -57,6 +88,21 @@ pub fn handler(ctx: Context<CancelUnstake>) -> Result<()> {
     // Add shares created to owner's StakingRecord.
     staking_record.shares = staking_record.shares.checked_add(shares_created).unwrap();
 
+    // Add restaked shares to the referrer's ReferralRecord.
+    if let Some(referrer) = staking_record.referrer {
+        let referral_record = ctx
+            .accounts
+            .referral_record
+            .as_mut()
+            .ok_or(ErrorCode::InvalidReferralRecord)?;
+        require_keys_eq!(
+            referral_record.referrer,
+            referrer,
+            ErrorCode::InvalidReferralRecord
+        );
+        operator_pool.add_referred_shares(referral_record, shares_created)?;
+    }
+
     // Reset owner's StakingRecord.
     staking_record.unstake_at_timestamp = 0;
     staking_record.tokens_unstake_amount = 0;
@@ -64,7 +110,8 @@ pub fn handler(ctx: Context<CancelUnstake>) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(CancelUnstakeEvent {
+    emit_cpi!(CancelUnstakeEvent {
+        version: CancelUnstakeEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool_key,
         epoch: pool_overview.completed_reward_epoch + 1,
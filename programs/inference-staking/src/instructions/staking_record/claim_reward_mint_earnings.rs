@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{
+    error::ErrorCode,
+    events::ClaimRewardMintEarningsEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview, StakingRecord},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRewardMintEarnings<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = operator_pool,
+        constraint = staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub staking_record: Box<Account<'info, StakingRecord>>,
+
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::GLOBAL_REWARD_MINT_VAULT_SEED, reward_mint.key().as_ref()],
+        bump,
+    )]
+    pub reward_mint_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Destination account for the reward mint earnings.
+    #[account(
+        mut,
+        constraint = destination.mint == reward_mint.key() @ ErrorCode::InvalidRewardMint,
+    )]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Instruction to claim earnings of an additional reward mint from its global vault.
+pub fn handler(ctx: Context<ClaimRewardMintEarnings>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ClaimRewardMintEarnings)?;
+
+    let pool_overview = &ctx.accounts.pool_overview;
+    let operator_pool = &ctx.accounts.operator_pool;
+    let staking_record = &mut ctx.accounts.staking_record;
+
+    let reward_mint_index = pool_overview
+        .reward_mint_index(&ctx.accounts.reward_mint.key())
+        .ok_or(ErrorCode::RewardMintNotRegistered)?;
+
+    let is_operator_claiming = operator_pool.operator_staking_record.key() == staking_record.key();
+
+    // Check that operator is not claiming when pool is halted.
+    require!(
+        !is_operator_claiming || operator_pool.halted_at_timestamp.is_none(),
+        ErrorCode::OperatorPoolHalted
+    );
+
+    // Check that global withdrawal has not been halted.
+    require!(
+        !pool_overview.is_withdrawal_halted,
+        ErrorCode::WithdrawalsHalted
+    );
+
+    // Ensure all rewards have been claimed for the pool, to match USDC claim invariants.
+    require_gte!(
+        operator_pool.reward_last_claimed_epoch,
+        pool_overview.completed_reward_epoch,
+        ErrorCode::UnclaimedRewards
+    );
+
+    operator_pool.settle_reward_mint_earnings(staking_record)?;
+
+    let claimable = staking_record.accrued_reward_mint_earnings[reward_mint_index];
+    require!(claimable > 0, ErrorCode::NoRewardMintEarningsToClaim);
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_mint_token_account.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.pool_overview.to_account_info(),
+            },
+            &[&[PoolOverview::SEED, &[pool_overview.bump]]],
+        ),
+        claimable,
+    )?;
+
+    staking_record.accrued_reward_mint_earnings[reward_mint_index] = 0;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    let reward_mint = &mut pool_overview.reward_mints[reward_mint_index];
+    reward_mint.unclaimed_amount = reward_mint.unclaimed_amount.checked_sub(claimable).unwrap();
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(ClaimRewardMintEarningsEvent {
+        version: ClaimRewardMintEarningsEvent::VERSION,
+        instruction_index,
+        operator_pool: ctx.accounts.operator_pool.key(),
+        epoch: pool_overview.completed_reward_epoch + 1,
+        staking_record: ctx.accounts.staking_record.key(),
+        owner: ctx.accounts.owner.key(),
+        is_operator: is_operator_claiming,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        destination: ctx.accounts.destination.key(),
+        amount: claimable,
+    });
+
+    Ok(())
+}
@@ -5,8 +5,9 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::error::ErrorCode;
 use crate::events::ClaimUnstakeEvent;
 use crate::operator_pool_signer_seeds;
-use crate::state::{OperatorPool, PoolOverview, StakingRecord};
+use crate::state::{OperatorPool, PausableInstruction, PoolOverview, StakingRecord};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimUnstake<'info> {
     /// CHECK: No signer enforced on owner account as ix is permissionless.
@@ -23,6 +24,8 @@ pub struct ClaimUnstake<'info> {
         seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
         bump = operator_pool.bump,
         has_one = operator_staking_record,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Box<Account<'info, OperatorPool>>,
 
@@ -36,6 +39,8 @@ pub struct ClaimUnstake<'info> {
         bump,
         has_one = owner,
         has_one = operator_pool,
+        constraint = owner_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub owner_staking_record: Box<Account<'info, StakingRecord>>,
 
@@ -67,6 +72,18 @@ pub struct ClaimUnstake<'info> {
 
 /// Instruction to claim tokens after unstaking delay.
 pub fn handler(ctx: Context<ClaimUnstake>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ClaimUnstake)?;
+
+    // Apply any unstaking slashes before the claim.
+    ctx.accounts
+        .operator_pool
+        .settle_unstaking_slash(&mut ctx.accounts.owner_staking_record)?;
+    ctx.accounts
+        .operator_pool
+        .remove_unstaking_from_cohort(&mut ctx.accounts.owner_staking_record);
+
     let operator_pool = &ctx.accounts.operator_pool;
     let pool_overview = &ctx.accounts.pool_overview;
     let operator_staking_record = &ctx.accounts.operator_staking_record;
@@ -131,8 +148,11 @@ pub fn handler(ctx: Context<ClaimUnstake>) -> Result<()> {
     staking_record.unstake_at_timestamp = 0;
 
     // If Operator is claiming and pool is not closed, check that they still
-    // maintain min. token stake of pool after.
-    if is_operator_claiming && operator_pool.closed_at_epoch.is_none() {
+    // maintain min. token stake of pool after, unless the program is winding down.
+    if is_operator_claiming
+        && operator_pool.closed_at_epoch.is_none()
+        && !pool_overview.is_winding_down()
+    {
         let min_operator_token_stake = pool_overview.min_operator_token_stake;
         let operator_stake = operator_pool.calc_tokens_for_share_amount(staking_record.shares);
         require_gte!(
@@ -145,7 +165,8 @@ pub fn handler(ctx: Context<ClaimUnstake>) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(ClaimUnstakeEvent {
+    emit_cpi!(ClaimUnstakeEvent {
+        version: ClaimUnstakeEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool_key,
         epoch: pool_overview.completed_reward_epoch + 1,
@@ -7,9 +7,10 @@ use crate::{
     error::ErrorCode,
     events::ClaimUsdcEarningsEvent,
     operator_pool_signer_seeds,
-    state::{OperatorPool, PoolOverview, StakingRecord},
+    state::{OperatorPool, PausableInstruction, PoolOverview, StakingRecord},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimUsdcEarnings<'info> {
     pub owner: Signer<'info>,
@@ -23,6 +24,8 @@ pub struct ClaimUsdcEarnings<'info> {
     #[account(
         seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
         bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
 
@@ -30,6 +33,8 @@ pub struct ClaimUsdcEarnings<'info> {
         mut,
         has_one = owner,
         has_one = operator_pool,
+        constraint = staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub staking_record: Account<'info, StakingRecord>,
 
@@ -55,6 +60,10 @@ pub struct ClaimUsdcEarnings<'info> {
 }
 
 pub fn handler(ctx: Context<ClaimUsdcEarnings>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::ClaimUsdcEarnings)?;
+
     let pool_overview = &ctx.accounts.pool_overview;
     let operator_pool = &ctx.accounts.operator_pool;
     let staking_record = &mut ctx.accounts.staking_record;
@@ -118,7 +127,8 @@ pub fn handler(ctx: Context<ClaimUsdcEarnings>) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(ClaimUsdcEarningsEvent {
+    emit_cpi!(ClaimUsdcEarningsEvent {
+        version: ClaimUsdcEarningsEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool_key,
         epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
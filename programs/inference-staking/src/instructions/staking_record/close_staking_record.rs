@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
 use crate::{
     error::ErrorCode,
-    state::{OperatorPool, StakingRecord},
+    events::CloseStakingRecordEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview, StakingRecord},
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseStakingRecord<'info> {
     /// Account to receive the reclaimed rent from StakingRecord
@@ -14,6 +17,12 @@ pub struct CloseStakingRecord<'info> {
 
     pub owner: Signer<'info>,
 
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
     #[account(
         mut,
         close = receiver,
@@ -24,19 +33,31 @@ pub struct CloseStakingRecord<'info> {
         ],
         bump,
         has_one = owner,
+        constraint = owner_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub owner_staking_record: Account<'info, StakingRecord>,
 
     #[account(
         seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
         bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Account<'info, OperatorPool>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<CloseStakingRecord>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CloseStakingRecord)?;
+
     let operator_pool = &ctx.accounts.operator_pool;
     let staking_record = &ctx.accounts.owner_staking_record;
 
@@ -53,6 +74,12 @@ pub fn handler(ctx: Context<CloseStakingRecord>) -> Result<()> {
         ErrorCode::UnclaimedUsdcEarnings
     );
 
+    // Check no unsettled additional reward mint earnings
+    require!(
+        !operator_pool.has_unclaimed_reward_mint_earnings(staking_record),
+        ErrorCode::UnclaimedRewardMintEarnings
+    );
+
     // Enforce that the operator pool has been fully unstaked before the
     // operator can close their staking record. This is because the unstake
     // flow instructions require the operator staking record account.
@@ -61,5 +88,18 @@ pub fn handler(ctx: Context<CloseStakingRecord>) -> Result<()> {
         require!(operator_pool.is_empty(), ErrorCode::PoolIsNotEmpty);
     }
 
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CloseStakingRecordEvent {
+        version: CloseStakingRecordEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool.key(),
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        staking_record: staking_record.key(),
+        owner: staking_record.owner,
+        receiver: ctx.accounts.receiver.key(),
+    });
+
     Ok(())
 }
@@ -1,7 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
 
-use crate::state::{OperatorPool, StakingRecord};
+use crate::{
+    error::ErrorCode,
+    events::CreateStakingRecordEvent,
+    state::{OperatorPool, PausableInstruction, PoolOverview, StakingRecord},
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateStakingRecord<'info> {
     #[account(mut)]
@@ -9,9 +15,17 @@ pub struct CreateStakingRecord<'info> {
 
     pub owner: Signer<'info>,
 
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
     #[account(
         seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
         bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Box<Account<'info, OperatorPool>>,
 
@@ -29,10 +43,18 @@ pub struct CreateStakingRecord<'info> {
     pub owner_staking_record: Box<Account<'info, StakingRecord>>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 /// Instruction to setup a StakingRecord.
 pub fn handler(ctx: Context<CreateStakingRecord>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::CreateStakingRecord)?;
+
     let staking_record = &mut ctx.accounts.owner_staking_record;
     staking_record.version = StakingRecord::VERSION;
     staking_record.owner = ctx.accounts.owner.key();
@@ -40,6 +62,21 @@ pub fn handler(ctx: Context<CreateStakingRecord>) -> Result<()> {
     staking_record.last_settled_usdc_per_share =
         ctx.accounts.operator_pool.cumulative_usdc_per_share;
     staking_record.accrued_usdc_earnings = 0;
+    staking_record.last_settled_reward_mint_per_share =
+        ctx.accounts.operator_pool.cumulative_reward_mint_per_share;
+    staking_record.unstaking_slash_checkpoint = ctx.accounts.operator_pool.unstaking_slash_count;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(CreateStakingRecordEvent {
+        version: CreateStakingRecordEvent::VERSION,
+        instruction_index,
+        operator_pool: staking_record.operator_pool,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        staking_record: staking_record.key(),
+        owner: staking_record.owner,
+    });
 
     Ok(())
 }
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_lang::system_program;
+
+use crate::{
+    events::MigrateStakingRecordEvent,
+    state::{PausableInstruction, PoolOverview, StakingRecord},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateStakingRecord<'info> {
+    /// Payer of the rent for the additional account space.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    /// CHECK: Only used to derive the StakingRecord PDA.
+    pub operator_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Only used to derive the StakingRecord PDA.
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: StakingRecords of an older layout can be shorter than the current fields require,
+    /// so the account is reallocated before it is deserialized in the handler.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            StakingRecord::SEED,
+            operator_pool.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub staking_record: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Permissionless instruction to reallocate a StakingRecord to the current layout and upgrade it
+/// to the current version.
+pub fn handler(ctx: Context<MigrateStakingRecord>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::MigrateStakingRecord)?;
+
+    // Reallocate the account to the current layout before deserializing it.
+    let staking_record_info = ctx.accounts.staking_record.to_account_info();
+    let space = 8 + StakingRecord::INIT_SPACE + StakingRecord::PADDING;
+    if staking_record_info.data_len() < space {
+        let rent_minimum = Rent::get()?.minimum_balance(space);
+        if rent_minimum > staking_record_info.lamports() {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: staking_record_info.clone(),
+                    },
+                ),
+                rent_minimum
+                    .checked_sub(staking_record_info.lamports())
+                    .unwrap(),
+            )?;
+        }
+        staking_record_info.realloc(space, false)?;
+    }
+
+    let mut staking_record =
+        StakingRecord::try_deserialize(&mut &staking_record_info.try_borrow_data()?[..])?;
+    let from_version = staking_record.migrate()?;
+    staking_record.try_serialize(&mut &mut staking_record_info.try_borrow_mut_data()?[..])?;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(MigrateStakingRecordEvent {
+        version: MigrateStakingRecordEvent::VERSION,
+        instruction_index,
+        operator_pool: staking_record.operator_pool,
+        epoch: ctx.accounts.pool_overview.completed_reward_epoch + 1,
+        staking_record: staking_record_info.key(),
+        owner: staking_record.owner,
+        payer: ctx.accounts.payer.key(),
+        from_version,
+        to_version: staking_record.version,
+    });
+
+    Ok(())
+}
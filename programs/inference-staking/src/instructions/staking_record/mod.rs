@@ -1,15 +1,21 @@
 pub mod cancel_unstake;
+pub mod claim_reward_mint_earnings;
 pub mod claim_unstake;
 pub mod claim_usdc_earnings;
 pub mod close_staking_record;
 pub mod create_staking_record;
+pub mod migrate_staking_record;
 pub mod stake;
 pub mod unstake;
+pub mod wind_down_staking_record;
 
 pub use cancel_unstake::*;
+pub use claim_reward_mint_earnings::*;
 pub use claim_unstake::*;
 pub use claim_usdc_earnings::*;
 pub use close_staking_record::*;
 pub use create_staking_record::*;
+pub use migrate_staking_record::*;
 pub use stake::*;
 pub use unstake::*;
+pub use wind_down_staking_record::*;
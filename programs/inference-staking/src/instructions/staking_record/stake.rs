@@ -3,9 +3,12 @@ use anchor_lang::solana_program::sysvar::instructions::load_current_index_checke
 
 use crate::error::ErrorCode;
 use crate::events::StakeEvent;
-use crate::state::{OperatorPool, PoolOverview, StakingRecord};
+use crate::state::{
+    OperatorPool, PausableInstruction, PoolOverview, ReferralRecord, StakingRecord,
+};
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Stake<'info> {
     pub owner: Signer<'info>,
@@ -14,6 +17,7 @@ pub struct Stake<'info> {
         seeds = [PoolOverview::SEED],
         bump = pool_overview.bump,
         constraint = !pool_overview.is_staking_halted @ ErrorCode::StakingHalted,
+        constraint = !pool_overview.is_winding_down() @ ErrorCode::ProgramWindingDown,
     )]
     pub pool_overview: Box<Account<'info, PoolOverview>>,
 
@@ -22,6 +26,8 @@ pub struct Stake<'info> {
         seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
         bump = operator_pool.bump,
         has_one = operator_staking_record,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Box<Account<'info, OperatorPool>>,
 
@@ -35,6 +41,8 @@ pub struct Stake<'info> {
         bump,
         has_one = owner,
         has_one = operator_pool,
+        constraint = owner_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub owner_staking_record: Box<Account<'info, StakingRecord>>,
 
@@ -57,6 +65,13 @@ pub struct Stake<'info> {
     )]
     pub staked_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// ReferralRecord of the StakingRecord's referrer. Required if the StakingRecord has a referrer.
+    #[account(
+        mut,
+        has_one = operator_pool @ ErrorCode::InvalidReferralRecord,
+    )]
+    pub referral_record: Option<Box<Account<'info, ReferralRecord>>>,
+
     pub token_program: Program<'info, Token>,
 
     /// CHECK: This is a system account that is used to get the current instruction index.
@@ -68,10 +83,17 @@ pub struct Stake<'info> {
 pub struct StakeArgs {
     /// Amount of tokens to stake.
     pub token_amount: u64,
+
+    /// Wallet that referred the staker. Recorded on the StakingRecord if it has no referrer yet.
+    pub referrer: Option<Pubkey>,
 }
 
 /// Instruction to stake tokens to an OperatorPool.
 pub fn handler(ctx: Context<Stake>, args: StakeArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::Stake)?;
+
     let operator_pool = &mut ctx.accounts.operator_pool;
     let pool_overview = &ctx.accounts.pool_overview;
     let operator_staking_record = &ctx.accounts.operator_staking_record;
@@ -107,6 +129,20 @@ pub fn handler(ctx: Context<Stake>, args: StakeArgs) -> Result<()> {
     let owner_token_account = &ctx.accounts.owner_token_account;
     require_gte!(owner_token_account.amount, token_amount);
 
+    // Record referrer on the StakingRecord. A referrer can only be set once, and operators
+    // cannot be referred to their own pool.
+    let previous_referrer = ctx.accounts.owner_staking_record.referrer;
+    if let Some(referrer) = args.referrer {
+        require!(
+            !is_operator_staking && referrer != ctx.accounts.owner.key(),
+            ErrorCode::InvalidReferrer
+        );
+        if let Some(previous_referrer) = previous_referrer {
+            require_keys_eq!(previous_referrer, referrer, ErrorCode::ReferrerAlreadySet);
+        }
+        ctx.accounts.owner_staking_record.referrer = Some(referrer);
+    }
+
     // Calculate number of shares to create, and update token and share amounts on OperatorPool.
     let shares_created =
         operator_pool.stake_tokens(&mut ctx.accounts.owner_staking_record, token_amount)?;
@@ -115,6 +151,27 @@ pub fn handler(ctx: Context<Stake>, args: StakeArgs) -> Result<()> {
     let staking_record = &mut ctx.accounts.owner_staking_record;
     staking_record.shares = staking_record.shares.checked_add(shares_created).unwrap();
 
+    // Add shares to the referrer's ReferralRecord. If the referrer was just set, all shares
+    // owned by the StakingRecord are now referred.
+    if let Some(referrer) = staking_record.referrer {
+        let referral_record = ctx
+            .accounts
+            .referral_record
+            .as_mut()
+            .ok_or(ErrorCode::InvalidReferralRecord)?;
+        require_keys_eq!(
+            referral_record.referrer,
+            referrer,
+            ErrorCode::InvalidReferralRecord
+        );
+        let referred_shares = if previous_referrer.is_some() {
+            shares_created
+        } else {
+            staking_record.shares
+        };
+        operator_pool.add_referred_shares(referral_record, referred_shares)?;
+    }
+
     token::transfer(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -146,7 +203,8 @@ pub fn handler(ctx: Context<Stake>, args: StakeArgs) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(StakeEvent {
+    emit_cpi!(StakeEvent {
+        version: StakeEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool.key(),
         epoch: pool_overview.completed_reward_epoch + 1,
@@ -155,6 +213,7 @@ pub fn handler(ctx: Context<Stake>, args: StakeArgs) -> Result<()> {
         is_operator: is_operator_staking,
         token_amount,
         shares_amount: shares_created,
+        referrer: ctx.accounts.owner_staking_record.referrer,
     });
 
     Ok(())
@@ -3,8 +3,11 @@ use anchor_lang::solana_program::sysvar::instructions::load_current_index_checke
 
 use crate::error::ErrorCode;
 use crate::events::UnstakeEvent;
-use crate::state::{OperatorPool, PoolOverview, StakingRecord};
+use crate::state::{
+    OperatorPool, PausableInstruction, PoolOverview, ReferralRecord, StakingRecord,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Unstake<'info> {
     pub owner: Signer<'info>,
@@ -12,7 +15,6 @@ pub struct Unstake<'info> {
     #[account(
         seeds = [PoolOverview::SEED],
         bump = pool_overview.bump,
-        constraint = !pool_overview.is_staking_halted @ ErrorCode::StakingHalted,
     )]
     pub pool_overview: Box<Account<'info, PoolOverview>>,
 
@@ -21,6 +23,8 @@ pub struct Unstake<'info> {
         seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
         bump = operator_pool.bump,
         has_one = operator_staking_record,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub operator_pool: Box<Account<'info, OperatorPool>>,
 
@@ -34,6 +38,8 @@ pub struct Unstake<'info> {
         bump,
         has_one = owner,
         has_one = operator_pool,
+        constraint = owner_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
     )]
     pub owner_staking_record: Box<Account<'info, StakingRecord>>,
 
@@ -42,6 +48,13 @@ pub struct Unstake<'info> {
     )]
     pub operator_staking_record: Box<Account<'info, StakingRecord>>,
 
+    /// ReferralRecord of the StakingRecord's referrer. Required if the StakingRecord has a referrer.
+    #[account(
+        mut,
+        has_one = operator_pool @ ErrorCode::InvalidReferralRecord,
+    )]
+    pub referral_record: Option<Box<Account<'info, ReferralRecord>>>,
+
     /// CHECK: This is a system account that is used to get the current instruction index.
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions: AccountInfo<'info>,
@@ -55,6 +68,10 @@ pub struct UnstakeArgs {
 
 /// Instruction to initiate unstaking of tokens from an OperatorPool.
 pub fn handler(ctx: Context<Unstake>, args: UnstakeArgs) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::Unstake)?;
+
     let operator_pool = &mut ctx.accounts.operator_pool;
     let pool_overview = &ctx.accounts.pool_overview;
     let operator_staking_record = &ctx.accounts.operator_staking_record;
@@ -87,16 +104,31 @@ pub fn handler(ctx: Context<Unstake>, args: UnstakeArgs) -> Result<()> {
     let staking_record = &mut ctx.accounts.owner_staking_record;
     require_gte!(staking_record.shares, shares_amount);
 
+    // Apply any unstaking slashes to the existing pending unstake, as its delay is reset below.
+    operator_pool.settle_unstaking_slash(staking_record)?;
+    operator_pool.remove_unstaking_from_cohort(staking_record);
+
     // Calculate number of tokens to unstake, and update token and share amounts on OperatorPool.
     let tokens_unstaked = operator_pool.unstake_tokens(staking_record, shares_amount)?;
 
+    // Remove unstaked shares from the referrer's ReferralRecord.
+    if let Some(referrer) = staking_record.referrer {
+        let referral_record = ctx
+            .accounts
+            .referral_record
+            .as_mut()
+            .ok_or(ErrorCode::InvalidReferralRecord)?;
+        require_keys_eq!(
+            referral_record.referrer,
+            referrer,
+            ErrorCode::InvalidReferralRecord
+        );
+        operator_pool.remove_referred_shares(referral_record, shares_amount)?;
+    }
+
     // Determine the correct unstake cooldown period on whether it's a delegator
     // or operator.
-    let unstake_delay_seconds = if is_operator_unstaking {
-        pool_overview.operator_unstake_delay_seconds
-    } else {
-        pool_overview.delegator_unstake_delay_seconds
-    };
+    let unstake_delay_seconds = pool_overview.unstake_delay_seconds(is_operator_unstaking);
 
     // Update owner's StakingRecord with new unstake details.
     staking_record.shares = staking_record.shares.checked_sub(shares_amount).unwrap();
@@ -104,15 +136,21 @@ pub fn handler(ctx: Context<Unstake>, args: UnstakeArgs) -> Result<()> {
         .tokens_unstake_amount
         .checked_add(tokens_unstaked)
         .unwrap();
-    staking_record.unstake_at_timestamp = Clock::get()?
-        .unix_timestamp
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    staking_record.unstake_at_timestamp = current_timestamp
         .checked_add(unstake_delay_seconds.try_into().unwrap())
         .unwrap();
+    operator_pool.add_unstaking_to_cohort(
+        staking_record,
+        current_timestamp,
+        pool_overview.unstaking_cohort_join_window_seconds(),
+    );
 
     // If Operator is unstaking and:
     // 1. Pool is closed, check that the unstake is after the final epoch. This is to prevent
     //    a pool becoming fully unstaked before its final reward epoch distribution.
-    // 2. Pool is not closed, check that they still maintain min. token stake of pool after.
+    // 2. Pool is not closed, check that they still maintain min. token stake of pool after,
+    //    unless the program is winding down.
     if is_operator_unstaking {
         match operator_pool.closed_at_epoch {
             Some(closed_at) => {
@@ -123,6 +161,7 @@ pub fn handler(ctx: Context<Unstake>, args: UnstakeArgs) -> Result<()> {
                     ErrorCode::FinalUnstakeEpochInvalid
                 );
             }
+            None if pool_overview.is_winding_down() => {}
             None => {
                 let min_operator_token_stake = pool_overview.min_operator_token_stake;
                 let operator_stake =
@@ -139,7 +178,8 @@ pub fn handler(ctx: Context<Unstake>, args: UnstakeArgs) -> Result<()> {
     let instructions = ctx.accounts.instructions.to_account_info();
     let instruction_index = load_current_index_checked(&instructions)?;
 
-    emit!(UnstakeEvent {
+    emit_cpi!(UnstakeEvent {
+        version: UnstakeEvent::VERSION,
         instruction_index,
         operator_pool: operator_pool.key(),
         epoch: pool_overview.completed_reward_epoch + 1,
@@ -0,0 +1,315 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_current_index_checked;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    constants::USDC_MINT_PUBKEY,
+    error::ErrorCode,
+    events::WindDownStakingRecordEvent,
+    operator_pool_signer_seeds,
+    state::{OperatorPool, PausableInstruction, PoolOverview, ReferralRecord, StakingRecord},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WindDownStakingRecord<'info> {
+    /// CHECK: No signer enforced on owner account as ix is permissionless.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [PoolOverview::SEED],
+        bump = pool_overview.bump,
+        constraint = pool_overview.is_winding_down() @ ErrorCode::ProgramNotWindingDown,
+    )]
+    pub pool_overview: Box<Account<'info, PoolOverview>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::SEED, operator_pool.initial_pool_admin.as_ref()],
+        bump = operator_pool.bump,
+        constraint = operator_pool.version == OperatorPool::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub operator_pool: Box<Account<'info, OperatorPool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            StakingRecord::SEED,
+            operator_pool.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+        has_one = owner,
+        has_one = operator_pool,
+        constraint = owner_staking_record.version == StakingRecord::VERSION
+            @ ErrorCode::AccountVersionOutdated,
+    )]
+    pub owner_staking_record: Box<Account<'info, StakingRecord>>,
+
+    /// ReferralRecord of the StakingRecord's referrer. Required if the StakingRecord has a referrer
+    /// and shares to unstake.
+    #[account(
+        mut,
+        has_one = operator_pool @ ErrorCode::InvalidReferralRecord,
+    )]
+    pub referral_record: Option<Box<Account<'info, ReferralRecord>>>,
+
+    #[account(
+        mut,
+        token::mint = pool_overview.mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Destination account for the USDC earnings. Must be a USDC token account of the owner.
+    #[account(
+        mut,
+        token::authority = owner,
+        constraint = owner_usdc_account.mint == USDC_MINT_PUBKEY @ ErrorCode::InvalidUsdcMint,
+    )]
+    pub owner_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::POOL_STAKED_TOKEN_VAULT_SEED, operator_pool.key().as_ref()],
+        bump,
+    )]
+    pub staked_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorPool::POOL_DELEGATOR_USDC_EARNINGS_VAULT_SEED, operator_pool.key().as_ref()],
+        bump,
+    )]
+    pub pool_usdc_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: This is a system account that is used to get the current instruction index.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Permissionless instruction to exit a StakingRecord during wind-down. Unstakes all shares and
+/// transfers the unstaked tokens, USDC earnings and additional reward mint earnings to the owner
+/// in one step.
+///
+/// Remaining accounts hold a pair of accounts for each registered reward mint, in PoolOverview
+/// order: the global reward mint vault and the owner's token account of the reward mint. Pairs
+/// are only validated for reward mints that the StakingRecord has earnings of.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, WindDownStakingRecord<'info>>) -> Result<()> {
+    ctx.accounts
+        .pool_overview
+        .require_instruction_not_paused(PausableInstruction::WindDownStakingRecord)?;
+
+    let pool_overview = &mut ctx.accounts.pool_overview;
+    let operator_pool = &mut ctx.accounts.operator_pool;
+
+    let is_operator =
+        operator_pool.operator_staking_record == ctx.accounts.owner_staking_record.key();
+
+    // Store keys before creating mutable borrows
+    let operator_pool_key = operator_pool.key();
+    let staking_record_key = ctx.accounts.owner_staking_record.key();
+    let owner_key = ctx.accounts.owner.key();
+
+    // Check that operator is not exiting when pool is halted.
+    require!(
+        !is_operator || operator_pool.halted_at_timestamp.is_none(),
+        ErrorCode::UnstakingNotAllowed
+    );
+
+    // Check that global withdrawal has not been halted.
+    require!(
+        !pool_overview.is_withdrawal_halted,
+        ErrorCode::WithdrawalsHalted
+    );
+
+    // Check that all rewards have been claimed, including after wind-down is complete. The exit
+    // is permissionless, so it must not forfeit rewards that are yet to be accrued to the shares.
+    operator_pool.check_unclaimed_rewards(pool_overview.completed_reward_epoch)?;
+
+    let staking_record = &mut ctx.accounts.owner_staking_record;
+
+    // Apply any unstaking slashes to the existing pending unstake.
+    operator_pool.settle_unstaking_slash(staking_record)?;
+    operator_pool.remove_unstaking_from_cohort(staking_record);
+
+    // Unstake all remaining shares. Unstaking settles the USDC earnings of the shares.
+    let shares_amount = staking_record.shares;
+    if shares_amount > 0 {
+        // If Operator is exiting a closed pool, check that the unstake is after the final epoch.
+        if is_operator {
+            if let Some(closed_at) = operator_pool.closed_at_epoch {
+                require_gte!(
+                    pool_overview.completed_reward_epoch.checked_add(1).unwrap(),
+                    closed_at,
+                    ErrorCode::FinalUnstakeEpochInvalid
+                );
+            }
+        }
+
+        let tokens_unstaked = operator_pool.unstake_tokens(staking_record, shares_amount)?;
+
+        // Remove unstaked shares from the referrer's ReferralRecord.
+        if let Some(referrer) = staking_record.referrer {
+            let referral_record = ctx
+                .accounts
+                .referral_record
+                .as_mut()
+                .ok_or(ErrorCode::InvalidReferralRecord)?;
+            require_keys_eq!(
+                referral_record.referrer,
+                referrer,
+                ErrorCode::InvalidReferralRecord
+            );
+            operator_pool.remove_referred_shares(referral_record, shares_amount)?;
+        }
+
+        staking_record.shares = 0;
+        staking_record.tokens_unstake_amount = staking_record
+            .tokens_unstake_amount
+            .checked_add(tokens_unstaked)
+            .unwrap();
+    } else {
+        operator_pool.settle_usdc_earnings(staking_record)?;
+    }
+
+    let token_amount = staking_record.tokens_unstake_amount;
+    let usdc_amount = staking_record.accrued_usdc_earnings;
+    let reward_mint_amounts =
+        staking_record.accrued_reward_mint_earnings[..pool_overview.reward_mints.len()].to_vec();
+    require!(
+        token_amount > 0 || usdc_amount > 0 || reward_mint_amounts.iter().any(|&a| a > 0),
+        ErrorCode::NothingToWindDown
+    );
+    require!(
+        ctx.accounts.pool_usdc_vault.amount >= usdc_amount,
+        ErrorCode::InsufficientPoolUsdcVaultBalance
+    );
+
+    // Transfer unstaked tokens to owner without delay.
+    if token_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.staked_token_account.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: operator_pool.to_account_info(),
+                },
+                &[operator_pool_signer_seeds!(operator_pool)],
+            ),
+            token_amount,
+        )?;
+        operator_pool.total_unstaking = operator_pool
+            .total_unstaking
+            .checked_sub(token_amount)
+            .unwrap();
+    }
+
+    // Transfer USDC earnings to owner.
+    if usdc_amount > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.pool_usdc_vault.to_account_info(),
+                    to: ctx.accounts.owner_usdc_account.to_account_info(),
+                    authority: operator_pool.to_account_info(),
+                },
+                &[operator_pool_signer_seeds!(operator_pool)],
+            ),
+            usdc_amount,
+        )?;
+    }
+
+    // Transfer additional reward mint earnings to owner.
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        pool_overview.reward_mints.len().checked_mul(2).unwrap(),
+        ErrorCode::InvalidRewardMintVault
+    );
+    for (i, amount) in reward_mint_amounts.iter().copied().enumerate() {
+        if amount == 0 {
+            continue;
+        }
+
+        let reward_mint = pool_overview.reward_mints[i].mint;
+        let vault_info = &ctx.remaining_accounts[i * 2];
+        let (expected_vault, _) = Pubkey::find_program_address(
+            &[
+                PoolOverview::GLOBAL_REWARD_MINT_VAULT_SEED,
+                reward_mint.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            vault_info.key(),
+            expected_vault,
+            ErrorCode::InvalidRewardMintVault
+        );
+
+        let destination_info = &ctx.remaining_accounts[i * 2 + 1];
+        require_keys_eq!(
+            *destination_info.owner,
+            Token::id(),
+            ErrorCode::InvalidRewardMintDestination
+        );
+        let destination =
+            TokenAccount::try_deserialize(&mut &destination_info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            destination.mint,
+            reward_mint,
+            ErrorCode::InvalidRewardMintDestination
+        );
+        require_keys_eq!(
+            destination.owner,
+            owner_key,
+            ErrorCode::InvalidRewardMintDestination
+        );
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: vault_info.clone(),
+                    to: destination_info.clone(),
+                    authority: pool_overview.to_account_info(),
+                },
+                &[&[PoolOverview::SEED, &[pool_overview.bump]]],
+            ),
+            amount,
+        )?;
+
+        let reward_mint = &mut pool_overview.reward_mints[i];
+        reward_mint.unclaimed_amount = reward_mint.unclaimed_amount.checked_sub(amount).unwrap();
+        staking_record.accrued_reward_mint_earnings[i] = 0;
+    }
+
+    // Reset owner's StakingRecord.
+    staking_record.tokens_unstake_amount = 0;
+    staking_record.unstake_at_timestamp = 0;
+    staking_record.accrued_usdc_earnings = 0;
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+    let instruction_index = load_current_index_checked(&instructions)?;
+
+    emit_cpi!(WindDownStakingRecordEvent {
+        version: WindDownStakingRecordEvent::VERSION,
+        instruction_index,
+        operator_pool: operator_pool_key,
+        epoch: pool_overview.completed_reward_epoch + 1,
+        staking_record: staking_record_key,
+        owner: owner_key,
+        is_operator,
+        shares_amount,
+        token_amount,
+        usdc_amount,
+        reward_mint_amounts,
+    });
+
+    Ok(())
+}
@@ -54,6 +54,43 @@ pub mod inference_staking {
         update_pool_overview_authorities::handler(ctx, args)
     }
 
+    pub fn set_instruction_pause(
+        ctx: Context<SetInstructionPause>,
+        args: SetInstructionPauseArgs,
+    ) -> Result<()> {
+        set_instruction_pause::handler(ctx, args)
+    }
+
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        args: UpdatePoolOverviewArgs,
+    ) -> Result<()> {
+        queue_config_change::handler(ctx, args)
+    }
+
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        execute_config_change::handler(ctx)
+    }
+
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        cancel_config_change::handler(ctx)
+    }
+
+    pub fn propose_program_admin(
+        ctx: Context<ProposeProgramAdmin>,
+        args: ProposeProgramAdminArgs,
+    ) -> Result<()> {
+        propose_program_admin::handler(ctx, args)
+    }
+
+    pub fn accept_program_admin(ctx: Context<AcceptProgramAdmin>) -> Result<()> {
+        accept_program_admin::handler(ctx)
+    }
+
+    pub fn cancel_program_admin_transfer(ctx: Context<CancelProgramAdminTransfer>) -> Result<()> {
+        cancel_program_admin_transfer::handler(ctx)
+    }
+
     pub fn mark_epoch_as_finalizing(
         ctx: Context<MarkEpochIsFinalizing>,
         args: MarkEpochIsFinalizingArgs,
@@ -61,6 +98,17 @@ pub mod inference_staking {
         mark_epoch_as_finalizing::handler(ctx, args)
     }
 
+    pub fn add_reward_mint(ctx: Context<AddRewardMint>) -> Result<()> {
+        add_reward_mint::handler(ctx)
+    }
+
+    pub fn initiate_wind_down(
+        ctx: Context<InitiateWindDown>,
+        args: InitiateWindDownArgs,
+    ) -> Result<()> {
+        initiate_wind_down::handler(ctx, args)
+    }
+
     /** -----------------------------------------------------------------------
      * Staking Instructions
      * ------------------------------------------------------------------------ */
@@ -92,6 +140,31 @@ pub mod inference_staking {
         claim_usdc_earnings::handler(ctx)
     }
 
+    pub fn claim_reward_mint_earnings(ctx: Context<ClaimRewardMintEarnings>) -> Result<()> {
+        claim_reward_mint_earnings::handler(ctx)
+    }
+
+    pub fn wind_down_staking_record<'info>(
+        ctx: Context<'_, '_, '_, 'info, WindDownStakingRecord<'info>>,
+    ) -> Result<()> {
+        wind_down_staking_record::handler(ctx)
+    }
+
+    pub fn migrate_staking_record(ctx: Context<MigrateStakingRecord>) -> Result<()> {
+        migrate_staking_record::handler(ctx)
+    }
+
+    /** -----------------------------------------------------------------------
+     * ReferralRecord Instructions
+     * ------------------------------------------------------------------------ */
+    pub fn create_referral_record(ctx: Context<CreateReferralRecord>) -> Result<()> {
+        create_referral_record::handler(ctx)
+    }
+
+    pub fn claim_referral_earnings(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+        claim_referral_earnings::handler(ctx)
+    }
+
     /** -----------------------------------------------------------------------
      * Reward Distribution Instructions
      * ------------------------------------------------------------------------ */
@@ -110,6 +183,10 @@ pub mod inference_staking {
         accrue_reward_emergency_bypass::handler(ctx)
     }
 
+    pub fn migrate_reward_record(ctx: Context<MigrateRewardRecord>) -> Result<()> {
+        migrate_reward_record::handler(ctx)
+    }
+
     /** -----------------------------------------------------------------------
      * OperatorPool Admin Instructions
      * ------------------------------------------------------------------------ */
@@ -132,6 +209,18 @@ pub mod inference_staking {
         withdraw_operator_usdc_commission::handler(ctx)
     }
 
+    pub fn distribute_operator_reward_commission<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeOperatorRewardCommission<'info>>,
+    ) -> Result<()> {
+        distribute_operator_reward_commission::handler(ctx)
+    }
+
+    pub fn distribute_operator_usdc_commission<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeOperatorUsdcCommission<'info>>,
+    ) -> Result<()> {
+        distribute_operator_usdc_commission::handler(ctx)
+    }
+
     pub fn change_operator_staking_record(ctx: Context<ChangeOperatorStakingRecord>) -> Result<()> {
         change_operator_staking_record::handler(ctx)
     }
@@ -155,6 +244,10 @@ pub mod inference_staking {
         sweep_closed_pool_usdc_dust::handler(ctx)
     }
 
+    pub fn migrate_operator_pool(ctx: Context<MigrateOperatorPool>) -> Result<()> {
+        migrate_operator_pool::handler(ctx)
+    }
+
     /** -----------------------------------------------------------------------
      * Program Admin Security Instructions
      * ------------------------------------------------------------------------ */
@@ -162,7 +255,96 @@ pub mod inference_staking {
         set_halt_status::handler(ctx, args)
     }
 
-    pub fn slash_stake(ctx: Context<SlashStake>, args: SlashStakeArgs) -> Result<()> {
-        slash_stake::handler(ctx, args)
+    pub fn expire_halt(ctx: Context<ExpireHalt>) -> Result<()> {
+        expire_halt::handler(ctx)
+    }
+
+    pub fn report_equivocation(
+        ctx: Context<ReportEquivocation>,
+        args: ReportEquivocationArgs,
+    ) -> Result<()> {
+        report_equivocation::handler(ctx, args)
+    }
+
+    pub fn tombstone_operator_key(
+        ctx: Context<TombstoneOperatorKey>,
+        args: TombstoneOperatorKeyArgs,
+    ) -> Result<()> {
+        tombstone_operator_key::handler(ctx, args)
+    }
+
+    pub fn create_slash_proposal(
+        ctx: Context<CreateSlashProposal>,
+        args: CreateSlashProposalArgs,
+    ) -> Result<()> {
+        create_slash_proposal::handler(ctx, args)
+    }
+
+    pub fn approve_slash_proposal(ctx: Context<ApproveSlashProposal>) -> Result<()> {
+        approve_slash_proposal::handler(ctx)
+    }
+
+    pub fn slash_stake(ctx: Context<SlashStake>) -> Result<()> {
+        slash_stake::handler(ctx)
+    }
+
+    pub fn reverse_slash(ctx: Context<ReverseSlash>) -> Result<()> {
+        reverse_slash::handler(ctx)
+    }
+
+    pub fn release_slash_escrow(ctx: Context<ReleaseSlashEscrow>) -> Result<()> {
+        release_slash_escrow::handler(ctx)
+    }
+
+    /** ------------------------------------------------------------------------
+     * Pending Action Instructions
+     * ------------------------------------------------------------------------ */
+    pub fn create_pending_action(
+        ctx: Context<CreatePendingAction>,
+        args: CreatePendingActionArgs,
+    ) -> Result<()> {
+        create_pending_action::handler(ctx, args)
+    }
+
+    pub fn approve_pending_action(ctx: Context<ApprovePendingAction>) -> Result<()> {
+        approve_pending_action::handler(ctx)
+    }
+
+    /** -----------------------------------------------------------------------
+     * Insurance Fund Instructions
+     * ------------------------------------------------------------------------ */
+    pub fn create_insurance_fund(ctx: Context<CreateInsuranceFund>) -> Result<()> {
+        create_insurance_fund::handler(ctx)
+    }
+
+    pub fn deposit_insurance_contributions(
+        ctx: Context<DepositInsuranceContributions>,
+    ) -> Result<()> {
+        deposit_insurance_contributions::handler(ctx)
+    }
+
+    pub fn create_insurance_claim(
+        ctx: Context<CreateInsuranceClaim>,
+        args: CreateInsuranceClaimArgs,
+    ) -> Result<()> {
+        create_insurance_claim::handler(ctx, args)
+    }
+
+    pub fn approve_insurance_claim(
+        ctx: Context<ApproveInsuranceClaim>,
+        args: ApproveInsuranceClaimArgs,
+    ) -> Result<()> {
+        approve_insurance_claim::handler(ctx, args)
+    }
+
+    pub fn claim_insurance(ctx: Context<ClaimInsurance>) -> Result<()> {
+        claim_insurance::handler(ctx)
+    }
+
+    /** -----------------------------------------------------------------------
+     * Admin Audit Log Instructions
+     * ------------------------------------------------------------------------ */
+    pub fn create_admin_audit_log(ctx: Context<CreateAdminAuditLog>) -> Result<()> {
+        create_admin_audit_log::handler(ctx)
     }
 }
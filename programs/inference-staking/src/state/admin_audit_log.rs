@@ -0,0 +1,164 @@
+use anchor_lang::{prelude::*, solana_program::hash::hashv};
+
+/// Number of most recent admin actions kept in the AdminAuditLog.
+pub const ADMIN_AUDIT_LOG_CAPACITY: usize = 32;
+
+/// Admin and authority actions recorded in the AdminAuditLog.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq, Debug)]
+pub enum AdminActionKind {
+    /// Args hash covers the UpdatePoolOverviewArgs.
+    UpdatePoolOverview,
+    /// Args hash covers the UpdatePoolOverviewAuthoritiesArgs.
+    UpdatePoolOverviewAuthorities,
+    /// Args hash covers the queued UpdatePoolOverviewArgs.
+    QueueConfigChange,
+    /// Args hash covers the key of the previous program admin.
+    AcceptProgramAdmin,
+    /// Args hash covers the SetInstructionPauseArgs.
+    SetInstructionPause,
+    /// Args hash is the action hash of the SetHaltStatusArgs for the OperatorPool.
+    SetHaltStatus,
+    /// Args hash covers the key of the executed SlashProposal.
+    SlashStake,
+    /// Args hash covers the key of the reversed SlashEscrow.
+    ReverseSlash,
+    /// Args hash is the action hash of the CreateRewardRecordArgs for the epoch.
+    CreateRewardRecord,
+    /// Args hash covers the InitiateWindDownArgs.
+    InitiateWindDown,
+    /// Args hash covers the key of the InsuranceClaim and the ApproveInsuranceClaimArgs.
+    ApproveInsuranceClaim,
+    /// Args hash covers the executed UpdatePoolOverviewArgs. Recorded with the program admin that
+    /// queued the change.
+    ExecuteConfigChange,
+    /// Args hash covers the TombstoneOperatorKeyArgs.
+    TombstoneOperatorKey,
+    /// Args hash covers the ProposeProgramAdminArgs.
+    ProposeProgramAdmin,
+    /// Args hash covers the cancelled UpdatePoolOverviewArgs.
+    CancelConfigChange,
+    /// Args hash covers the key of the added reward mint.
+    AddRewardMint,
+    /// Args hash covers the key of the released SlashEscrow. Recorded with the slashing authority
+    /// that proposed the slash.
+    ReleaseSlashEscrow,
+    /// Args hash covers the key of the OperatorPool and the bypassed epoch.
+    AccrueRewardEmergencyBypass,
+    /// Args hash covers the key of the cancelled pending program admin.
+    CancelProgramAdminTransfer,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct AdminAuditLogEntry {
+    /// Signer that performed the action.
+    pub actor: Pubkey,
+
+    /// Kind of action performed.
+    pub kind: AdminActionKind,
+
+    /// Timestamp of the action.
+    pub timestamp: i64,
+
+    /// Hash of the action's args, as described on AdminActionKind.
+    pub args_hash: [u8; 32],
+}
+
+#[derive(InitSpace)]
+#[account]
+pub struct AdminAuditLog {
+    /// Version of the AdminAuditLog account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// Total number of actions recorded. The next entry is written at
+    /// `total_entries % ADMIN_AUDIT_LOG_CAPACITY`.
+    pub total_entries: u64,
+
+    /// Ring buffer of the most recent actions.
+    #[max_len(ADMIN_AUDIT_LOG_CAPACITY)]
+    pub entries: Vec<AdminAuditLogEntry>,
+}
+
+impl AdminAuditLog {
+    /// Version of the AdminAuditLog account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for AdminAuditLog account.
+    pub const SEED: &'static [u8] = b"AdminAuditLog";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 128;
+}
+
+impl AdminAuditLog {
+    /// Returns the hash of serialized args recorded in an entry.
+    pub fn hash_args<T: AnchorSerialize>(args: &T) -> [u8; 32] {
+        let args = borsh::to_vec(args).unwrap();
+        hashv(&[&args]).to_bytes()
+    }
+
+    /// Records an action, overwriting the oldest entry once the log is full.
+    pub fn record(
+        &mut self,
+        actor: Pubkey,
+        kind: AdminActionKind,
+        args_hash: [u8; 32],
+        timestamp: i64,
+    ) {
+        let entry = AdminAuditLogEntry {
+            actor,
+            kind,
+            timestamp,
+            args_hash,
+        };
+        if self.entries.len() < ADMIN_AUDIT_LOG_CAPACITY {
+            self.entries.push(entry);
+        } else {
+            let index =
+                usize::try_from(self.total_entries % ADMIN_AUDIT_LOG_CAPACITY as u64).unwrap();
+            self.entries[index] = entry;
+        }
+        self.total_entries = self.total_entries.checked_add(1).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_overwrites_oldest_entry() {
+        let mut admin_audit_log = AdminAuditLog {
+            version: AdminAuditLog::VERSION,
+            bump: 0,
+            total_entries: 0,
+            entries: vec![],
+        };
+        let actor = Pubkey::new_unique();
+        for i in 0..ADMIN_AUDIT_LOG_CAPACITY + 2 {
+            admin_audit_log.record(
+                actor,
+                AdminActionKind::UpdatePoolOverview,
+                [0; 32],
+                i as i64,
+            );
+        }
+
+        assert_eq!(
+            admin_audit_log.total_entries,
+            ADMIN_AUDIT_LOG_CAPACITY as u64 + 2
+        );
+        assert_eq!(admin_audit_log.entries.len(), ADMIN_AUDIT_LOG_CAPACITY);
+        assert_eq!(
+            admin_audit_log.entries[0].timestamp,
+            ADMIN_AUDIT_LOG_CAPACITY as i64
+        );
+        assert_eq!(
+            admin_audit_log.entries[1].timestamp,
+            ADMIN_AUDIT_LOG_CAPACITY as i64 + 1
+        );
+        assert_eq!(admin_audit_log.entries[2].timestamp, 2);
+    }
+}
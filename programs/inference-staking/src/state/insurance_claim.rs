@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+#[derive(InitSpace)]
+#[account]
+pub struct InsuranceClaim {
+    /// Version of the InsuranceClaim account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// Id of the claim, assigned from InsuranceFund.claim_count.
+    pub id: u64,
+
+    /// Delegator that filed the claim and receives the payout.
+    pub claimant: Pubkey,
+
+    /// OperatorPool the claimant was staked in.
+    pub operator_pool: Pubkey,
+
+    /// StakingRecord of the claimant in the OperatorPool.
+    pub staking_record: Pubkey,
+
+    /// Hash of the off-chain description of the loss.
+    pub evidence_hash: [u8; 32],
+
+    /// Amount of tokens requested by the claimant.
+    pub requested_token_amount: u64,
+
+    /// Amount of USDC requested by the claimant.
+    pub requested_usdc_amount: u64,
+
+    /// Amount of tokens approved by the program admin.
+    pub approved_token_amount: u64,
+
+    /// Amount of USDC approved by the program admin.
+    pub approved_usdc_amount: u64,
+
+    /// Timestamp when the claim was created.
+    pub created_at: i64,
+
+    /// Timestamp when the claim was approved, if approved.
+    pub approved_at: Option<i64>,
+
+    /// Timestamp when the claim was paid out, if paid.
+    pub paid_at: Option<i64>,
+}
+
+impl InsuranceClaim {
+    /// Version of the InsuranceClaim account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for InsuranceClaim account.
+    pub const SEED: &'static [u8] = b"InsuranceClaim";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 128;
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+#[derive(InitSpace)]
+#[account]
+pub struct InsuranceFund {
+    /// Version of the InsuranceFund account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// Total amount of tokens deposited into the fund.
+    pub total_token_deposited: u64,
+
+    /// Total amount of USDC deposited into the fund.
+    pub total_usdc_deposited: u64,
+
+    /// Total amount of tokens paid out on claims.
+    pub total_token_paid: u64,
+
+    /// Total amount of USDC paid out on claims.
+    pub total_usdc_paid: u64,
+
+    /// Number of InsuranceClaims created. Used as the id of the next InsuranceClaim.
+    pub claim_count: u64,
+}
+
+impl InsuranceFund {
+    /// Version of the InsuranceFund account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for InsuranceFund account.
+    pub const SEED: &'static [u8] = b"InsuranceFund";
+
+    /// PDA seed for insurance fund token vault.
+    pub const INSURANCE_TOKEN_VAULT_SEED: &'static [u8] = b"InsuranceTokenVault";
+
+    /// PDA seed for insurance fund USDC vault.
+    pub const INSURANCE_USDC_VAULT_SEED: &'static [u8] = b"InsuranceUsdcVault";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 256;
+}
+
+impl InsuranceFund {
+    /// Records a deposit of tokens and USDC into the fund.
+    pub fn record_deposit(&mut self, token_amount: u64, usdc_amount: u64) {
+        self.total_token_deposited = self
+            .total_token_deposited
+            .checked_add(token_amount)
+            .unwrap();
+        self.total_usdc_deposited = self.total_usdc_deposited.checked_add(usdc_amount).unwrap();
+    }
+}
@@ -1,9 +1,29 @@
+pub mod admin_audit_log;
+pub mod insurance_claim;
+pub mod insurance_fund;
 pub mod operator_pool;
+pub mod pausable_instruction;
+pub mod pending_action;
+pub mod pending_config_change;
 pub mod pool_overview;
+pub mod referral_record;
 pub mod reward_record;
+pub mod slash_escrow;
+pub mod slash_proposal;
 pub mod staking_record;
+pub mod tombstone;
 
+pub use admin_audit_log::*;
+pub use insurance_claim::*;
+pub use insurance_fund::*;
 pub use operator_pool::*;
+pub use pausable_instruction::*;
+pub use pending_action::*;
+pub use pending_config_change::*;
 pub use pool_overview::*;
+pub use referral_record::*;
 pub use reward_record::*;
+pub use slash_escrow::*;
+pub use slash_proposal::*;
 pub use staking_record::*;
+pub use tombstone::*;
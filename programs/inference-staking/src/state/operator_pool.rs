@@ -2,7 +2,11 @@ use std::collections::HashSet;
 
 use anchor_lang::prelude::*;
 
-use crate::{constants::USDC_PRECISION_FACTOR, error::ErrorCode, state::StakingRecord};
+use crate::{
+    constants::USDC_PRECISION_FACTOR,
+    error::ErrorCode,
+    state::{ReferralRecord, StakingRecord, MAX_REWARD_MINTS},
+};
 
 // Keep numbers in sync with error codes.
 const MAX_NAME_LENGTH: usize = 64;
@@ -10,7 +14,101 @@ const MAX_DESCRIPTION_LENGTH: usize = 200;
 const MAX_WEBSITE_URL_LENGTH: usize = 64;
 const MAX_AVATAR_IMAGE_URL_LENGTH: usize = 128;
 
-const MAX_OPERATOR_AUTH_KEYS_LENGTH: usize = 5;
+const MAX_OPERATOR_AUTH_KEYS_LENGTH: usize = 16;
+
+/// Max. number of scheduled changes for each commission rate.
+pub const MAX_SCHEDULED_COMMISSION_RATES: usize = 4;
+
+/// Max. number of recipients that operator commission can be split across.
+pub const MAX_COMMISSION_RECIPIENTS: usize = 5;
+
+/// Max. number of unstaking cohorts tracked on OperatorPool.
+pub const MAX_UNSTAKING_COHORTS: usize = 12;
+
+/// Max. number of unstaking slashes of an OperatorPool.
+pub const MAX_UNSTAKING_SLASHES: usize = 8;
+
+/// Pending unstakes that stop being slashable at the same time.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct UnstakingCohort {
+    /// Timestamp from which the cohort is no longer slashed. Not later than the
+    /// unstake_at_timestamp of any pending unstake in the cohort.
+    pub matures_at: i64,
+
+    /// Amount of tokens being unstaked in the cohort.
+    pub amount: u64,
+
+    /// Amount of tokens slashed from the cohort by the unresolved unstaking slash. Restored if the
+    /// slash is reversed.
+    pub unresolved_slash_amount: u64,
+}
+
+/// Slash of the pending unstakes of an OperatorPool.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct UnstakingSlash {
+    /// Id of the slash, counted from 1. Ids of reversed slashes are not reused.
+    pub id: u64,
+
+    /// Timestamp of the slash. Only cohorts that matured after this timestamp were slashed.
+    pub slashed_at: i64,
+
+    /// Percentage of the pending unstakes slashed, in basis points.
+    pub slash_bps: u16,
+}
+
+/// Policy for how much of a balance to confiscate when an Operator is slashed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum ConfiscationPolicy {
+    /// Confiscate the full balance.
+    All,
+    /// Confiscate a percentage of the balance in basis points.
+    Percentage(u16),
+    /// Do not confiscate any of the balance.
+    None,
+}
+
+impl ConfiscationPolicy {
+    pub fn validate(&self) -> Result<()> {
+        if let ConfiscationPolicy::Percentage(bps) = self {
+            require_gte!(10_000, *bps, ErrorCode::InvalidConfiscationPolicy);
+        }
+        Ok(())
+    }
+
+    /// Calculates the amount of balance to confiscate. Rounded down for percentages.
+    pub fn calc_amount(&self, balance: u64) -> u64 {
+        match self {
+            ConfiscationPolicy::All => balance,
+            ConfiscationPolicy::Percentage(bps) => u64::try_from(
+                u128::from(balance)
+                    .checked_mul(u128::from(*bps))
+                    .unwrap()
+                    .checked_div(10_000)
+                    .unwrap(),
+            )
+            .unwrap(),
+            ConfiscationPolicy::None => 0,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct CommissionRecipient {
+    /// Wallet that owns the token accounts receiving this share of commission.
+    pub recipient: Pubkey,
+
+    /// Share of commission in basis points.
+    pub share_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct ScheduledCommissionRate {
+    /// First reward epoch that the rate applies to.
+    pub effective_epoch: u64,
+
+    /// Commission rate in basis points. Capped at 100%.
+    pub rate_bps: u16,
+}
 
 #[derive(InitSpace)]
 #[account]
@@ -56,13 +154,15 @@ pub struct OperatorPool {
     /// Commission Rate for Epoch Token Rewards. Capped at 100%.
     pub reward_commission_rate_bps: u16,
 
-    /// Commission Rate that will take place next Epoch, if set. Capped at 100%.
+    /// Deprecated: replaced by `reward_commission_rate_schedule`. Kept to preserve the account
+    /// layout. A pending rate is moved into the schedule on migration.
     pub new_reward_commission_rate_bps: Option<u16>,
 
     /// USDC commission rate in basis points (0-10000)
     pub usdc_commission_rate_bps: u16,
 
-    /// Pending USDC commission rate for next epoch
+    /// Deprecated: replaced by `usdc_commission_rate_schedule`. Kept to preserve the account
+    /// layout. A pending rate is moved into the schedule on migration.
     pub new_usdc_commission_rate_bps: Option<u16>,
 
     /// If any other user is allowed to delegate stake to Pool, besides operator_staking_record.
@@ -111,11 +211,82 @@ pub struct OperatorPool {
 
     /// Cumulative USDC per share (scaled by USDC_PRECISION_FACTOR)
     pub cumulative_usdc_per_share: u128,
+
+    /// Additional reward mint amounts that have been calculated in `accrueRewards`, that are yet to be
+    /// added to the per share index. Indexed by PoolOverview.reward_mints.
+    pub accrued_reward_mint_amounts: [u64; MAX_REWARD_MINTS],
+
+    /// Cumulative reward per share for each additional reward mint (scaled by USDC_PRECISION_FACTOR).
+    /// Indexed by PoolOverview.reward_mints.
+    pub cumulative_reward_mint_per_share: [u128; MAX_REWARD_MINTS],
+
+    /// Recipients that commission is split across when distributed. Shares must sum to 10,000 bps.
+    /// If empty, commission is withdrawn by the admin to a single destination instead.
+    #[max_len(MAX_COMMISSION_RECIPIENTS)]
+    pub commission_recipients: Vec<CommissionRecipient>,
+
+    /// Share of commission earned on referred shares that is paid to referrers, in basis points.
+    pub referral_commission_share_bps: u16,
+
+    /// Total amount of shares owned by StakingRecords that have a referrer.
+    pub total_referred_shares: u64,
+
+    /// Cumulative referral reward tokens per referred share (scaled by USDC_PRECISION_FACTOR).
+    pub cumulative_referral_reward_per_share: u128,
+
+    /// Cumulative referral USDC per referred share (scaled by USDC_PRECISION_FACTOR).
+    pub cumulative_referral_usdc_per_share: u128,
+
+    /// Pending unstakes that are still inside their delay, grouped by the time they stop being
+    /// slashable. Unstakes that are not in a cohort are not slashable.
+    #[max_len(MAX_UNSTAKING_COHORTS)]
+    pub unstaking_cohorts: Vec<UnstakingCohort>,
+
+    /// Unstaking slashes of the pool, oldest first. Pending unstakes are reduced lazily by the
+    /// slashes that happened before their cohort matured.
+    #[max_len(MAX_UNSTAKING_SLASHES)]
+    pub unstaking_slashes: Vec<UnstakingSlash>,
+
+    /// Number of unstaking slashes executed on the pool, including reversed slashes.
+    pub unstaking_slash_count: u64,
+
+    /// SlashEscrow of the last unstaking slash while it is unresolved. Pending unstakes that it
+    /// slashed are not settled and no further unstaking slash is executed until it is released or
+    /// reversed, so a reversal can restore the slashed cohorts.
+    pub unstaking_slash_escrow: Option<Pubkey>,
+
+    /// Reason code given by the halt authority when the pool was halted. 0 when not halted.
+    pub halt_reason_code: u8,
+
+    /// Hash of the evidence for the halt, for example an IPFS CID digest. Zeroed when not halted.
+    pub halt_evidence_hash: [u8; 32],
+
+    /// Timestamp after which the halt can be lifted permissionlessly if the pool has not been
+    /// slashed since it was halted. The halt does not expire if not set.
+    pub halt_expires_at: Option<i64>,
+
+    /// Timestamp of the last executed slash of the pool.
+    pub last_slashed_at: Option<i64>,
+
+    /// Timestamp when the pool was tombstoned by a slashing authority. A tombstoned pool cannot be
+    /// moved to a new admin.
+    pub tombstoned_at: Option<i64>,
+
+    /// Scheduled changes to the reward commission rate, ordered by effective epoch.
+    #[max_len(MAX_SCHEDULED_COMMISSION_RATES)]
+    pub reward_commission_rate_schedule: Vec<ScheduledCommissionRate>,
+
+    /// Scheduled changes to the USDC commission rate, ordered by effective epoch.
+    #[max_len(MAX_SCHEDULED_COMMISSION_RATES)]
+    pub usdc_commission_rate_schedule: Vec<ScheduledCommissionRate>,
 }
 
 impl OperatorPool {
     /// Version of the OperatorPool account.
-    pub const VERSION: u8 = 1;
+    /// - 1: Initial layout.
+    /// - 2: Max. number of operator auth keys raised from 5 to 16, and pending commission rates
+    ///   replaced by commission rate schedules.
+    pub const VERSION: u8 = 2;
 
     /// PDA seed for OperatorPool account.
     pub const SEED: &'static [u8] = b"OperatorPool";
@@ -140,6 +311,77 @@ impl OperatorPool {
 }
 
 impl OperatorPool {
+    /// Upgrades the account from its stored version to the current version, one version at a
+    /// time. The account must already be reallocated to the space of the current layout.
+    /// Returns the version the account was migrated from.
+    pub fn migrate(&mut self, completed_reward_epoch: u64) -> Result<u8> {
+        let from_version = self.version;
+        require_gt!(
+            Self::VERSION,
+            from_version,
+            ErrorCode::AccountVersionCurrent
+        );
+        while self.version < Self::VERSION {
+            match self.version {
+                // Pending commission rates were applied after the accrual that caught the pool
+                // up to the completed reward epoch, so they are moved into the schedules with
+                // the first epoch that would have used them.
+                1 => {
+                    let epochs_to_next_update =
+                        if self.reward_last_claimed_epoch < completed_reward_epoch {
+                            1
+                        } else {
+                            2
+                        };
+                    let effective_epoch = completed_reward_epoch
+                        .checked_add(epochs_to_next_update)
+                        .unwrap();
+                    if let Some(rate_bps) = self.new_reward_commission_rate_bps.take() {
+                        self.reward_commission_rate_schedule
+                            .push(ScheduledCommissionRate {
+                                effective_epoch,
+                                rate_bps,
+                            });
+                    }
+                    if let Some(rate_bps) = self.new_usdc_commission_rate_bps.take() {
+                        self.usdc_commission_rate_schedule
+                            .push(ScheduledCommissionRate {
+                                effective_epoch,
+                                rate_bps,
+                            });
+                    }
+                }
+                _ => return err!(ErrorCode::UnsupportedAccountVersion),
+            }
+            self.version = self.version.checked_add(1).unwrap();
+        }
+        Ok(from_version)
+    }
+
+    /// Lifts the halt of the pool and clears its halt reason, evidence and expiry.
+    pub fn clear_halt(&mut self) {
+        self.halted_at_timestamp = None;
+        self.halt_reason_code = 0;
+        self.halt_evidence_hash = [0; 32];
+        self.halt_expires_at = None;
+    }
+
+    /// Whether the halt of the pool has reached its expiry.
+    pub fn is_halt_expired(&self, current_timestamp: i64) -> bool {
+        self.halted_at_timestamp.is_some()
+            && self
+                .halt_expires_at
+                .is_some_and(|expires_at| current_timestamp >= expires_at)
+    }
+
+    /// Whether the pool has been slashed since it was last halted.
+    pub fn is_slashed_since_halt(&self) -> bool {
+        match (self.halted_at_timestamp, self.last_slashed_at) {
+            (Some(halted_at), Some(last_slashed_at)) => last_slashed_at >= halted_at,
+            _ => false,
+        }
+    }
+
     /// Calculates number of shares equivalent to token amount.
     /// Uses a default 1:1 rate if total_shares i 0.
     /// Final result is rounded down as part of integer division.
@@ -184,6 +426,7 @@ impl OperatorPool {
         token_amount: u64,
     ) -> Result<u64> {
         self.settle_usdc_earnings(staking_record)?;
+        self.settle_reward_mint_earnings(staking_record)?;
 
         let shares_created = self.calc_shares_for_token_amount(token_amount);
         self.total_staked_amount = self.total_staked_amount.checked_add(token_amount).unwrap();
@@ -201,6 +444,7 @@ impl OperatorPool {
         share_amount: u64,
     ) -> Result<u64> {
         self.settle_usdc_earnings(staking_record)?;
+        self.settle_reward_mint_earnings(staking_record)?;
 
         let tokens_unstaked = self.calc_tokens_for_share_amount(share_amount);
         self.total_staked_amount = self
@@ -223,6 +467,7 @@ impl OperatorPool {
         shares_amount: u64,
     ) -> Result<u64> {
         self.settle_usdc_earnings(staking_record)?;
+        self.settle_reward_mint_earnings(staking_record)?;
 
         let token_amount = self.calc_tokens_for_share_amount(shares_amount);
         self.total_staked_amount = self.total_staked_amount.checked_sub(token_amount).unwrap();
@@ -231,8 +476,316 @@ impl OperatorPool {
         Ok(token_amount)
     }
 
+    /// Updates OperatorPool total_staked_amount after slashing slash_bps of the pool's staked tokens.
+    /// Shares are unchanged, so the value of every share is lowered proportionally.
+    /// Returns number of tokens slashed.
+    pub fn slash_pool_tokens(&mut self, slash_bps: u16) -> u64 {
+        let token_amount = u64::try_from(
+            u128::from(self.total_staked_amount)
+                .checked_mul(slash_bps.into())
+                .unwrap()
+                .checked_div(10_000)
+                .unwrap(),
+        )
+        .unwrap();
+        self.total_staked_amount = self.total_staked_amount.checked_sub(token_amount).unwrap();
+
+        token_amount
+    }
+
+    /// Calculates the operator and delegator losses of slashing pool_slash_bps of the pool, split by
+    /// share ownership, and unstaking_slash_bps of pending unstakes that are still inside their delay.
+    /// Returns (operator loss, delegator loss).
+    pub fn calc_pool_slash_losses(
+        &self,
+        operator_staking_record: &StakingRecord,
+        pool_slash_bps: u16,
+        unstaking_slash_bps: u16,
+        current_timestamp: i64,
+    ) -> (u64, u64) {
+        let calc_bps_of = |amount: u64, slash_bps: u16| {
+            u64::try_from(
+                u128::from(amount)
+                    .checked_mul(slash_bps.into())
+                    .unwrap()
+                    .checked_div(10_000)
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+
+        let pool_loss = calc_bps_of(self.total_staked_amount, pool_slash_bps);
+        let operator_pool_loss = if self.total_shares == 0 {
+            0
+        } else {
+            u64::try_from(
+                u128::from(pool_loss)
+                    .checked_mul(operator_staking_record.shares.into())
+                    .unwrap()
+                    .checked_div(self.total_shares.into())
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+        let delegator_pool_loss = pool_loss.checked_sub(operator_pool_loss).unwrap();
+
+        let operator_unstaking = if operator_staking_record.unstaking_cohort_at > current_timestamp
+        {
+            operator_staking_record
+                .tokens_unstake_amount
+                .checked_sub(self.calc_unstaking_slash(operator_staking_record))
+                .unwrap()
+        } else {
+            0
+        };
+        let delegator_unstaking = self
+            .calc_slashable_unstaking(current_timestamp)
+            .saturating_sub(operator_unstaking);
+        let operator_loss = operator_pool_loss
+            .checked_add(calc_bps_of(operator_unstaking, unstaking_slash_bps))
+            .unwrap();
+        let delegator_loss = delegator_pool_loss
+            .checked_add(calc_bps_of(delegator_unstaking, unstaking_slash_bps))
+            .unwrap();
+
+        (operator_loss, delegator_loss)
+    }
+
+    /// Adds token_amount to the pool for non-operator delegators only. The operator's shares are
+    /// reduced so that the token value of the operator's stake is unchanged.
+    /// Returns number of operator shares removed.
+    pub fn redistribute_to_delegators(
+        &mut self,
+        operator_staking_record: &mut StakingRecord,
+        token_amount: u64,
+    ) -> Result<u64> {
+        self.settle_usdc_earnings(operator_staking_record)?;
+        self.settle_reward_mint_earnings(operator_staking_record)?;
+
+        // Operator shares removed = op_shares * amount / (total_staked + amount - op_tokens)
+        let operator_tokens = self.calc_tokens_for_share_amount(operator_staking_record.shares);
+        let shares_removed = u64::try_from(
+            u128::from(operator_staking_record.shares)
+                .checked_mul(u128::from(token_amount))
+                .unwrap()
+                .checked_div(
+                    u128::from(self.total_staked_amount)
+                        .checked_add(u128::from(token_amount))
+                        .unwrap()
+                        .checked_sub(u128::from(operator_tokens))
+                        .unwrap(),
+                )
+                .unwrap(),
+        )
+        .unwrap();
+
+        operator_staking_record.shares = operator_staking_record
+            .shares
+            .checked_sub(shares_removed)
+            .unwrap();
+        self.total_shares = self.total_shares.checked_sub(shares_removed).unwrap();
+        self.total_staked_amount = self.total_staked_amount.checked_add(token_amount).unwrap();
+
+        Ok(shares_removed)
+    }
+
+    /// Calculates the pending unstakes that are still inside their delay.
+    pub fn calc_slashable_unstaking(&self, current_timestamp: i64) -> u64 {
+        self.unstaking_cohorts
+            .iter()
+            .filter(|cohort| cohort.matures_at > current_timestamp)
+            .fold(0, |total, cohort| total.checked_add(cohort.amount).unwrap())
+    }
+
+    /// Adds the pending unstake of a staking record to an unstaking cohort. The unstake joins the
+    /// latest cohort that matures at most join_window_seconds before its unstake_at_timestamp, so
+    /// the number of cohorts inside the delay stays bounded. If no cohort is left, the unstake joins
+    /// the latest cohort that matures before it, or is not slashable if there is none.
+    pub fn add_unstaking_to_cohort(
+        &mut self,
+        staking_record: &mut StakingRecord,
+        current_timestamp: i64,
+        join_window_seconds: i64,
+    ) {
+        self.unstaking_cohorts
+            .retain(|cohort| cohort.matures_at > current_timestamp);
+
+        let unstake_at = staking_record.unstake_at_timestamp;
+        let latest_cohort_index = self
+            .unstaking_cohorts
+            .iter()
+            .enumerate()
+            .filter(|(_, cohort)| cohort.matures_at <= unstake_at)
+            .max_by_key(|(_, cohort)| cohort.matures_at)
+            .map(|(index, _)| index);
+        let cohort_index = match latest_cohort_index {
+            Some(index)
+                if self.unstaking_cohorts[index].matures_at
+                    >= unstake_at.saturating_sub(join_window_seconds)
+                    || self.unstaking_cohorts.len() == MAX_UNSTAKING_COHORTS =>
+            {
+                Some(index)
+            }
+            _ if unstake_at > current_timestamp
+                && self.unstaking_cohorts.len() < MAX_UNSTAKING_COHORTS =>
+            {
+                self.unstaking_cohorts.push(UnstakingCohort {
+                    matures_at: unstake_at,
+                    amount: 0,
+                    unresolved_slash_amount: 0,
+                });
+                Some(self.unstaking_cohorts.len() - 1)
+            }
+            _ => None,
+        };
+
+        match cohort_index {
+            Some(index) => {
+                let cohort = &mut self.unstaking_cohorts[index];
+                cohort.amount = cohort
+                    .amount
+                    .checked_add(staking_record.tokens_unstake_amount)
+                    .unwrap();
+                staking_record.unstaking_cohort_at = cohort.matures_at;
+            }
+            None => staking_record.unstaking_cohort_at = current_timestamp,
+        }
+    }
+
+    /// Removes the pending unstake of a staking record from its unstaking cohort, if the cohort is
+    /// still inside the delay. Must be called after settle_unstaking_slash and before any changes
+    /// to tokens_unstake_amount.
+    pub fn remove_unstaking_from_cohort(&mut self, staking_record: &mut StakingRecord) {
+        if let Some(cohort) = self
+            .unstaking_cohorts
+            .iter_mut()
+            .find(|cohort| cohort.matures_at == staking_record.unstaking_cohort_at)
+        {
+            cohort.amount = cohort
+                .amount
+                .checked_sub(staking_record.tokens_unstake_amount)
+                .unwrap();
+        }
+        staking_record.unstaking_cohort_at = 0;
+    }
+
+    /// Slashes slash_bps of the pending unstakes that are still inside their delay. The slashed
+    /// tokens are removed from total_unstaking immediately, while each StakingRecord is reduced
+    /// lazily when it is settled.
+    /// Returns number of unstaking tokens slashed.
+    pub fn slash_unstaking(&mut self, slash_bps: u16, current_timestamp: i64) -> Result<u64> {
+        require_gt!(
+            MAX_UNSTAKING_SLASHES,
+            self.unstaking_slashes.len(),
+            ErrorCode::UnstakingSlashLimitReached
+        );
+        self.unstaking_cohorts
+            .retain(|cohort| cohort.matures_at > current_timestamp);
+
+        let mut slashed_amount: u64 = 0;
+        for cohort in self.unstaking_cohorts.iter_mut() {
+            // Rounded down, so cohorts always cover the lazily settled StakingRecords.
+            let cohort_slashed_amount = u64::try_from(
+                u128::from(cohort.amount)
+                    .checked_mul(slash_bps.into())
+                    .unwrap()
+                    .checked_div(10_000)
+                    .unwrap(),
+            )
+            .unwrap();
+            cohort.amount = cohort.amount.checked_sub(cohort_slashed_amount).unwrap();
+            cohort.unresolved_slash_amount = cohort_slashed_amount;
+            slashed_amount = slashed_amount.checked_add(cohort_slashed_amount).unwrap();
+        }
+        self.total_unstaking = self.total_unstaking.checked_sub(slashed_amount).unwrap();
+        self.unstaking_slash_count = self.unstaking_slash_count.checked_add(1).unwrap();
+        self.unstaking_slashes.push(UnstakingSlash {
+            id: self.unstaking_slash_count,
+            slashed_at: current_timestamp,
+            slash_bps,
+        });
+
+        Ok(slashed_amount)
+    }
+
+    /// Resolves the unresolved unstaking slash once its escrow is released.
+    pub fn release_unstaking_slash(&mut self) {
+        for cohort in self.unstaking_cohorts.iter_mut() {
+            cohort.unresolved_slash_amount = 0;
+        }
+        self.unstaking_slash_escrow = None;
+    }
+
+    /// Restores the pending unstakes of a reversed unstaking slash and removes the slash.
+    pub fn reverse_unstaking_slash(&mut self, token_amount: u64) {
+        self.total_unstaking = self.total_unstaking.checked_add(token_amount).unwrap();
+        for cohort in self.unstaking_cohorts.iter_mut() {
+            cohort.amount = cohort
+                .amount
+                .checked_add(cohort.unresolved_slash_amount)
+                .unwrap();
+            cohort.unresolved_slash_amount = 0;
+        }
+        self.unstaking_slashes.pop();
+        self.unstaking_slash_escrow = None;
+    }
+
+    /// Whether an unstaking slash applies to the pending unstake of a staking record.
+    fn is_unstaking_slashed_by(staking_record: &StakingRecord, slash: &UnstakingSlash) -> bool {
+        slash.id > staking_record.unstaking_slash_checkpoint
+            && slash.slashed_at < staking_record.unstaking_cohort_at
+    }
+
+    /// Calculates the unstaking tokens of a staking record that are slashed by the unstaking slashes
+    /// since the record's checkpoint that happened before its cohort matured.
+    pub fn calc_unstaking_slash(&self, staking_record: &StakingRecord) -> u64 {
+        let remaining_amount = self
+            .unstaking_slashes
+            .iter()
+            .filter(|slash| Self::is_unstaking_slashed_by(staking_record, slash))
+            .fold(staking_record.tokens_unstake_amount, |amount, slash| {
+                u64::try_from(
+                    u128::from(amount)
+                        .checked_mul(u128::from(10_000 - slash.slash_bps))
+                        .unwrap()
+                        .checked_div(10_000)
+                        .unwrap(),
+                )
+                .unwrap()
+            });
+        staking_record
+            .tokens_unstake_amount
+            .checked_sub(remaining_amount)
+            .unwrap()
+    }
+
+    /// Settle unstaking slashes for a staking record. Must be called before any changes to
+    /// tokens_unstake_amount. The pool totals already account for the slash.
+    /// Fails for records reduced by the unresolved unstaking slash, which locks their unstake,
+    /// claim_unstake and cancel_unstake until the SlashEscrow is released or reversed.
+    /// Returns number of unstaking tokens slashed from the record.
+    pub fn settle_unstaking_slash(&self, staking_record: &mut StakingRecord) -> Result<u64> {
+        if self.unstaking_slash_escrow.is_some() {
+            let unresolved_slash = self.unstaking_slashes.last().unwrap();
+            require!(
+                !Self::is_unstaking_slashed_by(staking_record, unresolved_slash),
+                ErrorCode::UnstakingSlashUnresolved
+            );
+        }
+
+        let slashed_amount = self.calc_unstaking_slash(staking_record);
+        staking_record.tokens_unstake_amount = staking_record
+            .tokens_unstake_amount
+            .checked_sub(slashed_amount)
+            .unwrap();
+        staking_record.unstaking_slash_checkpoint = self.unstaking_slash_count;
+
+        Ok(slashed_amount)
+    }
+
     /// Check that all rewards have been claimed for pool closure conditions.
     /// Returns an error if rewards are unclaimed and conditions are not met.
+    #[allow(clippy::unnecessary_unwrap)]
     pub fn check_unclaimed_rewards(&self, completed_reward_epoch: u64) -> Result<()> {
         if completed_reward_epoch > self.reward_last_claimed_epoch {
             if self.closed_at_epoch.is_some() {
@@ -303,20 +856,228 @@ impl OperatorPool {
         false
     }
 
-    /// Updates reward commission to new rate. Called after accrual of all issued rewards.
-    pub fn update_reward_commission_rate(&mut self) {
-        if let Some(new_commission_rate_bps) = self.new_reward_commission_rate_bps {
-            self.reward_commission_rate_bps = new_commission_rate_bps;
-            self.new_reward_commission_rate_bps = None;
+    /// Settle additional reward mint earnings for a staking record.
+    /// Must be called before any share modifications.
+    pub fn settle_reward_mint_earnings(&self, staking_record: &mut StakingRecord) -> Result<()> {
+        for i in 0..MAX_REWARD_MINTS {
+            let reward_per_share_settlement_delta = self.cumulative_reward_mint_per_share[i]
+                .saturating_sub(staking_record.last_settled_reward_mint_per_share[i]);
+
+            let earned = (staking_record.shares as u128)
+                .checked_mul(reward_per_share_settlement_delta)
+                .unwrap()
+                .checked_div(USDC_PRECISION_FACTOR)
+                .unwrap();
+
+            staking_record.accrued_reward_mint_earnings[i] = staking_record
+                .accrued_reward_mint_earnings[i]
+                .checked_add(earned as u64)
+                .unwrap();
+            staking_record.last_settled_reward_mint_per_share[i] =
+                self.cumulative_reward_mint_per_share[i];
         }
+
+        Ok(())
+    }
+
+    /// Check if a staking record has unclaimed earnings for any additional reward mint.
+    pub fn has_unclaimed_reward_mint_earnings(&self, staking_record: &StakingRecord) -> bool {
+        (0..MAX_REWARD_MINTS).any(|i| {
+            if staking_record.accrued_reward_mint_earnings[i] > 0 {
+                return true;
+            }
+
+            let reward_per_share_settlement_delta = self.cumulative_reward_mint_per_share[i]
+                .saturating_sub(staking_record.last_settled_reward_mint_per_share[i]);
+
+            (staking_record.shares as u128)
+                .saturating_mul(reward_per_share_settlement_delta)
+                .saturating_div(USDC_PRECISION_FACTOR)
+                > 0
+        })
     }
 
-    /// Updates USDC commission to new rate. Called after accrual of all issued rewards.
-    pub fn update_usdc_commission_rate(&mut self) {
-        if let Some(new_commission_rate_bps) = self.new_usdc_commission_rate_bps {
-            self.usdc_commission_rate_bps = new_commission_rate_bps;
-            self.new_usdc_commission_rate_bps = None;
+    /// Calculates the part of a commission amount that is paid to referrers. This is the
+    /// referral commission share of the commission earned on referred shares.
+    /// Final result is rounded down as part of integer division.
+    pub fn calc_referral_commission(&self, commission: u64) -> u64 {
+        if self.total_shares == 0 || self.total_referred_shares == 0 {
+            return 0;
+        }
+
+        let referral_commission = u128::from(commission)
+            .checked_mul(u128::from(self.total_referred_shares))
+            .unwrap()
+            .checked_div(u128::from(self.total_shares))
+            .unwrap()
+            .checked_mul(u128::from(self.referral_commission_share_bps))
+            .unwrap()
+            .checked_div(10_000)
+            .unwrap();
+
+        u64::try_from(referral_commission).unwrap()
+    }
+
+    /// Adds referral commission to the per referred share indexes.
+    pub fn accrue_referral_commission(&mut self, reward_amount: u64, usdc_amount: u64) {
+        if self.total_referred_shares == 0 {
+            return;
         }
+
+        let reward_per_share_increase = u128::from(reward_amount)
+            .checked_mul(USDC_PRECISION_FACTOR)
+            .unwrap()
+            .checked_div(u128::from(self.total_referred_shares))
+            .unwrap();
+        self.cumulative_referral_reward_per_share = self
+            .cumulative_referral_reward_per_share
+            .checked_add(reward_per_share_increase)
+            .unwrap();
+
+        let usdc_per_share_increase = u128::from(usdc_amount)
+            .checked_mul(USDC_PRECISION_FACTOR)
+            .unwrap()
+            .checked_div(u128::from(self.total_referred_shares))
+            .unwrap();
+        self.cumulative_referral_usdc_per_share = self
+            .cumulative_referral_usdc_per_share
+            .checked_add(usdc_per_share_increase)
+            .unwrap();
+    }
+
+    /// Settle referral earnings for a referral record.
+    /// Must be called before any referred share modifications.
+    pub fn settle_referral_earnings(&self, referral_record: &mut ReferralRecord) -> Result<()> {
+        let reward_per_share_settlement_delta = self
+            .cumulative_referral_reward_per_share
+            .saturating_sub(referral_record.last_settled_referral_reward_per_share);
+        let earned_rewards = u128::from(referral_record.referred_shares)
+            .checked_mul(reward_per_share_settlement_delta)
+            .unwrap()
+            .checked_div(USDC_PRECISION_FACTOR)
+            .unwrap();
+
+        let usdc_per_share_settlement_delta = self
+            .cumulative_referral_usdc_per_share
+            .saturating_sub(referral_record.last_settled_referral_usdc_per_share);
+        let earned_usdc = u128::from(referral_record.referred_shares)
+            .checked_mul(usdc_per_share_settlement_delta)
+            .unwrap()
+            .checked_div(USDC_PRECISION_FACTOR)
+            .unwrap();
+
+        referral_record.accrued_reward_earnings = referral_record
+            .accrued_reward_earnings
+            .checked_add(earned_rewards as u64)
+            .unwrap();
+        referral_record.accrued_usdc_earnings = referral_record
+            .accrued_usdc_earnings
+            .checked_add(earned_usdc as u64)
+            .unwrap();
+        referral_record.last_settled_referral_reward_per_share =
+            self.cumulative_referral_reward_per_share;
+        referral_record.last_settled_referral_usdc_per_share =
+            self.cumulative_referral_usdc_per_share;
+
+        Ok(())
+    }
+
+    /// Settles referral earnings and adds shares to the referral record and pool referred shares.
+    pub fn add_referred_shares(
+        &mut self,
+        referral_record: &mut ReferralRecord,
+        shares_amount: u64,
+    ) -> Result<()> {
+        self.settle_referral_earnings(referral_record)?;
+
+        referral_record.referred_shares = referral_record
+            .referred_shares
+            .checked_add(shares_amount)
+            .unwrap();
+        self.total_referred_shares = self
+            .total_referred_shares
+            .checked_add(shares_amount)
+            .unwrap();
+
+        Ok(())
+    }
+
+    /// Settles referral earnings and removes shares from the referral record and pool referred shares.
+    pub fn remove_referred_shares(
+        &mut self,
+        referral_record: &mut ReferralRecord,
+        shares_amount: u64,
+    ) -> Result<()> {
+        self.settle_referral_earnings(referral_record)?;
+
+        referral_record.referred_shares = referral_record
+            .referred_shares
+            .checked_sub(shares_amount)
+            .unwrap();
+        self.total_referred_shares = self
+            .total_referred_shares
+            .checked_sub(shares_amount)
+            .unwrap();
+
+        Ok(())
+    }
+
+    /// Updates reward commission rate to any scheduled rates that apply to the given reward
+    /// epoch. Called before accrual of rewards for the epoch.
+    pub fn update_reward_commission_rate(&mut self, epoch: u64) {
+        Self::apply_commission_rate_schedule(
+            &mut self.reward_commission_rate_bps,
+            &mut self.reward_commission_rate_schedule,
+            epoch,
+        );
+    }
+
+    /// Updates USDC commission rate to any scheduled rates that apply to the given reward
+    /// epoch. Called before accrual of rewards for the epoch.
+    pub fn update_usdc_commission_rate(&mut self, epoch: u64) {
+        Self::apply_commission_rate_schedule(
+            &mut self.usdc_commission_rate_bps,
+            &mut self.usdc_commission_rate_schedule,
+            epoch,
+        );
+    }
+
+    fn apply_commission_rate_schedule(
+        commission_rate_bps: &mut u16,
+        schedule: &mut Vec<ScheduledCommissionRate>,
+        epoch: u64,
+    ) {
+        let applied = schedule
+            .iter()
+            .take_while(|entry| entry.effective_epoch <= epoch)
+            .count();
+        if let Some(entry) = schedule.drain(..applied).next_back() {
+            *commission_rate_bps = entry.rate_bps;
+        }
+    }
+
+    /// Splits commission amount across commission recipients by their share.
+    /// Rounding dust is assigned to the last recipient so the full amount is distributed.
+    pub fn calc_commission_split(&self, amount: u64) -> Vec<u64> {
+        let mut remaining = amount;
+        let mut split = Vec::with_capacity(self.commission_recipients.len());
+        for (i, recipient) in self.commission_recipients.iter().enumerate() {
+            let share = if i == self.commission_recipients.len() - 1 {
+                remaining
+            } else {
+                u64::try_from(
+                    u128::from(amount)
+                        .checked_mul(recipient.share_bps.into())
+                        .unwrap()
+                        .checked_div(10_000)
+                        .unwrap(),
+                )
+                .unwrap()
+            };
+            remaining = remaining.checked_sub(share).unwrap();
+            split.push(share);
+        }
+        split
     }
 
     /// Checks if the operator pool is fully empty (no shares, no staked tokens, no unstaking).
@@ -397,6 +1158,37 @@ impl OperatorPool {
         Ok(())
     }
 
+    pub fn validate_commission_recipients(
+        commission_recipients: &[CommissionRecipient],
+    ) -> Result<()> {
+        if commission_recipients.is_empty() {
+            return Ok(());
+        }
+
+        require_gte!(
+            MAX_COMMISSION_RECIPIENTS,
+            commission_recipients.len(),
+            ErrorCode::CommissionRecipientsLengthInvalid
+        );
+
+        let mut seen = HashSet::new();
+        let mut total_share_bps: u32 = 0;
+        for commission_recipient in commission_recipients {
+            if !seen.insert(commission_recipient.recipient) {
+                return Err(ErrorCode::DuplicateCommissionRecipient.into());
+            }
+            require_gt!(
+                commission_recipient.share_bps,
+                0,
+                ErrorCode::InvalidCommissionSplit
+            );
+            total_share_bps += u32::from(commission_recipient.share_bps);
+        }
+        require_eq!(total_share_bps, 10_000, ErrorCode::InvalidCommissionSplit);
+
+        Ok(())
+    }
+
     pub fn validate_commission_rate(commission_rate_bps: u16) -> Result<()> {
         require_gte!(
             10_000,
@@ -406,3 +1198,230 @@ impl OperatorPool {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(effective_epoch: u64, rate_bps: u16) -> ScheduledCommissionRate {
+        ScheduledCommissionRate {
+            effective_epoch,
+            rate_bps,
+        }
+    }
+
+    #[test]
+    fn test_validate_commission_recipients() {
+        let recipient = |share_bps| CommissionRecipient {
+            recipient: Pubkey::new_unique(),
+            share_bps,
+        };
+
+        assert!(OperatorPool::validate_commission_recipients(&[]).is_ok());
+        assert!(OperatorPool::validate_commission_recipients(&[
+            recipient(7_000),
+            recipient(3_000)
+        ])
+        .is_ok());
+        assert!(OperatorPool::validate_commission_recipients(&[
+            recipient(7_000),
+            recipient(2_000)
+        ])
+        .is_err());
+        assert!(
+            OperatorPool::validate_commission_recipients(&[recipient(10_000), recipient(0)])
+                .is_err()
+        );
+
+        let duplicate = recipient(5_000);
+        assert!(
+            OperatorPool::validate_commission_recipients(&[duplicate.clone(), duplicate]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_apply_commission_rate_schedule() {
+        let mut rate_bps = 0;
+        let mut schedule = vec![entry(5, 100), entry(7, 200), entry(10, 500)];
+
+        // No entries apply before their effective epoch.
+        OperatorPool::apply_commission_rate_schedule(&mut rate_bps, &mut schedule, 4);
+        assert_eq!(rate_bps, 0);
+        assert_eq!(schedule.len(), 3);
+
+        OperatorPool::apply_commission_rate_schedule(&mut rate_bps, &mut schedule, 5);
+        assert_eq!(rate_bps, 100);
+        assert!(schedule == vec![entry(7, 200), entry(10, 500)]);
+
+        // Multiple entries are applied at once when accruing a later epoch.
+        OperatorPool::apply_commission_rate_schedule(&mut rate_bps, &mut schedule, 12);
+        assert_eq!(rate_bps, 500);
+        assert!(schedule.is_empty());
+    }
+
+    #[test]
+    fn test_confiscation_policy() {
+        assert_eq!(ConfiscationPolicy::All.calc_amount(1_001), 1_001);
+        assert_eq!(
+            ConfiscationPolicy::Percentage(2_500).calc_amount(1_001),
+            250
+        );
+        assert_eq!(ConfiscationPolicy::None.calc_amount(1_001), 0);
+
+        assert!(ConfiscationPolicy::Percentage(10_000).validate().is_ok());
+        assert!(ConfiscationPolicy::Percentage(10_001).validate().is_err());
+    }
+
+    #[test]
+    fn test_unstaking_slash_exempts_claimable_unstakes() {
+        let zeroed = vec![0; OperatorPool::INIT_SPACE];
+        let mut operator_pool = OperatorPool::deserialize(&mut zeroed.as_slice()).unwrap();
+        let new_staking_record = |tokens_unstake_amount, unstake_at_timestamp| {
+            let zeroed = vec![0; StakingRecord::INIT_SPACE];
+            let mut staking_record = StakingRecord::deserialize(&mut zeroed.as_slice()).unwrap();
+            staking_record.tokens_unstake_amount = tokens_unstake_amount;
+            staking_record.unstake_at_timestamp = unstake_at_timestamp;
+            staking_record
+        };
+
+        // The first unstake is claimable from 110, the second one from 200.
+        let mut claimable_record = new_staking_record(1_000, 110);
+        let mut pending_record = new_staking_record(3_001, 200);
+        operator_pool.total_unstaking = 4_001;
+        operator_pool.add_unstaking_to_cohort(&mut claimable_record, 100, 10);
+        operator_pool.add_unstaking_to_cohort(&mut pending_record, 100, 10);
+        assert_eq!(operator_pool.unstaking_cohorts.len(), 2);
+        assert_eq!(operator_pool.calc_slashable_unstaking(100), 4_001);
+
+        // Only the unstake still inside its delay is slashed at 150.
+        assert_eq!(operator_pool.calc_slashable_unstaking(150), 3_001);
+        let slashed_amount = operator_pool.slash_unstaking(1_000, 150).unwrap();
+        assert_eq!(slashed_amount, 300);
+        assert_eq!(operator_pool.total_unstaking, 3_701);
+        assert_eq!(operator_pool.unstaking_cohorts.len(), 1);
+
+        // While the slash is unresolved, only the exempt record can be settled.
+        operator_pool.unstaking_slash_escrow = Some(Pubkey::new_unique());
+        assert_eq!(operator_pool.calc_unstaking_slash(&claimable_record), 0);
+        assert_eq!(operator_pool.calc_unstaking_slash(&pending_record), 301);
+        assert!(operator_pool
+            .settle_unstaking_slash(&mut pending_record)
+            .is_err());
+        assert_eq!(
+            operator_pool
+                .settle_unstaking_slash(&mut claimable_record)
+                .unwrap(),
+            0
+        );
+
+        // Once released, the pending unstake is reduced by the slash and the pool totals cover
+        // both StakingRecords.
+        operator_pool.release_unstaking_slash();
+        assert_eq!(
+            operator_pool
+                .settle_unstaking_slash(&mut pending_record)
+                .unwrap(),
+            301
+        );
+        assert_eq!(pending_record.tokens_unstake_amount, 2_700);
+        assert!(
+            operator_pool.total_unstaking
+                >= claimable_record.tokens_unstake_amount + pending_record.tokens_unstake_amount
+        );
+
+        // Settled records are not slashed again by the same slash.
+        assert_eq!(operator_pool.calc_unstaking_slash(&pending_record), 0);
+        operator_pool.remove_unstaking_from_cohort(&mut pending_record);
+        assert_eq!(operator_pool.unstaking_cohorts[0].amount, 1);
+    }
+
+    #[test]
+    fn test_reverse_unstaking_slash() {
+        let zeroed = vec![0; OperatorPool::INIT_SPACE];
+        let mut operator_pool = OperatorPool::deserialize(&mut zeroed.as_slice()).unwrap();
+        let zeroed = vec![0; StakingRecord::INIT_SPACE];
+        let mut staking_record = StakingRecord::deserialize(&mut zeroed.as_slice()).unwrap();
+        staking_record.tokens_unstake_amount = 2_000;
+        staking_record.unstake_at_timestamp = 200;
+        operator_pool.total_unstaking = 2_000;
+        operator_pool.add_unstaking_to_cohort(&mut staking_record, 100, 10);
+
+        let slashed_amount = operator_pool.slash_unstaking(5_000, 150).unwrap();
+        operator_pool.unstaking_slash_escrow = Some(Pubkey::new_unique());
+        operator_pool.reverse_unstaking_slash(slashed_amount);
+
+        // The reversed slash no longer applies, and a later slash uses a new id.
+        assert_eq!(operator_pool.total_unstaking, 2_000);
+        assert_eq!(operator_pool.unstaking_cohorts[0].amount, 2_000);
+        assert_eq!(operator_pool.calc_unstaking_slash(&staking_record), 0);
+        operator_pool.slash_unstaking(1_000, 160).unwrap();
+        assert_eq!(operator_pool.unstaking_slashes[0].id, 2);
+        assert_eq!(operator_pool.calc_unstaking_slash(&staking_record), 200);
+    }
+
+    #[test]
+    fn test_unstaking_slash_limit() {
+        let zeroed = vec![0; OperatorPool::INIT_SPACE];
+        let mut operator_pool = OperatorPool::deserialize(&mut zeroed.as_slice()).unwrap();
+        for _ in 0..MAX_UNSTAKING_SLASHES {
+            operator_pool.slash_unstaking(1_000, 100).unwrap();
+        }
+        assert!(operator_pool.slash_unstaking(1_000, 100).is_err());
+    }
+
+    #[test]
+    fn test_migrate_v1_operator_pool() {
+        // Space of OperatorPools created with the version 1 layout.
+        const V1_SPACE: usize = 8 + 1_207 + OperatorPool::PADDING;
+
+        let zeroed = vec![0; OperatorPool::INIT_SPACE];
+        let mut operator_pool = OperatorPool::deserialize(&mut zeroed.as_slice()).unwrap();
+        operator_pool.version = 1;
+        operator_pool.name = "a".repeat(MAX_NAME_LENGTH);
+        operator_pool.description = Some("a".repeat(MAX_DESCRIPTION_LENGTH));
+        operator_pool.website_url = Some("a".repeat(MAX_WEBSITE_URL_LENGTH));
+        operator_pool.avatar_image_url = Some("a".repeat(MAX_AVATAR_IMAGE_URL_LENGTH));
+        operator_pool.operator_auth_keys =
+            vec![Pubkey::new_unique(); MAX_OPERATOR_AUTH_KEYS_LENGTH];
+        operator_pool.new_reward_commission_rate_bps = Some(1_500);
+        operator_pool.new_usdc_commission_rate_bps = Some(2_000);
+        operator_pool.total_staked_amount = 1_000;
+        operator_pool.reward_last_claimed_epoch = 4;
+
+        // Fields added after version 1 are zero, so this is the data of a version 1 account.
+        let mut data = vec![0; V1_SPACE];
+        operator_pool
+            .try_serialize(&mut data.as_mut_slice())
+            .unwrap();
+
+        // The OperatorPool is loaded before it is reallocated, so the largest version 1 account
+        // must hold the current fields.
+        let mut operator_pool = OperatorPool::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(operator_pool.migrate(5).unwrap(), 1);
+        data.resize(8 + OperatorPool::INIT_SPACE + OperatorPool::PADDING, 0);
+        operator_pool
+            .try_serialize(&mut data.as_mut_slice())
+            .unwrap();
+
+        let mut operator_pool = OperatorPool::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(operator_pool.version, OperatorPool::VERSION);
+        assert_eq!(operator_pool.name, "a".repeat(MAX_NAME_LENGTH));
+        assert_eq!(operator_pool.total_staked_amount, 1_000);
+        assert_eq!(operator_pool.new_reward_commission_rate_bps, None);
+        assert_eq!(operator_pool.new_usdc_commission_rate_bps, None);
+        // The pool has not accrued the completed epoch, so the pending rates apply from the next
+        // epoch it accrues.
+        assert!(operator_pool.reward_commission_rate_schedule == vec![entry(6, 1_500)]);
+        assert!(operator_pool.usdc_commission_rate_schedule == vec![entry(6, 2_000)]);
+        assert!(operator_pool.migrate(5).is_err());
+
+        // Pools that have accrued the completed epoch apply the pending rates an epoch later.
+        let mut operator_pool = OperatorPool::deserialize(&mut zeroed.as_slice()).unwrap();
+        operator_pool.version = 1;
+        operator_pool.new_reward_commission_rate_bps = Some(1_500);
+        operator_pool.reward_last_claimed_epoch = 5;
+        operator_pool.migrate(5).unwrap();
+        assert!(operator_pool.reward_commission_rate_schedule == vec![entry(7, 1_500)]);
+        assert!(operator_pool.usdc_commission_rate_schedule.is_empty());
+    }
+}
@@ -0,0 +1,75 @@
+/// Instructions that can be paused individually, by their bit index in
+/// PoolOverview.paused_instructions. Bit indexes must not be reordered, as they are stored
+/// on-chain.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum PausableInstruction {
+    UpdatePoolOverview = 0,
+    UpdatePoolOverviewAuthorities = 1,
+    QueueConfigChange = 2,
+    ExecuteConfigChange = 3,
+    CancelConfigChange = 4,
+    ProposeProgramAdmin = 5,
+    AcceptProgramAdmin = 6,
+    CancelProgramAdminTransfer = 7,
+    MarkEpochAsFinalizing = 8,
+    AddRewardMint = 9,
+    CreateStakingRecord = 10,
+    Stake = 11,
+    Unstake = 12,
+    ClaimUnstake = 13,
+    CancelUnstake = 14,
+    CloseStakingRecord = 15,
+    ClaimUsdcEarnings = 16,
+    ClaimRewardMintEarnings = 17,
+    CreateReferralRecord = 18,
+    ClaimReferralEarnings = 19,
+    CreateRewardRecord = 20,
+    AccrueReward = 21,
+    AccrueRewardEmergencyBypass = 22,
+    CreateOperatorPool = 23,
+    WithdrawOperatorRewardCommission = 24,
+    WithdrawOperatorUsdcCommission = 25,
+    DistributeOperatorRewardCommission = 26,
+    DistributeOperatorUsdcCommission = 27,
+    ChangeOperatorStakingRecord = 28,
+    ChangeOperatorAdmin = 29,
+    UpdateOperatorPool = 30,
+    CloseOperatorPool = 31,
+    SweepClosedPoolUsdcDust = 32,
+    SetHaltStatus = 33,
+    ExpireHalt = 34,
+    ReportEquivocation = 35,
+    TombstoneOperatorKey = 36,
+    CreateSlashProposal = 37,
+    ApproveSlashProposal = 38,
+    SlashStake = 39,
+    ReverseSlash = 40,
+    ReleaseSlashEscrow = 41,
+    CreatePendingAction = 42,
+    ApprovePendingAction = 43,
+    CreateInsuranceFund = 44,
+    DepositInsuranceContributions = 45,
+    CreateInsuranceClaim = 46,
+    ApproveInsuranceClaim = 47,
+    ClaimInsurance = 48,
+    InitiateWindDown = 49,
+    WindDownStakingRecord = 50,
+    MigrateOperatorPool = 51,
+    MigrateStakingRecord = 52,
+    CreateAdminAuditLog = 53,
+    MigrateRewardRecord = 54,
+}
+
+impl PausableInstruction {
+    /// Number of pausable instructions.
+    pub const COUNT: u8 = 55;
+
+    /// Mask of all pausable instructions.
+    pub const ALL_MASK: u64 = (1 << Self::COUNT) - 1;
+
+    /// Returns the bit of the instruction in PoolOverview.paused_instructions.
+    pub fn mask(self) -> u64 {
+        1 << (self as u8)
+    }
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode,
+    state::{PoolOverview, MAX_APPROVALS},
+};
+
+/// Authority role whose actions can require approval by multiple authorities. Slashing has no
+/// role, as `slash_stake` is already gated by the approvals of its SlashProposal, which serves
+/// as the PendingAction of the slashing authorities.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AuthorityRole {
+    /// Reward distribution authorities, gating `create_reward_record`.
+    RewardDistribution,
+    /// Halt authorities, gating `set_halt_status`.
+    Halt,
+}
+
+#[derive(InitSpace)]
+#[account]
+pub struct PendingAction {
+    /// Version of the PendingAction account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// Id of the action, assigned from PoolOverview.pending_action_count.
+    pub id: u64,
+
+    /// Authority role that must approve the action.
+    pub role: AuthorityRole,
+
+    /// Hash of the gated instruction and its arguments.
+    pub action_hash: [u8; 32],
+
+    /// Authority that created the action.
+    pub proposer: Pubkey,
+
+    /// Authorities that approved the action, including the proposer.
+    #[max_len(MAX_APPROVALS)]
+    pub approvals: Vec<Pubkey>,
+
+    /// Timestamp when the action was created.
+    pub created_at: i64,
+
+    /// Timestamp when the action was executed, if executed.
+    pub executed_at: Option<i64>,
+}
+
+impl PendingAction {
+    /// Version of the PendingAction account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for PendingAction account.
+    pub const SEED: &'static [u8] = b"PendingAction";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 128;
+}
+
+impl PendingAction {
+    /// Counts approvals from keys that are still authorities of the role.
+    pub fn count_valid_approvals(&self, authorities: &[Pubkey]) -> u8 {
+        let count = self
+            .approvals
+            .iter()
+            .filter(|approval| authorities.contains(approval))
+            .count();
+        u8::try_from(count).unwrap()
+    }
+
+    /// Checks that the action matches the gated instruction and has enough approvals from current
+    /// authorities of the role, then marks it as executed.
+    pub fn execute(
+        &mut self,
+        pool_overview: &PoolOverview,
+        role: AuthorityRole,
+        action_hash: [u8; 32],
+        current_timestamp: i64,
+    ) -> Result<()> {
+        require!(
+            self.role == role && self.action_hash == action_hash,
+            ErrorCode::PendingActionMismatch
+        );
+        require!(
+            self.executed_at.is_none(),
+            ErrorCode::PendingActionAlreadyExecuted
+        );
+        require_gte!(
+            self.count_valid_approvals(pool_overview.authorities(role)),
+            pool_overview.approval_threshold(role),
+            ErrorCode::PendingActionNotApproved
+        );
+        self.executed_at = Some(current_timestamp);
+        Ok(())
+    }
+}
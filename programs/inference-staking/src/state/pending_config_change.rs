@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::UpdatePoolOverviewArgs;
+
+#[derive(InitSpace)]
+#[account]
+pub struct PendingConfigChange {
+    /// Version of the PendingConfigChange account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// Id of the config change, assigned from PoolOverview.config_change_count.
+    pub id: u64,
+
+    /// Account that paid rent for this account, refunded when executed or cancelled.
+    pub rent_payer: Pubkey,
+
+    /// Settings to change on PoolOverview.
+    pub args: UpdatePoolOverviewArgs,
+
+    /// New wallet address that receives the operator pool registration fees, if changed.
+    pub registration_fee_payout_wallet: Option<Pubkey>,
+
+    /// New destination account for slashed USDC, if changed.
+    pub slashing_destination_usdc_account: Option<Pubkey>,
+
+    /// New destination account for slashed tokens, if changed.
+    pub slashing_destination_token_account: Option<Pubkey>,
+
+    /// Timestamp when the config change was queued.
+    pub queued_at: i64,
+
+    /// Timestamp from which the config change can be executed.
+    pub execute_at: i64,
+
+    /// Program admin that queued the config change.
+    pub queued_by: Pubkey,
+}
+
+impl PendingConfigChange {
+    /// Version of the PendingConfigChange account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for PendingConfigChange account.
+    pub const SEED: &'static [u8] = b"PendingConfigChange";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 128;
+}
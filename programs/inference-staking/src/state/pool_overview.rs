@@ -1,5 +1,67 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    error::ErrorCode,
+    state::{
+        AdminActionKind, AdminAuditLog, AuthorityRole, OperatorPool, PausableInstruction,
+        ScheduledCommissionRate, MAX_SCHEDULED_COMMISSION_RATES, MAX_UNSTAKING_COHORTS,
+    },
+};
+
+/// Max. number of additional reward mints that can be registered on PoolOverview.
+pub const MAX_REWARD_MINTS: usize = 4;
+
+/// Number of keys of each authority list included in the initial PoolOverview account space.
+pub const BASE_AUTHORITIES: usize = 5;
+
+/// Max. number of keys of each authority list. Lists longer than BASE_AUTHORITIES require the
+/// PoolOverview account to be reallocated.
+pub const MAX_AUTHORITIES: usize = 32;
+
+/// Max. number of approvals recorded on a SlashProposal or PendingAction, which also bounds the
+/// approval thresholds.
+pub const MAX_APPROVALS: usize = 5;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RewardMint {
+    /// Mint address of the additional reward token.
+    pub mint: Pubkey,
+
+    /// Total amount of this reward token across all epochs that is issued, but yet to be claimed.
+    pub unclaimed_amount: u64,
+}
+
+/// Snapshot of the PoolOverview settings managed by the program admin, emitted in events.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PoolOverviewConfig {
+    pub is_staking_halted: bool,
+    pub is_withdrawal_halted: bool,
+    pub is_accrue_reward_halted: bool,
+    pub allow_pool_creation: bool,
+    pub operator_pool_registration_fee: u64,
+    pub registration_fee_payout_wallet: Pubkey,
+    pub slashing_destination_usdc_account: Pubkey,
+    pub slashing_destination_token_account: Pubkey,
+    pub min_operator_token_stake: u64,
+    pub delegator_unstake_delay_seconds: u64,
+    pub operator_unstake_delay_seconds: u64,
+    pub slashing_delay_seconds: u64,
+    pub max_commission_rate_bps: Option<u16>,
+    pub max_commission_increase_bps: Option<u16>,
+    pub commission_increase_notice_epochs: u64,
+    pub commission_decrease_notice_epochs: u64,
+    pub max_delegator_slash_ratio_bps: Option<u32>,
+    pub slashing_approval_threshold: u8,
+    pub slashing_appeal_period_seconds: u64,
+    pub slash_redistribution_bps: u16,
+    pub slash_burn_bps: u16,
+    pub insurance_slash_bps: u16,
+    pub insurance_commission_bps: u16,
+    pub config_change_timelock_seconds: u64,
+    pub reward_distribution_approval_threshold: u8,
+    pub halt_approval_threshold: u8,
+}
+
 #[derive(InitSpace)]
 #[account]
 pub struct PoolOverview {
@@ -13,15 +75,15 @@ pub struct PoolOverview {
     pub program_admin: Pubkey,
 
     /// List of signers authorized to create or modify RewardRecord.
-    #[max_len(5)]
+    #[max_len(BASE_AUTHORITIES)]
     pub reward_distribution_authorities: Vec<Pubkey>,
 
     /// List of signers authorized to set OperatorPool.halted_at.
-    #[max_len(5)]
+    #[max_len(BASE_AUTHORITIES)]
     pub halt_authorities: Vec<Pubkey>,
 
     /// List of signers authorized to slash Operator's stake.
-    #[max_len(5)]
+    #[max_len(BASE_AUTHORITIES)]
     pub slashing_authorities: Vec<Pubkey>,
 
     /// Destination account for slashed USDC tokens.
@@ -75,6 +137,103 @@ pub struct PoolOverview {
 
     /// Total amount of USDC tokens across all epochs that are issued, but yet to be paid out.
     pub unclaimed_usdc: u64,
+
+    /// Registry of additional reward mints paid out besides the staked token and USDC.
+    /// The position of a mint in this list is used to index the per-mint accounting
+    /// on OperatorPool, StakingRecord and RewardRecord, so mints can only be appended.
+    #[max_len(MAX_REWARD_MINTS)]
+    pub reward_mints: Vec<RewardMint>,
+
+    /// Max. commission rate in basis points that an Operator can set. No maximum if not set.
+    pub max_commission_rate_bps: Option<u16>,
+
+    /// Max. increase in basis points of a single commission rate change. No maximum if not set.
+    pub max_commission_increase_bps: Option<u16>,
+
+    /// Min. number of epochs before a commission rate increase takes effect. Must be at least
+    /// `commission_decrease_notice_epochs`. A value of 0 is treated as 1 (the next epoch).
+    pub commission_increase_notice_epochs: u64,
+
+    /// Min. number of epochs before a commission rate decrease takes effect.
+    /// A value of 0 is treated as 1 (the next epoch).
+    pub commission_decrease_notice_epochs: u64,
+
+    /// Max. ratio in basis points of the delegator loss to the operator loss in a slash. For example,
+    /// 20,000 allows delegators to lose up to twice as much as the operator. No maximum if not set.
+    pub max_delegator_slash_ratio_bps: Option<u32>,
+
+    /// Min. number of slashing authority approvals on a SlashProposal before it can be executed.
+    /// A value of 0 is treated as 1.
+    pub slashing_approval_threshold: u8,
+
+    /// Period in seconds that slashed funds are held in escrow, during which the program admin
+    /// can reverse the slash.
+    pub slashing_appeal_period_seconds: u64,
+
+    /// Share of slashed tokens in basis points that is redistributed to the non-operator delegators
+    /// of the slashed pool.
+    pub slash_redistribution_bps: u16,
+
+    /// Share of slashed tokens in basis points that is burned. The remainder of slashed tokens is
+    /// sent to the slashing destination token account.
+    pub slash_burn_bps: u16,
+
+    /// Share of slashed tokens and USDC in basis points that is deposited into the InsuranceFund.
+    pub insurance_slash_bps: u16,
+
+    /// Share of operator commissions in basis points that is deposited into the InsuranceFund.
+    pub insurance_commission_bps: u16,
+
+    /// Reward token commissions set aside for the InsuranceFund, held in the global token reward
+    /// vault until deposited.
+    pub pending_insurance_rewards: u64,
+
+    /// USDC commissions set aside for the InsuranceFund, held in the global USDC earnings vault
+    /// until deposited.
+    pub pending_insurance_usdc: u64,
+
+    /// Program admin proposed by the current program admin, pending acceptance by the new key.
+    pub pending_program_admin: Option<Pubkey>,
+
+    /// Delay in seconds before a queued PendingConfigChange can be executed. When set, settings
+    /// other than the emergency halts can only be changed through a PendingConfigChange.
+    pub config_change_timelock_seconds: u64,
+
+    /// Number of PendingConfigChanges queued. Used as the id of the next PendingConfigChange.
+    pub config_change_count: u64,
+
+    /// Min. number of reward distribution authority approvals on a PendingAction before
+    /// `create_reward_record` can be executed. A PendingAction is not required if 1 or less.
+    pub reward_distribution_approval_threshold: u8,
+
+    /// Min. number of halt authority approvals on a PendingAction before `set_halt_status` can be
+    /// executed. A PendingAction is not required if 1 or less.
+    pub halt_approval_threshold: u8,
+
+    /// Number of PendingActions created. Used as the id of the next PendingAction.
+    pub pending_action_count: u64,
+
+    /// List of signers authorized to mark epochs as finalizing. While empty, the reward
+    /// distribution authorities are authorized instead.
+    #[max_len(BASE_AUTHORITIES)]
+    pub epoch_authorities: Vec<Pubkey>,
+
+    /// Bitmask of paused instructions, indexed by PausableInstruction.
+    pub paused_instructions: u64,
+
+    /// Timestamp after which the pause of each instruction lapses, indexed by
+    /// PausableInstruction. Zero if the pause has no expiry.
+    pub instruction_pause_expires_at: [i64; 64],
+
+    /// Timestamp at which the program entered wind-down. Once set, wind-down cannot be reverted.
+    pub wind_down_at: Option<i64>,
+
+    /// Last epoch that rewards are distributed for during wind-down.
+    pub wind_down_final_epoch: Option<u64>,
+
+    /// Whether the AdminAuditLog has been created. Once created, admin and authority actions
+    /// must be recorded in it.
+    pub is_admin_audit_log_enabled: bool,
 }
 
 impl PoolOverview {
@@ -87,6 +246,359 @@ impl PoolOverview {
     /// PDA seed for global USDC earnings vault.
     pub const GLOBAL_USDC_EARNINGS_VAULT_SEED: &'static [u8] = b"GlobalUsdcEarningsVault";
 
+    /// PDA seed for global reward vault of an additional reward mint.
+    pub const GLOBAL_REWARD_MINT_VAULT_SEED: &'static [u8] = b"GlobalRewardMintVault";
+
     /// Reserved padding space for future upgrades.
     pub const PADDING: usize = 1024;
 }
+
+impl PoolOverview {
+    /// Returns the registry index of an additional reward mint, if registered.
+    pub fn reward_mint_index(&self, mint: &Pubkey) -> Option<usize> {
+        self.reward_mints.iter().position(|r| r.mint == *mint)
+    }
+
+    /// Returns the InsuranceFund share of an amount of operator commission.
+    pub fn calc_insurance_commission(&self, commission: u64) -> u64 {
+        u64::try_from(
+            u128::from(commission)
+                .checked_mul(self.insurance_commission_bps.into())
+                .unwrap()
+                .checked_div(10_000)
+                .unwrap(),
+        )
+        .unwrap()
+    }
+
+    /// Updates the registration fee payout wallet and slashing destinations that are set.
+    pub fn update_destinations(
+        &mut self,
+        registration_fee_payout_wallet: Option<Pubkey>,
+        slashing_destination_usdc_account: Option<Pubkey>,
+        slashing_destination_token_account: Option<Pubkey>,
+    ) {
+        if let Some(registration_fee_payout_wallet) = registration_fee_payout_wallet {
+            self.registration_fee_payout_wallet = registration_fee_payout_wallet;
+        }
+        if let Some(slashing_destination_usdc_account) = slashing_destination_usdc_account {
+            self.slashing_destination_usdc_account = slashing_destination_usdc_account;
+        }
+        if let Some(slashing_destination_token_account) = slashing_destination_token_account {
+            self.slashing_destination_token_account = slashing_destination_token_account;
+        }
+    }
+
+    /// Returns a snapshot of the settings managed by the program admin.
+    pub fn config(&self) -> PoolOverviewConfig {
+        PoolOverviewConfig {
+            is_staking_halted: self.is_staking_halted,
+            is_withdrawal_halted: self.is_withdrawal_halted,
+            is_accrue_reward_halted: self.is_accrue_reward_halted,
+            allow_pool_creation: self.allow_pool_creation,
+            operator_pool_registration_fee: self.operator_pool_registration_fee,
+            registration_fee_payout_wallet: self.registration_fee_payout_wallet,
+            slashing_destination_usdc_account: self.slashing_destination_usdc_account,
+            slashing_destination_token_account: self.slashing_destination_token_account,
+            min_operator_token_stake: self.min_operator_token_stake,
+            delegator_unstake_delay_seconds: self.delegator_unstake_delay_seconds,
+            operator_unstake_delay_seconds: self.operator_unstake_delay_seconds,
+            slashing_delay_seconds: self.slashing_delay_seconds,
+            max_commission_rate_bps: self.max_commission_rate_bps,
+            max_commission_increase_bps: self.max_commission_increase_bps,
+            commission_increase_notice_epochs: self.commission_increase_notice_epochs,
+            commission_decrease_notice_epochs: self.commission_decrease_notice_epochs,
+            max_delegator_slash_ratio_bps: self.max_delegator_slash_ratio_bps,
+            slashing_approval_threshold: self.slashing_approval_threshold,
+            slashing_appeal_period_seconds: self.slashing_appeal_period_seconds,
+            slash_redistribution_bps: self.slash_redistribution_bps,
+            slash_burn_bps: self.slash_burn_bps,
+            insurance_slash_bps: self.insurance_slash_bps,
+            insurance_commission_bps: self.insurance_commission_bps,
+            config_change_timelock_seconds: self.config_change_timelock_seconds,
+            reward_distribution_approval_threshold: self.reward_distribution_approval_threshold,
+            halt_approval_threshold: self.halt_approval_threshold,
+        }
+    }
+
+    /// Whether an instruction is paused. A pause with an expiry lapses once the expiry has passed.
+    pub fn is_instruction_paused(
+        &self,
+        instruction: PausableInstruction,
+        current_timestamp: i64,
+    ) -> bool {
+        if self.paused_instructions & instruction.mask() == 0 {
+            return false;
+        }
+        let expires_at = self.instruction_pause_expires_at[instruction as usize];
+        expires_at == 0 || current_timestamp < expires_at
+    }
+
+    /// Checks that an instruction is not paused.
+    pub fn require_instruction_not_paused(&self, instruction: PausableInstruction) -> Result<()> {
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            !self.is_instruction_paused(instruction, current_timestamp),
+            ErrorCode::InstructionPaused
+        );
+        Ok(())
+    }
+
+    /// Whether pausing the instructions in a mask with expires_at would make an active pause of
+    /// any of them lapse earlier.
+    pub fn shortens_instruction_pause(
+        &self,
+        instructions_mask: u64,
+        expires_at: Option<i64>,
+        current_timestamp: i64,
+    ) -> bool {
+        let Some(expires_at) = expires_at else {
+            return false;
+        };
+        (0..usize::from(PausableInstruction::COUNT)).any(|bit| {
+            let mask = 1 << bit;
+            if instructions_mask & mask == 0 || self.paused_instructions & mask == 0 {
+                return false;
+            }
+            let current_expires_at = self.instruction_pause_expires_at[bit];
+            if current_expires_at == 0 {
+                return true;
+            }
+            current_timestamp < current_expires_at && expires_at < current_expires_at
+        })
+    }
+
+    /// Pauses or unpauses the instructions in a mask. Pauses lapse after expires_at if set.
+    pub fn set_instruction_pause(
+        &mut self,
+        instructions_mask: u64,
+        is_paused: bool,
+        expires_at: Option<i64>,
+    ) {
+        for bit in 0..usize::from(PausableInstruction::COUNT) {
+            if instructions_mask & (1 << bit) == 0 {
+                continue;
+            }
+            if is_paused {
+                self.instruction_pause_expires_at[bit] = expires_at.unwrap_or(0);
+            } else {
+                self.instruction_pause_expires_at[bit] = 0;
+            }
+        }
+        if is_paused {
+            self.paused_instructions |= instructions_mask;
+        } else {
+            self.paused_instructions &= !instructions_mask;
+        }
+    }
+
+    /// Records an admin action in the AdminAuditLog. The AdminAuditLog is required once created.
+    pub fn record_admin_action(
+        &self,
+        admin_audit_log: Option<&mut Account<'_, AdminAuditLog>>,
+        actor: Pubkey,
+        kind: AdminActionKind,
+        args_hash: [u8; 32],
+    ) -> Result<()> {
+        match admin_audit_log {
+            Some(admin_audit_log) => {
+                admin_audit_log.record(actor, kind, args_hash, Clock::get()?.unix_timestamp)
+            }
+            None => require!(
+                !self.is_admin_audit_log_enabled,
+                ErrorCode::AdminAuditLogRequired
+            ),
+        }
+        Ok(())
+    }
+
+    /// Whether the program has entered wind-down.
+    pub fn is_winding_down(&self) -> bool {
+        self.wind_down_at.is_some()
+    }
+
+    /// Puts the program into wind-down with a final reward epoch, which must not be before the
+    /// current epoch. The current epoch is the epoch being finalized if finalization has started,
+    /// so that its RewardRecord can still be created.
+    pub fn initiate_wind_down(&mut self, final_epoch: u64, current_timestamp: i64) -> Result<()> {
+        require!(!self.is_winding_down(), ErrorCode::ProgramWindingDown);
+
+        let current_epoch = match self.is_epoch_finalizing {
+            true => self.completed_reward_epoch.checked_add(1).unwrap(),
+            false => self.completed_reward_epoch,
+        };
+        require_gte!(
+            final_epoch,
+            current_epoch,
+            ErrorCode::InvalidWindDownFinalEpoch
+        );
+
+        self.wind_down_at = Some(current_timestamp);
+        self.wind_down_final_epoch = Some(final_epoch);
+        self.allow_pool_creation = false;
+        Ok(())
+    }
+
+    /// Returns the unstake delay in seconds for an Operator or Delegator. No delay applies
+    /// during wind-down.
+    pub fn unstake_delay_seconds(&self, is_operator: bool) -> u64 {
+        if self.is_winding_down() {
+            0
+        } else if is_operator {
+            self.operator_unstake_delay_seconds
+        } else {
+            self.delegator_unstake_delay_seconds
+        }
+    }
+
+    /// Returns the window in seconds within which pending unstakes share an unstaking cohort. The
+    /// longest unstake delay is split so that the cohorts inside the delay fit on the OperatorPool.
+    pub fn unstaking_cohort_join_window_seconds(&self) -> i64 {
+        let max_unstake_delay_seconds = self
+            .unstake_delay_seconds(true)
+            .max(self.unstake_delay_seconds(false));
+        i64::try_from(max_unstake_delay_seconds / (MAX_UNSTAKING_COHORTS as u64 - 2)).unwrap()
+    }
+
+    /// Returns the account space required to hold authority lists of the given lengths.
+    pub fn space_with_authorities(authorities_lens: &[usize]) -> usize {
+        let extra_authorities: usize = authorities_lens
+            .iter()
+            .map(|len| len.saturating_sub(BASE_AUTHORITIES))
+            .sum();
+        8 + Self::INIT_SPACE + Self::PADDING + extra_authorities * 32
+    }
+
+    /// Whether a key is authorized to mark epochs as finalizing. Falls back to the reward
+    /// distribution authorities until epoch authorities are set.
+    pub fn is_epoch_authority(&self, key: &Pubkey) -> bool {
+        if self.epoch_authorities.is_empty() {
+            self.reward_distribution_authorities.contains(key)
+        } else {
+            self.epoch_authorities.contains(key)
+        }
+    }
+
+    /// Returns the list of authorities of a role.
+    pub fn authorities(&self, role: AuthorityRole) -> &[Pubkey] {
+        match role {
+            AuthorityRole::RewardDistribution => &self.reward_distribution_authorities,
+            AuthorityRole::Halt => &self.halt_authorities,
+        }
+    }
+
+    /// Returns the number of approvals required on a PendingAction of a role.
+    pub fn approval_threshold(&self, role: AuthorityRole) -> u8 {
+        match role {
+            AuthorityRole::RewardDistribution => self.reward_distribution_approval_threshold,
+            AuthorityRole::Halt => self.halt_approval_threshold,
+        }
+    }
+
+    /// Whether actions of a role must be approved in a PendingAction.
+    pub fn requires_pending_action(&self, role: AuthorityRole) -> bool {
+        self.approval_threshold(role) > 1
+    }
+
+    /// Check that approval thresholds can be met by the current authorities of each role.
+    pub fn validate_approval_thresholds(&self) -> Result<()> {
+        require_gte!(
+            self.slashing_authorities.len(),
+            usize::from(self.slashing_approval_threshold),
+            ErrorCode::InvalidSlashingApprovalThreshold
+        );
+        for role in [AuthorityRole::RewardDistribution, AuthorityRole::Halt] {
+            require_gte!(
+                self.authorities(role).len(),
+                usize::from(self.approval_threshold(role)),
+                ErrorCode::InvalidApprovalThreshold
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the number of approvals required to execute a SlashProposal.
+    pub fn required_slashing_approvals(&self) -> u8 {
+        self.slashing_approval_threshold.max(1)
+    }
+
+    /// Check that commission rate is valid and does not exceed the program-wide maximum.
+    pub fn validate_commission_rate(&self, commission_rate_bps: u16) -> Result<()> {
+        OperatorPool::validate_commission_rate(commission_rate_bps)?;
+        if let Some(max_commission_rate_bps) = self.max_commission_rate_bps {
+            require_gte!(
+                max_commission_rate_bps,
+                commission_rate_bps,
+                ErrorCode::CommissionRateAboveMax
+            );
+        }
+        Ok(())
+    }
+
+    /// Check that a commission rate schedule is ordered and within the program-wide guardrails.
+    /// Entries carried over unchanged from the existing schedule keep the notice they were
+    /// announced with, so they are exempt from the notice period check.
+    pub fn validate_commission_rate_schedule(
+        &self,
+        current_rate_bps: u16,
+        existing_schedule: &[ScheduledCommissionRate],
+        new_schedule: &[ScheduledCommissionRate],
+    ) -> Result<()> {
+        require_gte!(
+            MAX_SCHEDULED_COMMISSION_RATES,
+            new_schedule.len(),
+            ErrorCode::CommissionScheduleTooLong
+        );
+
+        // The current epoch is the epoch after the last completed reward epoch. Rates always
+        // apply from the next epoch at the earliest, so the current epoch's rewards are
+        // unaffected by the change.
+        let current_epoch = self.completed_reward_epoch.checked_add(1).unwrap();
+
+        let mut previous_rate_bps = current_rate_bps;
+        let mut previous_epoch = current_epoch;
+        for entry in new_schedule {
+            require_gt!(
+                entry.effective_epoch,
+                previous_epoch,
+                ErrorCode::InvalidCommissionSchedule
+            );
+            self.validate_commission_rate(entry.rate_bps)?;
+
+            let is_increase = entry.rate_bps > previous_rate_bps;
+            if is_increase {
+                if let Some(max_commission_increase_bps) = self.max_commission_increase_bps {
+                    require_gte!(
+                        max_commission_increase_bps,
+                        entry.rate_bps - previous_rate_bps,
+                        ErrorCode::CommissionIncreaseTooLarge
+                    );
+                }
+            }
+
+            let existing_index = existing_schedule.iter().position(|e| e == entry);
+            let is_previously_announced = match existing_index {
+                Some(0) => previous_rate_bps == current_rate_bps,
+                Some(i) => previous_rate_bps == existing_schedule[i - 1].rate_bps,
+                None => false,
+            };
+            if !is_previously_announced {
+                let notice_epochs = if is_increase {
+                    self.commission_increase_notice_epochs
+                } else {
+                    self.commission_decrease_notice_epochs
+                };
+                require_gte!(
+                    entry.effective_epoch,
+                    current_epoch.checked_add(notice_epochs.max(1)).unwrap(),
+                    ErrorCode::CommissionNoticePeriodNotMet
+                );
+            }
+
+            previous_rate_bps = entry.rate_bps;
+            previous_epoch = entry.effective_epoch;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[derive(InitSpace)]
+#[account]
+pub struct ReferralRecord {
+    /// Version of the ReferralRecord account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// Wallet that referred delegators to the OperatorPool and receives the referral share of commission.
+    pub referrer: Pubkey,
+
+    /// OperatorPool that delegators were referred to.
+    pub operator_pool: Pubkey,
+
+    /// Amount of pool shares owned by StakingRecords that were referred by this referrer.
+    pub referred_shares: u64,
+
+    /// Referral reward per referred share value at last settlement.
+    pub last_settled_referral_reward_per_share: u128,
+
+    /// Referral USDC per referred share value at last settlement.
+    pub last_settled_referral_usdc_per_share: u128,
+
+    /// Accrued reward tokens available to claim.
+    pub accrued_reward_earnings: u64,
+
+    /// Accrued USDC available to claim.
+    pub accrued_usdc_earnings: u64,
+}
+
+impl ReferralRecord {
+    /// Version of the ReferralRecord account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for ReferralRecord account.
+    pub const SEED: &'static [u8] = b"ReferralRecord";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 256;
+}
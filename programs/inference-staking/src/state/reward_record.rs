@@ -1,6 +1,6 @@
 use anchor_lang::{prelude::*, solana_program::hash};
 
-use crate::error::ErrorCode;
+use crate::{error::ErrorCode, state::MAX_REWARD_MINTS};
 
 #[derive(InitSpace)]
 #[account]
@@ -24,11 +24,17 @@ pub struct RewardRecord {
 
     /// Timestamp when the epoch was finalized (when this record was created).
     pub epoch_finalized_at: i64,
+
+    /// Amount issued for this epoch for each additional reward mint, in PoolOverview registry order.
+    #[max_len(MAX_REWARD_MINTS)]
+    pub reward_mint_payouts: Vec<u64>,
 }
 
 impl RewardRecord {
     /// Version of the RewardRecord account.
-    pub const VERSION: u8 = 1;
+    /// - 1: Initial layout.
+    /// - 2: Amounts issued for additional reward mints added.
+    pub const VERSION: u8 = 2;
 
     /// PDA seed for RewardRecord account.
     pub const SEED: &'static [u8] = b"RewardRecord";
@@ -38,9 +44,32 @@ impl RewardRecord {
 }
 
 impl RewardRecord {
+    /// Upgrades the account from its stored version to the current version, one version at a
+    /// time. The account must already be reallocated to the space of the current layout.
+    /// Returns the version the account was migrated from.
+    pub fn migrate(&mut self) -> Result<u8> {
+        let from_version = self.version;
+        require_gt!(
+            Self::VERSION,
+            from_version,
+            ErrorCode::AccountVersionCurrent
+        );
+        while self.version < Self::VERSION {
+            match self.version {
+                // Version 1 records were created before any reward mint was registered, so no
+                // reward mint amounts were issued for them.
+                1 => self.reward_mint_payouts = Vec::new(),
+                _ => return err!(ErrorCode::UnsupportedAccountVersion),
+            }
+            self.version = self.version.checked_add(1).unwrap();
+        }
+        Ok(from_version)
+    }
+
     /// Verify that given pool_address and reward_amount exist in Merkle Tree by attempting to
     /// generate the known root node through iteratively hashing the leaf/computed node with its
     /// sibling node provided in the proof.
+    #[allow(clippy::too_many_arguments)]
     pub fn verify_proof(
         &self,
         merkle_index: u8,
@@ -49,12 +78,18 @@ impl RewardRecord {
         proof_path: Vec<bool>,
         reward_amount: u64,
         usdc_amount: u64,
+        reward_mint_amounts: &[u64],
     ) -> Result<()> {
         require_eq!(proof.len(), proof_path.len(), ErrorCode::InvalidProof);
 
         let root = self.merkle_roots.get(usize::from(merkle_index)).unwrap();
 
-        let leaf_data = format!("{},{},{}", pool_address, reward_amount, usdc_amount);
+        // Additional reward mint amounts are appended to the leaf in registry order, so
+        // leaves for epochs without additional reward mints keep their original format.
+        let mut leaf_data = format!("{},{},{}", pool_address, reward_amount, usdc_amount);
+        for reward_mint_amount in reward_mint_amounts {
+            leaf_data.push_str(&format!(",{}", reward_mint_amount));
+        }
 
         // Define distinct prefixes for hashing.
         const LEAF_PREFIX: &[u8] = &[0x00];
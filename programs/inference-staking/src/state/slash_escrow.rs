@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+#[derive(InitSpace)]
+#[account]
+pub struct SlashEscrow {
+    /// Version of the SlashEscrow account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// OperatorPool that was slashed.
+    pub operator_pool: Pubkey,
+
+    /// SlashProposal that was executed.
+    pub slash_proposal: Pubkey,
+
+    /// Operator StakingRecord that was slashed.
+    pub operator_staking_record: Pubkey,
+
+    /// Amount of shares slashed from the Operator's StakingRecord.
+    pub shares_slashed: u64,
+
+    /// Amount of tokens slashed from the Operator's shares.
+    pub token_amount_slashed: u64,
+
+    /// Amount of tokens slashed from the whole pool.
+    pub pool_token_amount_slashed: u64,
+
+    /// Amount of the Operator's accrued USDC earnings confiscated.
+    pub usdc_confiscated: u64,
+
+    /// Amount of reward commission confiscated.
+    pub reward_commission_confiscated: u64,
+
+    /// Amount of USDC commission confiscated.
+    pub usdc_commission_confiscated: u64,
+
+    /// Amount of slashed tokens to redistribute to the non-operator delegators of the pool on release.
+    pub redistribution_amount: u64,
+
+    /// Amount of slashed tokens to burn on release.
+    pub burn_amount: u64,
+
+    /// Amount of slashed tokens to deposit into the InsuranceFund on release.
+    pub insurance_token_amount: u64,
+
+    /// Amount of confiscated USDC to deposit into the InsuranceFund on release.
+    pub insurance_usdc_amount: u64,
+
+    /// Timestamp of the slash.
+    pub slashed_at: i64,
+
+    /// Timestamp after which the escrowed funds can be released to the slashing destinations.
+    pub release_at: i64,
+
+    /// Timestamp when the escrowed funds were released, if released.
+    pub released_at: Option<i64>,
+
+    /// Timestamp when the slash was reversed by the program admin, if reversed.
+    pub reversed_at: Option<i64>,
+
+    /// Amount of tokens slashed from the pool's pending unstakes that were still inside their delay.
+    pub unstaking_token_amount_slashed: u64,
+
+    /// Slashing authority that proposed the slash.
+    pub proposer: Pubkey,
+}
+
+impl SlashEscrow {
+    /// Version of the SlashEscrow account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for SlashEscrow account.
+    pub const SEED: &'static [u8] = b"SlashEscrow";
+
+    /// PDA seed for slash escrow token vault.
+    pub const ESCROW_TOKEN_VAULT_SEED: &'static [u8] = b"SlashEscrowTokenVault";
+
+    /// PDA seed for slash escrow USDC vault.
+    pub const ESCROW_USDC_VAULT_SEED: &'static [u8] = b"SlashEscrowUsdcVault";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 256;
+}
+
+impl SlashEscrow {
+    /// Whether the escrow has been released or reversed.
+    pub fn is_resolved(&self) -> bool {
+        self.released_at.is_some() || self.reversed_at.is_some()
+    }
+}
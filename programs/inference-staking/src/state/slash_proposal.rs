@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::ErrorCode,
+    state::{ConfiscationPolicy, MAX_APPROVALS},
+};
+
+#[derive(InitSpace)]
+#[account]
+pub struct SlashProposal {
+    /// Version of the SlashProposal account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// OperatorPool that is proposed to be slashed.
+    pub operator_pool: Pubkey,
+
+    /// Slashing authority that created the proposal.
+    pub proposer: Pubkey,
+
+    /// Hash of the off-chain evidence for the slash.
+    pub evidence_hash: [u8; 32],
+
+    /// Slash to execute once the proposal is approved.
+    pub args: SlashStakeArgs,
+
+    /// Slashing authorities that approved the proposal, including the proposer.
+    #[max_len(MAX_APPROVALS)]
+    pub approvals: Vec<Pubkey>,
+
+    /// Timestamp when the proposal was created.
+    pub created_at: i64,
+
+    /// Timestamp when the proposal was executed by `slash_stake`, if executed.
+    pub executed_at: Option<i64>,
+}
+
+impl SlashProposal {
+    /// Version of the SlashProposal account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for SlashProposal account.
+    pub const SEED: &'static [u8] = b"SlashProposal";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 256;
+}
+
+impl SlashProposal {
+    /// Counts approvals from keys that are still slashing authorities.
+    pub fn count_valid_approvals(&self, slashing_authorities: &[Pubkey]) -> u8 {
+        let count = self
+            .approvals
+            .iter()
+            .filter(|approval| slashing_authorities.contains(approval))
+            .count();
+        u8::try_from(count).unwrap()
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace)]
+pub struct SlashStakeArgs {
+    /// Amount of shares to slash from Operator's stake.
+    pub shares_amount: u64,
+
+    /// Percentage of the pool's total staked amount to slash in basis points. Shares are unchanged,
+    /// so this lowers the value of every share in the pool proportionally, including delegators.
+    pub pool_slash_bps: u16,
+
+    /// Percentage of pending unstakes to slash in basis points. Slashed pro-rata across all
+    /// pending unstakes that are still inside their delay, including the operator's. Unstakes that
+    /// are already claimable are not slashed.
+    pub unstaking_slash_bps: u16,
+
+    /// How much of the Operator's accrued USDC earnings to confiscate.
+    pub usdc_earnings_confiscation: ConfiscationPolicy,
+
+    /// How much of the reward commission fee vault balance to confiscate.
+    pub reward_commission_confiscation: ConfiscationPolicy,
+
+    /// How much of the USDC commission fee vault balance to confiscate.
+    pub usdc_commission_confiscation: ConfiscationPolicy,
+}
+
+impl SlashStakeArgs {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.shares_amount > 0 || self.pool_slash_bps > 0 || self.unstaking_slash_bps > 0,
+            ErrorCode::InvalidAmount
+        );
+        require_gt!(10_000, self.pool_slash_bps, ErrorCode::InvalidSlashBps);
+        require_gt!(10_000, self.unstaking_slash_bps, ErrorCode::InvalidSlashBps);
+        self.usdc_earnings_confiscation.validate()?;
+        self.reward_commission_confiscation.validate()?;
+        self.usdc_commission_confiscation.validate()?;
+        Ok(())
+    }
+}
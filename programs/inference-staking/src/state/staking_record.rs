@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{error::ErrorCode, state::MAX_REWARD_MINTS};
+
 #[derive(InitSpace)]
 #[account]
 pub struct StakingRecord {
@@ -26,10 +28,29 @@ pub struct StakingRecord {
 
     /// Accrued USDC rewards available to claim
     pub accrued_usdc_earnings: u64,
+
+    /// Reward per share value at last settlement for each additional reward mint.
+    pub last_settled_reward_mint_per_share: [u128; MAX_REWARD_MINTS],
+
+    /// Accrued rewards available to claim for each additional reward mint.
+    pub accrued_reward_mint_earnings: [u64; MAX_REWARD_MINTS],
+
+    /// Wallet that referred this StakingRecord to the OperatorPool, if any. Shares owned by this
+    /// StakingRecord count towards the referrer's ReferralRecord.
+    pub referrer: Option<Pubkey>,
+
+    /// Number of unstaking slashes of the OperatorPool at last settlement of tokens_unstake_amount.
+    pub unstaking_slash_checkpoint: u64,
+
+    /// Timestamp from which tokens_unstake_amount is no longer slashable, as set by its unstaking
+    /// cohort on the OperatorPool.
+    pub unstaking_cohort_at: i64,
 }
 
 impl StakingRecord {
     /// Version of the StakingRecord account.
+    /// - 0: Operator StakingRecords created without version or reserved padding.
+    /// - 1: Initial layout.
     pub const VERSION: u8 = 1;
 
     /// PDA seed for StakingRecord account.
@@ -38,3 +59,66 @@ impl StakingRecord {
     /// Reserved padding space for future upgrades.
     pub const PADDING: usize = 256;
 }
+
+impl StakingRecord {
+    /// Upgrades the account from its stored version to the current version, one version at a
+    /// time. The account must already be reallocated to the space of the current layout.
+    /// Returns the version the account was migrated from.
+    pub fn migrate(&mut self) -> Result<u8> {
+        let from_version = self.version;
+        require_gt!(
+            Self::VERSION,
+            from_version,
+            ErrorCode::AccountVersionCurrent
+        );
+        while self.version < Self::VERSION {
+            match self.version {
+                // Version 1 only adds the reserved padding space.
+                0 => {}
+                _ => return err!(ErrorCode::UnsupportedAccountVersion),
+            }
+            self.version = self.version.checked_add(1).unwrap();
+        }
+        Ok(from_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_v0_staking_record() {
+        // Space of StakingRecords created with the version 0 layout, without reserved padding.
+        const V0_SPACE: usize = 8 + 113;
+
+        let zeroed = vec![0; StakingRecord::INIT_SPACE];
+        let mut staking_record = StakingRecord::deserialize(&mut zeroed.as_slice()).unwrap();
+        staking_record.version = 0;
+        staking_record.owner = Pubkey::new_unique();
+        staking_record.shares = 1_000;
+        staking_record.tokens_unstake_amount = 200;
+        staking_record.accrued_usdc_earnings = 30;
+
+        // Fields added after version 0 are zero, so this is the data of a version 0 account.
+        let mut data = vec![0; 8 + StakingRecord::INIT_SPACE + StakingRecord::PADDING];
+        staking_record
+            .try_serialize(&mut data.as_mut_slice())
+            .unwrap();
+        data.truncate(V0_SPACE);
+
+        // The version 0 space cannot hold the current fields, so the account must be reallocated
+        // before it is deserialized.
+        assert!(StakingRecord::try_deserialize(&mut data.as_slice()).is_err());
+        data.resize(8 + StakingRecord::INIT_SPACE + StakingRecord::PADDING, 0);
+
+        let mut staking_record = StakingRecord::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(staking_record.migrate().unwrap(), 0);
+        assert_eq!(staking_record.version, StakingRecord::VERSION);
+        assert_eq!(staking_record.shares, 1_000);
+        assert_eq!(staking_record.tokens_unstake_amount, 200);
+        assert_eq!(staking_record.accrued_usdc_earnings, 30);
+        assert_eq!(staking_record.referrer, None);
+        assert!(staking_record.migrate().is_err());
+    }
+}
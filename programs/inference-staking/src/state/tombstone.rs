@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+#[derive(InitSpace)]
+#[account]
+pub struct Tombstone {
+    /// Version of the Tombstone account.
+    pub version: u8,
+
+    /// PDA Bump
+    pub bump: u8,
+
+    /// Operator admin key or auth key that is banned.
+    pub key: Pubkey,
+
+    /// OperatorPool the key belonged to when it was tombstoned.
+    pub operator_pool: Pubkey,
+
+    /// Slashing authority that tombstoned the key.
+    pub authority: Pubkey,
+
+    /// Approved SlashProposal of the OperatorPool that the tombstone is based on.
+    pub slash_proposal: Pubkey,
+
+    /// Timestamp when the key was tombstoned.
+    pub tombstoned_at: i64,
+}
+
+impl Tombstone {
+    /// Version of the Tombstone account.
+    pub const VERSION: u8 = 1;
+
+    /// PDA seed for Tombstone account.
+    pub const SEED: &'static [u8] = b"Tombstone";
+
+    /// Reserved padding space for future upgrades.
+    pub const PADDING: usize = 64;
+}
+
+impl Tombstone {
+    /// Check that none of the keys are tombstoned. The Tombstone PDA of each key must be passed in
+    /// the same order as the keys, and must not have been created.
+    pub fn require_not_tombstoned(
+        keys: &[Pubkey],
+        tombstone_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        require_gte!(
+            tombstone_accounts.len(),
+            keys.len(),
+            ErrorCode::InvalidTombstoneAccount
+        );
+        for (key, tombstone_info) in keys.iter().zip(tombstone_accounts) {
+            let (expected_tombstone, _) =
+                Pubkey::find_program_address(&[Tombstone::SEED, key.as_ref()], &crate::ID);
+            require_keys_eq!(
+                tombstone_info.key(),
+                expected_tombstone,
+                ErrorCode::InvalidTombstoneAccount
+            );
+            require!(tombstone_info.data_is_empty(), ErrorCode::KeyTombstoned);
+        }
+        Ok(())
+    }
+}
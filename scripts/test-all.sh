@@ -11,12 +11,26 @@ PATTERN1="^test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/regist
 PATTERN2="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/inference-staking.test.ts\"$"
 PATTERN3="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/rewards.test.ts\"$"
 PATTERN4="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/constraints.test.ts\"$"
+PATTERN5="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/reward-mints.test.ts\"$"
+PATTERN6="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/commission-sharing.test.ts\"$"
+PATTERN7="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/slashing.test.ts\"$"
+PATTERN8="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/insurance.test.ts\"$"
+PATTERN9="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/halting.test.ts\"$"
+PATTERN10="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/governance.test.ts\"$"
+PATTERN11="^# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/\*\*/wind-down.test.ts\"$"
 
 # Check if the file is in the expected state
 if ! grep -q "$PATTERN1" "$FILE_PATH" || \
    ! grep -q "$PATTERN2" "$FILE_PATH" || \
    ! grep -q "$PATTERN3" "$FILE_PATH" || \
-   ! grep -q "$PATTERN4" "$FILE_PATH"; then
+   ! grep -q "$PATTERN4" "$FILE_PATH" || \
+   ! grep -q "$PATTERN5" "$FILE_PATH" || \
+   ! grep -q "$PATTERN6" "$FILE_PATH" || \
+   ! grep -q "$PATTERN7" "$FILE_PATH" || \
+   ! grep -q "$PATTERN8" "$FILE_PATH" || \
+   ! grep -q "$PATTERN9" "$FILE_PATH" || \
+   ! grep -q "$PATTERN10" "$FILE_PATH" || \
+   ! grep -q "$PATTERN11" "$FILE_PATH"; then
     echo "❌ Error: Anchor.toml is not in the expected initial state."
     echo "Please ensure the file has the following test configuration:"
     echo ""
@@ -24,6 +38,13 @@ if ! grep -q "$PATTERN1" "$FILE_PATH" || \
     echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/inference-staking.test.ts\""
     echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/rewards.test.ts\""
     echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/constraints.test.ts\""
+    echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/reward-mints.test.ts\""
+    echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/commission-sharing.test.ts\""
+    echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/slashing.test.ts\""
+    echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/insurance.test.ts\""
+    echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/halting.test.ts\""
+    echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/governance.test.ts\""
+    echo "# test = \"bun run ts-mocha -p ./tsconfig.json -r tsconfig-paths/register -t 1000000 tests/**/wind-down.test.ts\""
     echo ""
     exit 1
 fi
@@ -73,6 +94,83 @@ sed -i'.bak' \
 
 bun run test
 
+echo ""
+echo "Running reward mint tests..."
+echo ""
+
+sed -i'.bak' \
+    -e 's/^test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/constraints.test.ts"$/# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/constraints.test.ts"/' \
+    -e 's/^# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/reward-mints.test.ts"$/test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/reward-mints.test.ts"/' \
+    $FILE_PATH
+
+bun run test
+
+echo ""
+echo "Running commission sharing tests..."
+echo ""
+
+sed -i'.bak' \
+    -e 's/^test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/reward-mints.test.ts"$/# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/reward-mints.test.ts"/' \
+    -e 's/^# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/commission-sharing.test.ts"$/test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/commission-sharing.test.ts"/' \
+    $FILE_PATH
+
+bun run test
+
+echo ""
+echo "Running slashing tests..."
+echo ""
+
+sed -i'.bak' \
+    -e 's/^test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/commission-sharing.test.ts"$/# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/commission-sharing.test.ts"/' \
+    -e 's/^# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/slashing.test.ts"$/test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/slashing.test.ts"/' \
+    $FILE_PATH
+
+bun run test
+
+echo ""
+echo "Running Insurance fund tests..."
+echo ""
+
+sed -i'.bak' \
+    -e 's/^test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/slashing.test.ts"$/# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/slashing.test.ts"/' \
+    -e 's/^# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/insurance.test.ts"$/test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/insurance.test.ts"/' \
+    $FILE_PATH
+
+bun run test
+
+echo ""
+echo "Running Halt expiry and equivocation tests..."
+echo ""
+
+sed -i'.bak' \
+    -e 's/^test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/insurance.test.ts"$/# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/insurance.test.ts"/' \
+    -e 's/^# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/halting.test.ts"$/test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/halting.test.ts"/' \
+    $FILE_PATH
+
+bun run test
+
+echo ""
+echo "Running Governance tests..."
+echo ""
+
+sed -i'.bak' \
+    -e 's/^test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/halting.test.ts"$/# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/halting.test.ts"/' \
+    -e 's/^# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/governance.test.ts"$/test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/governance.test.ts"/' \
+    $FILE_PATH
+
+bun run test
+
+echo ""
+echo "Running wind-down tests..."
+echo ""
+
+sed -i'.bak' \
+    -e 's/^test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/governance.test.ts"$/# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/governance.test.ts"/' \
+    -e 's/^# test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/wind-down.test.ts"$/test = "bun run ts-mocha -p .\/tsconfig.json -r tsconfig-paths\/register -t 1000000 tests\/\*\*\/wind-down.test.ts"/' \
+    $FILE_PATH
+
+bun run test
+
 # Restore the original content
 echo "$ORIGINAL_CONTENT" > $FILE_PATH
 
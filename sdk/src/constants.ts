@@ -1,3 +1,76 @@
 import { BN } from "@coral-xyz/anchor";
 
 export const USDC_PRECISION_FACTOR = new BN("1000000000000000000");
+
+/**
+ * Prefix of the instruction data of events emitted via self-CPI, which is
+ * Anchor's event instruction tag (0x1d9acb512ea545e4) in little-endian order.
+ */
+export const EVENT_IX_TAG_LE = Buffer.from([
+  0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d,
+]);
+
+/**
+ * Instructions that can be paused individually, in order of their bit index in
+ * `PoolOverview.pausedInstructions`. Keep in sync with the program's
+ * `PausableInstruction` enum.
+ */
+export const PAUSABLE_INSTRUCTIONS = [
+  "updatePoolOverview",
+  "updatePoolOverviewAuthorities",
+  "queueConfigChange",
+  "executeConfigChange",
+  "cancelConfigChange",
+  "proposeProgramAdmin",
+  "acceptProgramAdmin",
+  "cancelProgramAdminTransfer",
+  "markEpochAsFinalizing",
+  "addRewardMint",
+  "createStakingRecord",
+  "stake",
+  "unstake",
+  "claimUnstake",
+  "cancelUnstake",
+  "closeStakingRecord",
+  "claimUsdcEarnings",
+  "claimRewardMintEarnings",
+  "createReferralRecord",
+  "claimReferralEarnings",
+  "createRewardRecord",
+  "accrueReward",
+  "accrueRewardEmergencyBypass",
+  "createOperatorPool",
+  "withdrawOperatorRewardCommission",
+  "withdrawOperatorUsdcCommission",
+  "distributeOperatorRewardCommission",
+  "distributeOperatorUsdcCommission",
+  "changeOperatorStakingRecord",
+  "changeOperatorAdmin",
+  "updateOperatorPool",
+  "closeOperatorPool",
+  "sweepClosedPoolUsdcDust",
+  "setHaltStatus",
+  "expireHalt",
+  "reportEquivocation",
+  "tombstoneOperatorKey",
+  "createSlashProposal",
+  "approveSlashProposal",
+  "slashStake",
+  "reverseSlash",
+  "releaseSlashEscrow",
+  "createPendingAction",
+  "approvePendingAction",
+  "createInsuranceFund",
+  "depositInsuranceContributions",
+  "createInsuranceClaim",
+  "approveInsuranceClaim",
+  "claimInsurance",
+  "initiateWindDown",
+  "windDownStakingRecord",
+  "migrateOperatorPool",
+  "migrateStakingRecord",
+  "createAdminAuditLog",
+  "migrateRewardRecord",
+] as const;
+
+export type PausableInstruction = (typeof PAUSABLE_INSTRUCTIONS)[number];
@@ -2,6 +2,7 @@ import type { BN } from "@coral-xyz/anchor";
 import type { PublicKey } from "@solana/web3.js";
 
 import type { IDL } from "./idl";
+import type { InstructionArgs } from "./types";
 
 /** ******************************************************************************
  *  Event Types
@@ -9,7 +10,62 @@ import type { IDL } from "./idl";
  * Keep in sync with program event structs.
  ******************************************************************************* */
 
+export type ConfiscationPolicy =
+  | { all: Record<string, never> }
+  | { percentage: { 0: number } }
+  | { none: Record<string, never> };
+
+export type AuthorityRole =
+  | { rewardDistribution: Record<string, never> }
+  | { halt: Record<string, never> };
+
+export type PoolOverviewConfig = {
+  isStakingHalted: boolean;
+  isWithdrawalHalted: boolean;
+  isAccrueRewardHalted: boolean;
+  allowPoolCreation: boolean;
+  operatorPoolRegistrationFee: BN;
+  registrationFeePayoutWallet: PublicKey;
+  slashingDestinationUsdcAccount: PublicKey;
+  slashingDestinationTokenAccount: PublicKey;
+  minOperatorTokenStake: BN;
+  delegatorUnstakeDelaySeconds: BN;
+  operatorUnstakeDelaySeconds: BN;
+  slashingDelaySeconds: BN;
+  maxCommissionRateBps: number | null;
+  maxCommissionIncreaseBps: number | null;
+  commissionIncreaseNoticeEpochs: BN;
+  commissionDecreaseNoticeEpochs: BN;
+  maxDelegatorSlashRatioBps: number | null;
+  slashingApprovalThreshold: number;
+  slashingAppealPeriodSeconds: BN;
+  slashRedistributionBps: number;
+  slashBurnBps: number;
+  insuranceSlashBps: number;
+  insuranceCommissionBps: number;
+  configChangeTimelockSeconds: BN;
+  rewardDistributionApprovalThreshold: number;
+  haltApprovalThreshold: number;
+};
+
+export type AcceptProgramAdminEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  oldProgramAdmin: PublicKey;
+  newProgramAdmin: PublicKey;
+};
+
+export type AccrueRewardEmergencyBypassEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  bypassedEpoch: BN;
+};
+
 export type AccrueRewardEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -19,9 +75,70 @@ export type AccrueRewardEventData = {
   operatorRewardCommission: BN;
   delegatorUsdcEarnings: BN;
   operatorUsdcCommission: BN;
+  rewardMintAmounts: BN[];
+  referralRewardCommission: BN;
+  referralUsdcCommission: BN;
+  insuranceRewardCommission: BN;
+  insuranceUsdcCommission: BN;
+};
+
+export type AddRewardMintEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  rewardMint: PublicKey;
+  rewardMintIndex: number;
+};
+
+export type ApproveInsuranceClaimEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  insuranceClaim: PublicKey;
+  claimId: BN;
+  tokenAmount: BN;
+  usdcAmount: BN;
+};
+
+export type ApprovePendingActionEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  pendingAction: PublicKey;
+  actionId: BN;
+  authority: PublicKey;
+  approvals: number;
+};
+
+export type ApproveSlashProposalEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  slashProposal: PublicKey;
+  authority: PublicKey;
+  approvals: number;
+};
+
+export type CancelConfigChangeEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  pendingConfigChange: PublicKey;
+  configChangeId: BN;
+};
+
+export type CancelProgramAdminTransferEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  programAdmin: PublicKey;
+  pendingProgramAdmin: PublicKey;
 };
 
 export type CancelUnstakeEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -33,6 +150,7 @@ export type CancelUnstakeEventData = {
 };
 
 export type ChangeOperatorAdminEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -41,6 +159,7 @@ export type ChangeOperatorAdminEventData = {
 };
 
 export type ChangeOperatorStakingRecordEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -49,7 +168,44 @@ export type ChangeOperatorStakingRecordEventData = {
   newStakingRecord: PublicKey;
 };
 
+export type ClaimInsuranceEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  insuranceClaim: PublicKey;
+  claimId: BN;
+  claimant: PublicKey;
+  tokenAmount: BN;
+  usdcAmount: BN;
+};
+
+export type ClaimReferralEarningsEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  referralRecord: PublicKey;
+  referrer: PublicKey;
+  rewardAmount: BN;
+  usdcAmount: BN;
+};
+
+export type ClaimRewardMintEarningsEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  stakingRecord: PublicKey;
+  owner: PublicKey;
+  isOperator: boolean;
+  rewardMint: PublicKey;
+  destination: PublicKey;
+  amount: BN;
+};
+
 export type ClaimUnstakeEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -60,6 +216,7 @@ export type ClaimUnstakeEventData = {
 };
 
 export type ClaimUsdcEarningsEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -70,7 +227,227 @@ export type ClaimUsdcEarningsEventData = {
   usdcAmount: BN;
 };
 
+export type CloseOperatorPoolEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  closedAtEpoch: BN;
+};
+
+export type CloseStakingRecordEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  stakingRecord: PublicKey;
+  owner: PublicKey;
+  receiver: PublicKey;
+};
+
+export type CreateAdminAuditLogEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  adminAuditLog: PublicKey;
+};
+
+export type CreateInsuranceClaimEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  insuranceClaim: PublicKey;
+  claimId: BN;
+  claimant: PublicKey;
+  stakingRecord: PublicKey;
+  evidenceHash: number[];
+  tokenAmount: BN;
+  usdcAmount: BN;
+};
+
+export type CreateInsuranceFundEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  insuranceFund: PublicKey;
+};
+
+export type CreateOperatorPoolEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  admin: PublicKey;
+  operatorStakingRecord: PublicKey;
+  registrationFee: BN;
+  autoStakeFees: boolean;
+  rewardCommissionRateBps: number;
+  usdcCommissionRateBps: number;
+  allowDelegation: boolean;
+  operatorAuthKeys: PublicKey[];
+};
+
+export type CreatePendingActionEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  pendingAction: PublicKey;
+  actionId: BN;
+  role: AuthorityRole;
+  actionHash: number[];
+  proposer: PublicKey;
+};
+
+export type CreatePoolOverviewEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  programAdmin: PublicKey;
+  mint: PublicKey;
+  config: PoolOverviewConfig;
+};
+
+export type CreateReferralRecordEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  referralRecord: PublicKey;
+  referrer: PublicKey;
+};
+
+export type CreateRewardRecordEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  rewardRecord: PublicKey;
+  authority: PublicKey;
+  merkleRoots: number[][];
+  totalRewards: BN;
+  totalUsdcPayout: BN;
+  rewardMintPayouts: BN[];
+  pendingAction: PublicKey | null;
+};
+
+export type CreateSlashProposalEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  slashProposal: PublicKey;
+  proposer: PublicKey;
+  evidenceHash: number[];
+};
+
+export type CreateStakingRecordEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  stakingRecord: PublicKey;
+  owner: PublicKey;
+};
+
+export type DepositInsuranceContributionsEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  tokenAmount: BN;
+  usdcAmount: BN;
+};
+
+export type DistributeOperatorRewardCommissionEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  destinations: PublicKey[];
+  amounts: BN[];
+  rewardAmountDistributed: BN;
+};
+
+export type DistributeOperatorUsdcCommissionEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  destinations: PublicKey[];
+  amounts: BN[];
+  usdcAmountDistributed: BN;
+};
+
+export type ExecuteConfigChangeEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  pendingConfigChange: PublicKey;
+  configChangeId: BN;
+  oldConfig: PoolOverviewConfig;
+  newConfig: PoolOverviewConfig;
+};
+
+export type ExpireHaltEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  haltedAt: BN;
+  reasonCode: number;
+  evidenceHash: number[];
+};
+
+export type InitiateWindDownEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  programAdmin: PublicKey;
+  finalEpoch: BN;
+  windDownAt: BN;
+};
+
+export type MarkEpochAsFinalizingEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  authority: PublicKey;
+};
+
+export type MigrateOperatorPoolEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  payer: PublicKey;
+  fromVersion: number;
+  toVersion: number;
+};
+
+export type MigrateRewardRecordEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  rewardRecord: PublicKey;
+  rewardRecordEpoch: BN;
+  payer: PublicKey;
+  fromVersion: number;
+  toVersion: number;
+};
+
+export type MigrateStakingRecordEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  stakingRecord: PublicKey;
+  owner: PublicKey;
+  payer: PublicKey;
+  fromVersion: number;
+  toVersion: number;
+};
+
 export type OperatorAutoStakeEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -81,14 +458,97 @@ export type OperatorAutoStakeEventData = {
   sharesAmount: BN;
 };
 
+export type ProposeProgramAdminEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  programAdmin: PublicKey;
+  pendingProgramAdmin: PublicKey;
+};
+
+export type QueueConfigChangeEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  pendingConfigChange: PublicKey;
+  configChangeId: BN;
+  args: InstructionArgs<"updatePoolOverview">;
+  registrationFeePayoutWallet: PublicKey | null;
+  slashingDestinationUsdcAccount: PublicKey | null;
+  slashingDestinationTokenAccount: PublicKey | null;
+  executeAt: BN;
+};
+
+export type ReleaseSlashEscrowEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  slashEscrow: PublicKey;
+  destination: PublicKey;
+  destinationUsdc: PublicKey;
+  tokenAmount: BN;
+  usdcAmount: BN;
+  redistributionAmount: BN;
+  operatorSharesRemoved: BN;
+  burnAmount: BN;
+  insuranceTokenAmount: BN;
+  insuranceUsdcAmount: BN;
+};
+
+export type ReportEquivocationEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  reporter: PublicKey;
+  authKey: PublicKey;
+  subjectId: number[];
+  firstResultHash: number[];
+  secondResultHash: number[];
+  evidenceHash: number[];
+};
+
+export type ReverseSlashEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  slashEscrow: PublicKey;
+  operatorStakingRecord: PublicKey;
+  sharesRestored: BN;
+  tokenAmountRestored: BN;
+  poolTokenAmountRestored: BN;
+  unstakingTokenAmountRestored: BN;
+  usdcRestored: BN;
+  rewardCommissionRestored: BN;
+  usdcCommissionRestored: BN;
+};
+
 export type SetHaltStatusEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
   isHalted: boolean;
+  reasonCode: number;
+  evidenceHash: number[];
+  expiresAt: BN | null;
+};
+
+export type SetInstructionPauseEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  authority: PublicKey;
+  instructionsMask: BN;
+  isPaused: boolean;
+  expiresAt: BN | null;
+  pausedInstructions: BN;
 };
 
 export type SlashStakeEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -101,9 +561,28 @@ export type SlashStakeEventData = {
   usdcConfiscated: BN;
   rewardCommissionConfiscated: BN;
   usdcCommissionConfiscated: BN;
+  poolSlashBps: number;
+  poolTokenAmountSlashed: BN;
+  unstakingSlashBps: number;
+  unstakingInDelayAtSlash: BN;
+  unstakingTokenAmountSlashed: BN;
+  operatorUnstakingSlashed: BN;
+  usdcEarningsConfiscation: ConfiscationPolicy;
+  rewardCommissionConfiscation: ConfiscationPolicy;
+  usdcCommissionConfiscation: ConfiscationPolicy;
+  slashProposal: PublicKey;
+  evidenceHash: number[];
+  slashEscrow: PublicKey;
+  releaseAt: BN;
+  redistributionAmount: BN;
+  burnAmount: BN;
+  treasuryTokenAmount: BN;
+  insuranceTokenAmount: BN;
+  insuranceUsdcAmount: BN;
 };
 
 export type StakeEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -112,9 +591,11 @@ export type StakeEventData = {
   isOperator: boolean;
   tokenAmount: BN;
   sharesAmount: BN;
+  referrer: PublicKey | null;
 };
 
 export type SweepClosedPoolUsdcDustEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -122,7 +603,20 @@ export type SweepClosedPoolUsdcDustEventData = {
   usdcAmountSwept: BN;
 };
 
+export type TombstoneOperatorKeyEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  tombstone: PublicKey;
+  key: PublicKey;
+  isAdmin: boolean;
+  authority: PublicKey;
+  slashProposal: PublicKey;
+};
+
 export type UnstakeEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -135,12 +629,50 @@ export type UnstakeEventData = {
 };
 
 export type UpdateOperatorPoolEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
 };
 
+export type UpdatePoolOverviewAuthoritiesEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  oldRewardDistributionAuthorities: PublicKey[];
+  newRewardDistributionAuthorities: PublicKey[];
+  oldHaltAuthorities: PublicKey[];
+  newHaltAuthorities: PublicKey[];
+  oldSlashingAuthorities: PublicKey[];
+  newSlashingAuthorities: PublicKey[];
+  oldEpochAuthorities: PublicKey[];
+  newEpochAuthorities: PublicKey[];
+};
+
+export type UpdatePoolOverviewEventData = {
+  version: number;
+  instructionIndex: number;
+  epoch: BN;
+  oldConfig: PoolOverviewConfig;
+  newConfig: PoolOverviewConfig;
+};
+
+export type WindDownStakingRecordEventData = {
+  version: number;
+  instructionIndex: number;
+  operatorPool: PublicKey;
+  epoch: BN;
+  stakingRecord: PublicKey;
+  owner: PublicKey;
+  isOperator: boolean;
+  sharesAmount: BN;
+  tokenAmount: BN;
+  usdcAmount: BN;
+  rewardMintAmounts: BN[];
+};
+
 export type WithdrawOperatorRewardCommissionEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -150,6 +682,7 @@ export type WithdrawOperatorRewardCommissionEventData = {
 };
 
 export type WithdrawOperatorUsdcCommissionEventData = {
+  version: number;
   instructionIndex: number;
   operatorPool: PublicKey;
   epoch: BN;
@@ -159,19 +692,62 @@ export type WithdrawOperatorUsdcCommissionEventData = {
 };
 
 export type EventDataMap = {
+  acceptProgramAdminEvent: AcceptProgramAdminEventData;
+  accrueRewardEmergencyBypassEvent: AccrueRewardEmergencyBypassEventData;
   accrueRewardEvent: AccrueRewardEventData;
+  addRewardMintEvent: AddRewardMintEventData;
+  approveInsuranceClaimEvent: ApproveInsuranceClaimEventData;
+  approvePendingActionEvent: ApprovePendingActionEventData;
+  approveSlashProposalEvent: ApproveSlashProposalEventData;
+  cancelConfigChangeEvent: CancelConfigChangeEventData;
+  cancelProgramAdminTransferEvent: CancelProgramAdminTransferEventData;
   cancelUnstakeEvent: CancelUnstakeEventData;
   changeOperatorAdminEvent: ChangeOperatorAdminEventData;
   changeOperatorStakingRecordEvent: ChangeOperatorStakingRecordEventData;
+  claimInsuranceEvent: ClaimInsuranceEventData;
+  claimReferralEarningsEvent: ClaimReferralEarningsEventData;
+  claimRewardMintEarningsEvent: ClaimRewardMintEarningsEventData;
   claimUnstakeEvent: ClaimUnstakeEventData;
   claimUsdcEarningsEvent: ClaimUsdcEarningsEventData;
+  closeOperatorPoolEvent: CloseOperatorPoolEventData;
+  closeStakingRecordEvent: CloseStakingRecordEventData;
+  createAdminAuditLogEvent: CreateAdminAuditLogEventData;
+  createInsuranceClaimEvent: CreateInsuranceClaimEventData;
+  createInsuranceFundEvent: CreateInsuranceFundEventData;
+  createOperatorPoolEvent: CreateOperatorPoolEventData;
+  createPendingActionEvent: CreatePendingActionEventData;
+  createPoolOverviewEvent: CreatePoolOverviewEventData;
+  createReferralRecordEvent: CreateReferralRecordEventData;
+  createRewardRecordEvent: CreateRewardRecordEventData;
+  createSlashProposalEvent: CreateSlashProposalEventData;
+  createStakingRecordEvent: CreateStakingRecordEventData;
+  depositInsuranceContributionsEvent: DepositInsuranceContributionsEventData;
+  distributeOperatorRewardCommissionEvent: DistributeOperatorRewardCommissionEventData;
+  distributeOperatorUsdcCommissionEvent: DistributeOperatorUsdcCommissionEventData;
+  executeConfigChangeEvent: ExecuteConfigChangeEventData;
+  expireHaltEvent: ExpireHaltEventData;
+  initiateWindDownEvent: InitiateWindDownEventData;
+  markEpochAsFinalizingEvent: MarkEpochAsFinalizingEventData;
+  migrateOperatorPoolEvent: MigrateOperatorPoolEventData;
+  migrateRewardRecordEvent: MigrateRewardRecordEventData;
+  migrateStakingRecordEvent: MigrateStakingRecordEventData;
   operatorAutoStakeEvent: OperatorAutoStakeEventData;
+  proposeProgramAdminEvent: ProposeProgramAdminEventData;
+  queueConfigChangeEvent: QueueConfigChangeEventData;
+  releaseSlashEscrowEvent: ReleaseSlashEscrowEventData;
+  reportEquivocationEvent: ReportEquivocationEventData;
+  reverseSlashEvent: ReverseSlashEventData;
   setHaltStatusEvent: SetHaltStatusEventData;
+  setInstructionPauseEvent: SetInstructionPauseEventData;
   slashStakeEvent: SlashStakeEventData;
   stakeEvent: StakeEventData;
   sweepClosedPoolUsdcDustEvent: SweepClosedPoolUsdcDustEventData;
+  tombstoneOperatorKeyEvent: TombstoneOperatorKeyEventData;
   unstakeEvent: UnstakeEventData;
   updateOperatorPoolEvent: UpdateOperatorPoolEventData;
+  updatePoolOverviewAuthoritiesEvent: UpdatePoolOverviewAuthoritiesEventData;
+  updatePoolOverviewEvent: UpdatePoolOverviewEventData;
+  windDownStakingRecordEvent: WindDownStakingRecordEventData;
   withdrawOperatorRewardCommissionEvent: WithdrawOperatorRewardCommissionEventData;
   withdrawOperatorUsdcCommissionEvent: WithdrawOperatorUsdcCommissionEventData;
 };
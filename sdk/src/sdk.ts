@@ -10,11 +10,12 @@ import { bs58 } from "@coral-xyz/anchor/dist/cjs/utils/bytes";
 import type {
   AccountInfo,
   AccountMeta,
+  CompiledInnerInstruction,
   VersionedTransaction,
 } from "@solana/web3.js";
 import { PublicKey } from "@solana/web3.js";
 
-import { USDC_PRECISION_FACTOR } from "./constants";
+import { EVENT_IX_TAG_LE, USDC_PRECISION_FACTOR } from "./constants";
 import {
   OPERATOR_POOL_DISCRIMINATOR,
   STAKING_RECORD_DISCRIMINATOR,
@@ -78,6 +79,22 @@ export class InferenceStakingProgramSdk {
     return pda;
   }
 
+  eventAuthorityPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority", "utf-8")],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  adminAuditLogPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("AdminAuditLog", "utf-8")],
+      this.program.programId
+    );
+    return pda;
+  }
+
   operatorPoolPda(operatorPoolAdmin: PublicKey): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
       [Buffer.from("OperatorPool", "utf-8"), operatorPoolAdmin.toBuffer()],
@@ -98,6 +115,29 @@ export class InferenceStakingProgramSdk {
     return pda;
   }
 
+  referralRecordPda(
+    operatorPoolPda: PublicKey,
+    referrer: PublicKey
+  ): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("ReferralRecord", "utf-8"),
+        operatorPoolPda.toBuffer(),
+        referrer.toBuffer(),
+      ],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  tombstonePda(key: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("Tombstone", "utf-8"), key.toBuffer()],
+      this.program.programId
+    );
+    return pda;
+  }
+
   rewardRecordPda(epoch: BN): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
       [
@@ -109,6 +149,70 @@ export class InferenceStakingProgramSdk {
     return pda;
   }
 
+  slashProposalPda(
+    operatorPoolPda: PublicKey,
+    evidenceHash: number[]
+  ): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("SlashProposal", "utf-8"),
+        operatorPoolPda.toBuffer(),
+        Buffer.from(evidenceHash),
+      ],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  slashEscrowPda(slashProposalPda: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("SlashEscrow", "utf-8"), slashProposalPda.toBuffer()],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  pendingConfigChangePda(configChangeId: BN): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("PendingConfigChange", "utf-8"),
+        configChangeId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  pendingActionPda(actionId: BN): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("PendingAction", "utf-8"),
+        actionId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  insuranceFundPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("InsuranceFund", "utf-8")],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  insuranceClaimPda(claimId: BN): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("InsuranceClaim", "utf-8"),
+        claimId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    );
+    return pda;
+  }
+
   /** ************************************************************************
    *  Program On-Chain Vault PDAs
    *************************************************************************** */
@@ -129,6 +233,14 @@ export class InferenceStakingProgramSdk {
     return pda;
   }
 
+  globalRewardMintVaultPda(rewardMint: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("GlobalRewardMintVault", "utf-8"), rewardMint.toBuffer()],
+      this.program.programId
+    );
+    return pda;
+  }
+
   poolStakedTokenVaultPda(operatorPoolPda: PublicKey): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
       [
@@ -173,6 +285,44 @@ export class InferenceStakingProgramSdk {
     return pda;
   }
 
+  slashEscrowTokenVaultPda(slashEscrowPda: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("SlashEscrowTokenVault", "utf-8"),
+        slashEscrowPda.toBuffer(),
+      ],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  slashEscrowUsdcVaultPda(slashEscrowPda: PublicKey): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("SlashEscrowUsdcVault", "utf-8"),
+        slashEscrowPda.toBuffer(),
+      ],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  insuranceTokenVaultPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("InsuranceTokenVault", "utf-8")],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  insuranceUsdcVaultPda(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("InsuranceUsdcVault", "utf-8")],
+      this.program.programId
+    );
+    return pda;
+  }
+
   /** ************************************************************************
    *  Account Lookup Methods
    *************************************************************************** */
@@ -441,6 +591,24 @@ export class InferenceStakingProgramSdk {
       delegatorUnstakeDelaySeconds: null,
       operatorUnstakeDelaySeconds: null,
       slashingDelaySeconds: null,
+      maxCommissionRateBps: null,
+      maxCommissionIncreaseBps: null,
+      clearMaxCommissionRateBps: false,
+      clearMaxCommissionIncreaseBps: false,
+      commissionIncreaseNoticeEpochs: null,
+      commissionDecreaseNoticeEpochs: null,
+      maxDelegatorSlashRatioBps: null,
+      clearMaxDelegatorSlashRatioBps: false,
+      slashingApprovalThreshold: null,
+      slashingAppealPeriodSeconds: null,
+      slashRedistributionBps: null,
+      slashBurnBps: null,
+      insuranceSlashBps: null,
+      insuranceCommissionBps: null,
+      configChangeTimelockSeconds: null,
+      rewardDistributionApprovalThreshold: null,
+      haltApprovalThreshold: null,
+      windDownFinalEpoch: null,
     };
     return empty;
   }
@@ -450,8 +618,8 @@ export class InferenceStakingProgramSdk {
       typeof this.program.methods.updateOperatorPool
     >[0];
     const empty: EmptyUpdateFields = {
-      newRewardCommissionRateBps: null,
-      newUsdcCommissionRateBps: null,
+      rewardCommissionRateSchedule: null,
+      usdcCommissionRateSchedule: null,
       autoStakeFees: null,
       allowDelegation: null,
       name: null,
@@ -459,10 +627,38 @@ export class InferenceStakingProgramSdk {
       websiteUrl: null,
       avatarImageUrl: null,
       operatorAuthKeys: null,
+      commissionRecipients: null,
+      referralCommissionShareBps: null,
     };
     return empty;
   }
 
+  /**
+   * Returns the Tombstone PDAs of the given keys as remaining accounts, in the
+   * same order. Required when creating an OperatorPool (admin followed by auth
+   * keys) and when updating the auth keys of an OperatorPool.
+   */
+  getTombstoneRemainingAccounts(keys: PublicKey[]): AccountMeta[] {
+    return keys.map((key) => ({
+      pubkey: this.tombstonePda(key),
+      isSigner: false,
+      isWritable: false,
+    }));
+  }
+
+  /**
+   * Returns the global vaults of the given reward mints as remaining accounts,
+   * in the same order. Required when creating a RewardRecord, with the reward
+   * mints in PoolOverview registry order.
+   */
+  getRewardMintVaultRemainingAccounts(rewardMints: PublicKey[]): AccountMeta[] {
+    return rewardMints.map((rewardMint) => ({
+      pubkey: this.globalRewardMintVaultPda(rewardMint),
+      isSigner: false,
+      isWritable: false,
+    }));
+  }
+
   /**
    * Calculates the total available USDC earnings for a staking record,
    * including both settled (accrued_usdc_earnings) and unsettled amounts.
@@ -591,6 +787,50 @@ export class InferenceStakingProgramSdk {
     return events;
   }
 
+  /**
+   * Events emitted via self-CPI are recorded as inner instructions of the
+   * program, with the instruction data prefixed by the event instruction tag.
+   */
+  private parseEventsFromInnerInstructionsV2(
+    tx: VersionedTransaction,
+    innerInstructions: CompiledInnerInstruction[]
+  ): ParsedEvent[] {
+    const accountKeys = tx.message.getAccountKeys();
+
+    const events: ParsedEvent[] = [];
+    for (const innerInstruction of innerInstructions) {
+      for (const instruction of innerInstruction.instructions) {
+        const programId = accountKeys.get(instruction.programIdIndex);
+        if (programId == null || !programId.equals(this.program.programId)) {
+          continue;
+        }
+
+        const data = Buffer.from(bs58.decode(instruction.data));
+        if (!data.subarray(0, 8).equals(EVENT_IX_TAG_LE)) {
+          continue;
+        }
+
+        try {
+          const event = this.coder.events.decode(
+            data.subarray(8).toString("base64")
+          );
+          if (event == null) {
+            continue;
+          }
+          events.push({
+            name: event.name as InferenceStakingEvents,
+            data: event.data as EventDataMap[InferenceStakingEvents],
+          });
+        } catch {
+          // Skip invalid events
+          continue;
+        }
+      }
+    }
+
+    return events;
+  }
+
   private getEventTypeV1<T extends InferenceStakingEvents>(
     events: ParsedEvent[],
     eventType: T,
@@ -717,16 +957,23 @@ export class InferenceStakingProgramSdk {
   handleDecodeTransaction({
     tx,
     logs,
+    innerInstructions = [],
     version,
   }: {
     tx: VersionedTransaction;
     logs: string[];
+    innerInstructions?: CompiledInnerInstruction[];
     version: InferenceStakingProgramVersion;
   }): InferenceStakingDecodedTransactionResult {
     switch (version) {
-      // eslint-disable-next-line @typescript-eslint/no-unnecessary-condition
-      case "v1": {
-        const events = this.parseEventsFromTransactionLogsV1(logs);
+      case "v1":
+      case "v2": {
+        // Since v2, events are emitted via self-CPI and are read from the
+        // inner instructions, as program logs can be truncated.
+        const events =
+          version === "v1"
+            ? this.parseEventsFromTransactionLogsV1(logs)
+            : this.parseEventsFromInnerInstructionsV2(tx, innerInstructions);
 
         const getEventByType = <T extends InferenceStakingEvents>(
           eventType: T,
@@ -742,7 +989,7 @@ export class InferenceStakingProgramSdk {
         };
 
         return {
-          version: "v1",
+          version,
           events,
           instructions: this.decodeTransactionV1(tx),
           getEventByType,
@@ -1,7 +1,11 @@
+import { BN } from "@coral-xyz/anchor";
 import { Keypair } from "@solana/web3.js";
 import bs58 from "bs58";
 import promiseLimit from "promise-limit";
 
+import type { PausableInstruction } from "./constants";
+import { PAUSABLE_INSTRUCTIONS } from "./constants";
+
 export function assertUnreachable(x: never): never {
   throw new Error(
     `Received a value which should not exist: ${JSON.stringify(x)}`
@@ -105,3 +109,17 @@ export function parseKeypairFromString(
     return null;
   }
 }
+
+/**
+ * Returns the bitmask of the given instructions for `setInstructionPause`.
+ */
+export function getInstructionPauseMask(
+  instructions: PausableInstruction[]
+): BN {
+  let mask = new BN(0);
+  for (const instruction of instructions) {
+    const bit = PAUSABLE_INSTRUCTIONS.indexOf(instruction);
+    mask = mask.or(new BN(1).shln(bit));
+  }
+  return mask;
+}
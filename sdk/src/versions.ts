@@ -1,10 +1,10 @@
 import type { InferenceStakingEvents, ParsedEvent } from "./events";
 import type { DecodedStakingProgramInstruction } from "./types";
 
-export type InferenceStakingProgramVersion = "v1";
+export type InferenceStakingProgramVersion = "v1" | "v2";
 
 export type InferenceStakingDecodedTransactionResult = {
-  version: "v1";
+  version: InferenceStakingProgramVersion;
   instructions: DecodedStakingProgramInstruction[];
   events: ParsedEvent[];
   getEventByInstructionIndex: (instructionIndex: number) => ParsedEvent[];
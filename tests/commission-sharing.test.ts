@@ -0,0 +1,1049 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { AccountMeta, Connection, PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
+import { assert } from "chai";
+
+import type { InferenceStaking } from "@sdk/src/idl";
+
+import type { ConstructMerkleTreeInput } from "@tests/lib/merkle";
+import { MerkleUtils } from "@tests/lib/merkle";
+import type { SetupTestResult } from "@tests/lib/setup";
+import { setupTests } from "@tests/lib/setup";
+import {
+  assertError,
+  assertStakingProgramError,
+  handleMarkEpochAsFinalizing,
+} from "@tests/lib/utils";
+
+describe("Operator commission sharing tests", () => {
+  let setup: SetupTestResult;
+  let connection: Connection;
+  let program: anchor.Program<InferenceStaking>;
+
+  const recipient1Kp = Keypair.generate();
+  const recipient2Kp = Keypair.generate();
+  const recipient1ShareBps = 3_000;
+  const recipient2ShareBps = 7_000;
+
+  let recipient1TokenAccount: PublicKey;
+  let recipient2TokenAccount: PublicKey;
+  let recipient1UsdcAccount: PublicKey;
+  let recipient2UsdcAccount: PublicKey;
+  let rewardRecipientAccounts: AccountMeta[];
+  let usdcRecipientAccounts: AccountMeta[];
+
+  const referrerKp = Keypair.generate();
+  const otherReferrerKp = Keypair.generate();
+  const referralCommissionShareBps = 5_000;
+  const operatorStake = new anchor.BN(100_000);
+  const delegatorStake = new anchor.BN(300_000);
+
+  let referralRecord: PublicKey;
+  let otherReferralRecord: PublicKey;
+  let referrerTokenAccount: PublicKey;
+  let referrerUsdcAccount: PublicKey;
+  let expectedReferralRewardEarnings: bigint;
+  let expectedReferralUsdcEarnings: bigint;
+
+  before(async () => {
+    setup = await setupTests();
+    program = setup.sdk.program;
+    connection = program.provider.connection;
+
+    await program.methods
+      .createPoolOverview()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+        newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
+        newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        allowPoolCreation: true,
+        minOperatorTokenStake: new anchor.BN(0),
+        delegatorUnstakeDelaySeconds: new anchor.BN(8),
+        operatorUnstakeDelaySeconds: new anchor.BN(20),
+        operatorPoolRegistrationFee: new anchor.BN(1_000),
+        slashingDelaySeconds: new anchor.BN(3),
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    // Create the first RewardRecord before OperatorPool 1 joins, so that the
+    // pool starts accruing rewards from epoch 2.
+    await handleMarkEpochAsFinalizing({ program, setup });
+    await program.methods
+      .createRewardRecord({
+        merkleRoots: [],
+        totalRewards: new anchor.BN(0),
+        totalUsdcPayout: new anchor.BN(0),
+        rewardMintPayouts: [],
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[1],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+
+    await program.methods
+      .createOperatorPool({
+        autoStakeFees: false,
+        rewardCommissionRateBps: 1_000,
+        usdcCommissionRateBps: 1_000,
+        allowDelegation: true,
+        name: setup.pool1.name,
+        description: setup.pool1.description,
+        websiteUrl: setup.pool1.websiteUrl,
+        avatarImageUrl: setup.pool1.avatarImageUrl,
+        operatorAuthKeys: null,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        stakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        poolOverview: setup.poolOverview,
+        mint: setup.tokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        adminTokenAccount: setup.pool1.adminTokenAccount,
+        registrationFeePayoutTokenAccount:
+          setup.registrationFeePayoutTokenAccount,
+        operatorUsdcVault: setup.pool1.poolUsdcVault,
+        usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+      )
+      .signers([setup.payerKp, setup.pool1.adminKp])
+      .rpc();
+
+    const recipientAccounts = await Promise.all(
+      [recipient1Kp, recipient2Kp].flatMap((recipientKp) =>
+        [setup.tokenMint, setup.usdcTokenMint].map((mint) =>
+          getOrCreateAssociatedTokenAccount(
+            connection,
+            setup.payerKp,
+            mint,
+            recipientKp.publicKey
+          )
+        )
+      )
+    );
+    [
+      recipient1TokenAccount,
+      recipient1UsdcAccount,
+      recipient2TokenAccount,
+      recipient2UsdcAccount,
+    ] = recipientAccounts.map((account) => account.address) as [
+      PublicKey,
+      PublicKey,
+      PublicKey,
+      PublicKey,
+    ];
+
+    const toAccountMeta = (pubkey: PublicKey): AccountMeta => ({
+      pubkey,
+      isSigner: false,
+      isWritable: true,
+    });
+    rewardRecipientAccounts = [
+      recipient1TokenAccount,
+      recipient2TokenAccount,
+    ].map(toAccountMeta);
+    usdcRecipientAccounts = [recipient1UsdcAccount, recipient2UsdcAccount].map(
+      toAccountMeta
+    );
+  });
+
+  it("Fail to set commission recipients with invalid shares", async () => {
+    for (const [commissionRecipients, errorCode] of [
+      [
+        [
+          { recipient: recipient1Kp.publicKey, shareBps: 3_000 },
+          { recipient: recipient2Kp.publicKey, shareBps: 6_000 },
+        ],
+        "invalidCommissionSplit",
+      ],
+      [
+        [
+          { recipient: recipient1Kp.publicKey, shareBps: 0 },
+          { recipient: recipient2Kp.publicKey, shareBps: 10_000 },
+        ],
+        "invalidCommissionSplit",
+      ],
+      [
+        [
+          { recipient: recipient1Kp.publicKey, shareBps: 3_000 },
+          { recipient: recipient1Kp.publicKey, shareBps: 7_000 },
+        ],
+        "duplicateCommissionRecipient",
+      ],
+    ] as const) {
+      try {
+        await program.methods
+          .updateOperatorPool({
+            ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+            commissionRecipients: [...commissionRecipients],
+          })
+          .accountsStrict({
+            admin: setup.pool1.admin,
+            operatorPool: setup.pool1.pool,
+            poolOverview: setup.poolOverview,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            eventAuthority: setup.eventAuthority,
+            program: program.programId,
+          })
+          .signers([setup.pool1.adminKp])
+          .rpc();
+        assert(false);
+      } catch (error) {
+        assertStakingProgramError(error, errorCode);
+      }
+    }
+  });
+
+  it("Fail to distribute commission without commission recipients", async () => {
+    try {
+      await program.methods
+        .distributeOperatorRewardCommission()
+        .accountsStrict({
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .remainingAccounts(rewardRecipientAccounts)
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "commissionSplitNotConfigured");
+    }
+  });
+
+  it("Operator admin sets commission recipients", async () => {
+    await program.methods
+      .updateOperatorPool({
+        ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+        commissionRecipients: [
+          { recipient: recipient1Kp.publicKey, shareBps: recipient1ShareBps },
+          { recipient: recipient2Kp.publicKey, shareBps: recipient2ShareBps },
+        ],
+      })
+      .accountsStrict({
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        poolOverview: setup.poolOverview,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.pool1.adminKp])
+      .rpc();
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.lengthOf(operatorPool.commissionRecipients, 2);
+    assert(
+      operatorPool.commissionRecipients[0]?.recipient.equals(
+        recipient1Kp.publicKey
+      )
+    );
+    assert.equal(
+      operatorPool.commissionRecipients[0]?.shareBps,
+      recipient1ShareBps
+    );
+    assert(
+      operatorPool.commissionRecipients[1]?.recipient.equals(
+        recipient2Kp.publicKey
+      )
+    );
+    assert.equal(
+      operatorPool.commissionRecipients[1]?.shareBps,
+      recipient2ShareBps
+    );
+  });
+
+  it("Fail to withdraw commission once commission is split", async () => {
+    try {
+      await program.methods
+        .withdrawOperatorRewardCommission()
+        .accountsStrict({
+          admin: setup.pool1.admin,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+          destination: setup.pool1.adminTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool1.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "commissionSplitConfigured");
+    }
+
+    try {
+      await program.methods
+        .withdrawOperatorUsdcCommission()
+        .accountsStrict({
+          admin: setup.pool1.admin,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+          destination: getAssociatedTokenAddressSync(
+            setup.usdcTokenMint,
+            setup.pool1.admin
+          ),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool1.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "commissionSplitConfigured");
+    }
+  });
+
+  it("Fail to distribute commission with missing or mismatched recipient accounts", async () => {
+    for (const remainingAccounts of [
+      rewardRecipientAccounts.slice(0, 1),
+      [...rewardRecipientAccounts].reverse(),
+      usdcRecipientAccounts,
+    ]) {
+      try {
+        await program.methods
+          .distributeOperatorRewardCommission()
+          .accountsStrict({
+            poolOverview: setup.poolOverview,
+            operatorPool: setup.pool1.pool,
+            rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            eventAuthority: setup.eventAuthority,
+            program: program.programId,
+          })
+          .remainingAccounts(remainingAccounts)
+          .rpc();
+        assert(false);
+      } catch (error) {
+        assertStakingProgramError(error, "invalidCommissionRecipientAccount");
+      }
+    }
+  });
+
+  it("Anyone can distribute reward commission across commission recipients", async () => {
+    // Fund the commission vault directly, as commission accrued from rewards
+    // is distributed the same way.
+    const commissionAmount = 10_001n;
+    await mintTo(
+      connection,
+      setup.payerKp,
+      setup.tokenMint,
+      setup.pool1.rewardCommissionFeeTokenVault,
+      setup.tokenHolderKp,
+      commissionAmount
+    );
+
+    await program.methods
+      .distributeOperatorRewardCommission()
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(rewardRecipientAccounts)
+      .rpc();
+
+    const [vault, recipient1Account, recipient2Account] = await Promise.all([
+      getAccount(connection, setup.pool1.rewardCommissionFeeTokenVault),
+      getAccount(connection, recipient1TokenAccount),
+      getAccount(connection, recipient2TokenAccount),
+    ]);
+
+    // The last recipient receives the rounding remainder.
+    const recipient1Amount =
+      (commissionAmount * BigInt(recipient1ShareBps)) / 10_000n;
+    assert.equal(vault.amount, 0n);
+    assert.equal(recipient1Account.amount, recipient1Amount);
+    assert.equal(recipient2Account.amount, commissionAmount - recipient1Amount);
+  });
+
+  it("Anyone can distribute USDC commission across commission recipients", async () => {
+    const commissionAmount = 20_003n;
+    await mintTo(
+      connection,
+      setup.payerKp,
+      setup.usdcTokenMint,
+      setup.pool1.usdcCommissionFeeTokenVault,
+      setup.tokenHolderKp,
+      commissionAmount
+    );
+
+    await program.methods
+      .distributeOperatorUsdcCommission()
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(usdcRecipientAccounts)
+      .rpc();
+
+    const [vault, recipient1Account, recipient2Account] = await Promise.all([
+      getAccount(connection, setup.pool1.usdcCommissionFeeTokenVault),
+      getAccount(connection, recipient1UsdcAccount),
+      getAccount(connection, recipient2UsdcAccount),
+    ]);
+
+    const recipient1Amount =
+      (commissionAmount * BigInt(recipient1ShareBps)) / 10_000n;
+    assert.equal(vault.amount, 0n);
+    assert.equal(recipient1Account.amount, recipient1Amount);
+    assert.equal(recipient2Account.amount, commissionAmount - recipient1Amount);
+  });
+
+  it("Fail to set a referral commission share above 100%", async () => {
+    try {
+      await program.methods
+        .updateOperatorPool({
+          ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+          referralCommissionShareBps: 10_001,
+        })
+        .accountsStrict({
+          admin: setup.pool1.admin,
+          operatorPool: setup.pool1.pool,
+          poolOverview: setup.poolOverview,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool1.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidReferralCommissionShare");
+    }
+  });
+
+  it("Operator admin sets the referral commission share", async () => {
+    await program.methods
+      .updateOperatorPool({
+        ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+        referralCommissionShareBps,
+      })
+      .accountsStrict({
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        poolOverview: setup.poolOverview,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.pool1.adminKp])
+      .rpc();
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.equal(
+      operatorPool.referralCommissionShareBps,
+      referralCommissionShareBps
+    );
+  });
+
+  it("Create ReferralRecords for referrers of OperatorPool 1", async () => {
+    referralRecord = setup.sdk.referralRecordPda(
+      setup.pool1.pool,
+      referrerKp.publicKey
+    );
+    otherReferralRecord = setup.sdk.referralRecordPda(
+      setup.pool1.pool,
+      otherReferrerKp.publicKey
+    );
+
+    for (const [referrer, record] of [
+      [referrerKp.publicKey, referralRecord],
+      [otherReferrerKp.publicKey, otherReferralRecord],
+    ] as const) {
+      await program.methods
+        .createReferralRecord()
+        .accountsStrict({
+          payer: setup.payer,
+          referrer,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          referralRecord: record,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp])
+        .rpc();
+    }
+
+    const record = await program.account.referralRecord.fetch(referralRecord);
+    assert(record.referrer.equals(referrerKp.publicKey));
+    assert(record.operatorPool.equals(setup.pool1.pool));
+    assert(record.referredShares.isZero());
+    assert(record.accruedRewardEarnings.isZero());
+    assert(record.accruedUsdcEarnings.isZero());
+
+    const [tokenAccount, usdcAccount] = await Promise.all([
+      getOrCreateAssociatedTokenAccount(
+        connection,
+        setup.payerKp,
+        setup.tokenMint,
+        referrerKp.publicKey
+      ),
+      getOrCreateAssociatedTokenAccount(
+        connection,
+        setup.payerKp,
+        setup.usdcTokenMint,
+        referrerKp.publicKey
+      ),
+    ]);
+    referrerTokenAccount = tokenAccount.address;
+    referrerUsdcAccount = usdcAccount.address;
+  });
+
+  it("Fail to create the same ReferralRecord twice", async () => {
+    try {
+      await program.methods
+        .createReferralRecord()
+        .accountsStrict({
+          payer: setup.payer,
+          referrer: referrerKp.publicKey,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          referralRecord,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertError(error, "already in use");
+    }
+  });
+
+  it("Fail to stake as the Operator with a referrer", async () => {
+    try {
+      await program.methods
+        .stake({ tokenAmount: operatorStake, referrer: referrerKp.publicKey })
+        .accountsStrict({
+          owner: setup.pool1.admin,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          ownerStakingRecord: setup.pool1.stakingRecord,
+          operatorStakingRecord: setup.pool1.stakingRecord,
+          stakedTokenAccount: setup.pool1.stakedTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          ownerTokenAccount: setup.pool1.adminTokenAccount,
+          referralRecord,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool1.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidReferrer");
+    }
+
+    await program.methods
+      .stake({ tokenAmount: operatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.pool1.admin,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.stakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: setup.pool1.adminTokenAccount,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.pool1.adminKp])
+      .rpc();
+  });
+
+  it("Fail to stake as a delegator with an invalid referrer or ReferralRecord", async () => {
+    await program.methods
+      .createStakingRecord()
+      .accountsStrict({
+        payer: setup.payer,
+        owner: setup.delegator1,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.delegator1Kp])
+      .rpc();
+
+    for (const [referrer, record, errorCode] of [
+      [setup.delegator1, referralRecord, "invalidReferrer"],
+      [referrerKp.publicKey, null, "invalidReferralRecord"],
+      [referrerKp.publicKey, otherReferralRecord, "invalidReferralRecord"],
+    ] as const) {
+      try {
+        await program.methods
+          .stake({ tokenAmount: delegatorStake, referrer })
+          .accountsStrict({
+            owner: setup.delegator1,
+            poolOverview: setup.poolOverview,
+            operatorPool: setup.pool1.pool,
+            ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+            operatorStakingRecord: setup.pool1.stakingRecord,
+            stakedTokenAccount: setup.pool1.stakedTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            ownerTokenAccount: getAssociatedTokenAddressSync(
+              setup.tokenMint,
+              setup.delegator1
+            ),
+            referralRecord: record,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            eventAuthority: setup.eventAuthority,
+            program: program.programId,
+          })
+          .signers([setup.delegator1Kp])
+          .rpc();
+        assert(false);
+      } catch (error) {
+        assertStakingProgramError(error, errorCode);
+      }
+    }
+  });
+
+  it("Delegator stakes with a referrer", async () => {
+    await program.methods
+      .stake({ tokenAmount: delegatorStake, referrer: referrerKp.publicKey })
+      .accountsStrict({
+        owner: setup.delegator1,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          setup.tokenMint,
+          setup.delegator1
+        ),
+        referralRecord,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.delegator1Kp])
+      .rpc();
+
+    const [stakingRecord, record, operatorPool] = await Promise.all([
+      program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+      program.account.referralRecord.fetch(referralRecord),
+      program.account.operatorPool.fetch(setup.pool1.pool),
+    ]);
+    assert(stakingRecord.referrer?.equals(referrerKp.publicKey));
+    assert(record.referredShares.eq(stakingRecord.shares));
+    assert(operatorPool.totalReferredShares.eq(stakingRecord.shares));
+  });
+
+  it("Fail to change the referrer of a StakingRecord", async () => {
+    try {
+      await program.methods
+        .stake({
+          tokenAmount: new anchor.BN(100),
+          referrer: otherReferrerKp.publicKey,
+        })
+        .accountsStrict({
+          owner: setup.delegator1,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+          operatorStakingRecord: setup.pool1.stakingRecord,
+          stakedTokenAccount: setup.pool1.stakedTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          ownerTokenAccount: getAssociatedTokenAddressSync(
+            setup.tokenMint,
+            setup.delegator1
+          ),
+          referralRecord: otherReferralRecord,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.delegator1Kp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "referrerAlreadySet");
+    }
+  });
+
+  it("Fail to claim referral earnings before any are accrued", async () => {
+    try {
+      await program.methods
+        .claimReferralEarnings()
+        .accountsStrict({
+          referrer: referrerKp.publicKey,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          referralRecord,
+          rewardTokenAccount: setup.rewardTokenAccount,
+          usdcTokenAccount: setup.usdcTokenAccount,
+          rewardDestination: referrerTokenAccount,
+          usdcDestination: referrerUsdcAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([referrerKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "noReferralEarningsToClaim");
+    }
+  });
+
+  it("Accruing rewards pays the referral share of commission on referred shares", async () => {
+    const rewardsInput: ConstructMerkleTreeInput[] = setup.rewardEpochs[2];
+    const merkleTree = MerkleUtils.constructMerkleTree(rewardsInput);
+    const totalRewards = rewardsInput.reduce(
+      (acc, curr) => acc + curr.tokenAmount,
+      0n
+    );
+    const totalUsdc = rewardsInput.reduce(
+      (acc, curr) => acc + curr.usdcAmount,
+      0n
+    );
+    await Promise.all([
+      mintTo(
+        connection,
+        setup.payerKp,
+        setup.tokenMint,
+        setup.rewardTokenAccount,
+        setup.tokenHolderKp,
+        totalRewards
+      ),
+      mintTo(
+        connection,
+        setup.payerKp,
+        setup.usdcTokenMint,
+        setup.usdcTokenAccount,
+        setup.tokenHolderKp,
+        totalUsdc
+      ),
+    ]);
+
+    await handleMarkEpochAsFinalizing({ program, setup });
+    await program.methods
+      .createRewardRecord({
+        merkleRoots: [Array.from(MerkleUtils.getTreeRoot(merkleTree))],
+        totalRewards: new anchor.BN(totalRewards.toString()),
+        totalUsdcPayout: new anchor.BN(totalUsdc.toString()),
+        rewardMintPayouts: [],
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[2],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+
+    const nodeIndex = rewardsInput.findIndex(
+      (x) => x.address == setup.pool1.pool.toString()
+    );
+    const proofInputs = rewardsInput[nodeIndex];
+    assert(proofInputs != null);
+    const { proof, proofPath } = MerkleUtils.generateMerkleProof({
+      ...proofInputs,
+      index: nodeIndex,
+      merkleTree,
+    });
+
+    const operatorPoolPre = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+
+    await program.methods
+      .accrueReward({
+        merkleIndex: 0,
+        proof: proof.map((arr) => Array.from(arr)),
+        proofPath,
+        rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
+        usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+        rewardMintAmounts: [],
+      })
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[2],
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+    // Mirrors OperatorPool::calc_referral_commission followed by settlement
+    // of the per referred share index.
+    const totalShares = BigInt(operatorPoolPre.totalShares.toString());
+    const referredShares = BigInt(
+      operatorPoolPre.totalReferredShares.toString()
+    );
+    const precisionFactor = 10n ** 18n;
+    const calcReferralEarnings = (amount: bigint, rateBps: number) => {
+      const commission = (amount * BigInt(rateBps)) / 10_000n;
+      const referralCommission =
+        (((commission * referredShares) / totalShares) *
+          BigInt(referralCommissionShareBps)) /
+        10_000n;
+      const perShare = (referralCommission * precisionFactor) / referredShares;
+      return (referredShares * perShare) / precisionFactor;
+    };
+    expectedReferralRewardEarnings = calcReferralEarnings(
+      proofInputs.tokenAmount,
+      operatorPoolPre.rewardCommissionRateBps
+    );
+    expectedReferralUsdcEarnings = calcReferralEarnings(
+      proofInputs.usdcAmount,
+      operatorPoolPre.usdcCommissionRateBps
+    );
+    assert(expectedReferralRewardEarnings > 0n);
+    assert(expectedReferralUsdcEarnings > 0n);
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert(operatorPool.cumulativeReferralRewardPerShare.gtn(0));
+    assert(operatorPool.cumulativeReferralUsdcPerShare.gtn(0));
+  });
+
+  it("Fail to claim referral earnings of another referrer", async () => {
+    try {
+      await program.methods
+        .claimReferralEarnings()
+        .accountsStrict({
+          referrer: otherReferrerKp.publicKey,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          referralRecord,
+          rewardTokenAccount: setup.rewardTokenAccount,
+          usdcTokenAccount: setup.usdcTokenAccount,
+          rewardDestination: referrerTokenAccount,
+          usdcDestination: referrerUsdcAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([otherReferrerKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertError(error, "ConstraintSeeds");
+    }
+  });
+
+  it("Referrer claims referral earnings", async () => {
+    const poolOverviewPre = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+
+    await program.methods
+      .claimReferralEarnings()
+      .accountsStrict({
+        referrer: referrerKp.publicKey,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        referralRecord,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        rewardDestination: referrerTokenAccount,
+        usdcDestination: referrerUsdcAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([referrerKp])
+      .rpc();
+
+    const [tokenAccount, usdcAccount, record, poolOverview] =
+      await Promise.all([
+        getAccount(connection, referrerTokenAccount),
+        getAccount(connection, referrerUsdcAccount),
+        program.account.referralRecord.fetch(referralRecord),
+        program.account.poolOverview.fetch(setup.poolOverview),
+      ]);
+    assert.equal(tokenAccount.amount, expectedReferralRewardEarnings);
+    assert.equal(usdcAccount.amount, expectedReferralUsdcEarnings);
+    assert(record.accruedRewardEarnings.isZero());
+    assert(record.accruedUsdcEarnings.isZero());
+    assert(
+      poolOverview.unclaimedRewards.eq(
+        poolOverviewPre.unclaimedRewards.sub(
+          new anchor.BN(expectedReferralRewardEarnings.toString())
+        )
+      )
+    );
+    assert(
+      poolOverview.unclaimedUsdc.eq(
+        poolOverviewPre.unclaimedUsdc.sub(
+          new anchor.BN(expectedReferralUsdcEarnings.toString())
+        )
+      )
+    );
+
+    try {
+      await program.methods
+        .claimReferralEarnings()
+        .accountsStrict({
+          referrer: referrerKp.publicKey,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          referralRecord,
+          rewardTokenAccount: setup.rewardTokenAccount,
+          usdcTokenAccount: setup.usdcTokenAccount,
+          rewardDestination: referrerTokenAccount,
+          usdcDestination: referrerUsdcAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([referrerKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "noReferralEarningsToClaim");
+    }
+  });
+});
@@ -1,13 +1,18 @@
 import * as anchor from "@coral-xyz/anchor";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 import { assert } from "chai";
 
 import type { InferenceStaking } from "@sdk/src/idl";
 
 import type { SetupTestResult } from "@tests/lib/setup";
 import { setupTests } from "@tests/lib/setup";
-import { assertStakingProgramError, assertError } from "@tests/lib/utils";
+import { assertStakingProgramError } from "@tests/lib/utils";
 
 describe("Additional tests for instruction constraints", () => {
   let setup: SetupTestResult;
@@ -24,6 +29,60 @@ describe("Additional tests for instruction constraints", () => {
   const isWithdrawalHalted = false;
   const isAccrueRewardHalted = false;
   const slashingDelaySeconds = new anchor.BN(3);
+  const epochAuthorityKp = Keypair.generate();
+
+  // PoolOverview space without the authorities that exceed BASE_AUTHORITIES.
+  let basePoolOverviewSpace = 0;
+
+  // Mirrors PoolOverview::space_with_authorities, with 5 BASE_AUTHORITIES.
+  const getExtraAuthoritiesSpace = (authoritiesLens: number[]) =>
+    authoritiesLens
+      .map((len) => Math.max(len - 5, 0) * 32)
+      .reduce((space, extraSpace) => space + extraSpace, 0);
+
+  const updateAuthorities = async (
+    newSlashingAuthorities: PublicKey[],
+    newEpochAuthorities: PublicKey[]
+  ) => {
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: null,
+        newHaltAuthorities: null,
+        newSlashingAuthorities,
+        newEpochAuthorities,
+      })
+      .accountsStrict({
+        payer: setup.signerKp.publicKey,
+        programAdmin: setup.signerKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.signerKp])
+      .rpc();
+  };
+
+  const markEpochAsFinalizing = async (authorityKp: Keypair) => {
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    await program.methods
+      .markEpochAsFinalizing({
+        expectedEpoch: poolOverview.completedRewardEpoch.addn(1),
+      })
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        authority: authorityKp.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([authorityKp])
+      .rpc();
+  };
 
   before(async () => {
     setup = await setupTests();
@@ -48,6 +107,9 @@ describe("Additional tests for instruction constraints", () => {
           slashingDestinationTokenAccount:
             setup.slashingDestinationTokenAccount,
           slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.signerKp])
         .rpc();
@@ -72,12 +134,16 @@ describe("Additional tests for instruction constraints", () => {
         registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
         slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
         slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.poolOverviewAdminKp])
       .rpc();
 
     await program.methods
       .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
         isStakingHalted,
         isWithdrawalHalted,
         isAccrueRewardHalted,
@@ -94,6 +160,10 @@ describe("Additional tests for instruction constraints", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -112,6 +182,10 @@ describe("Additional tests for instruction constraints", () => {
           registrationFeePayoutWallet: null,
           slashingDestinationTokenAccount: null,
           slashingDestinationUsdcAccount: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.haltingAuthorityKp])
         .rpc();
@@ -121,7 +195,7 @@ describe("Additional tests for instruction constraints", () => {
     }
   });
 
-  it("Updating PoolOverview new program admin requires the current and new program admin to sign", async () => {
+  it("Transferring program admin requires the new program admin to accept", async () => {
     const poolOverviewPre = await program.account.poolOverview.fetch(
       setup.poolOverview
     );
@@ -130,63 +204,81 @@ describe("Additional tests for instruction constraints", () => {
 
     try {
       await program.methods
-        .updatePoolOverviewAuthorities({
-          newRewardDistributionAuthorities: null,
-          newHaltAuthorities: null,
-          newSlashingAuthorities: null,
+        .proposeProgramAdmin({
+          newProgramAdmin: setup.signerKp.publicKey,
         })
         .accountsStrict({
-          newProgramAdmin: setup.signerKp.publicKey,
-          programAdmin: setup.poolOverviewAdmin,
+          programAdmin: setup.signerKp.publicKey,
           poolOverview: setup.poolOverview,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.poolOverviewAdminKp])
+        .signers([setup.signerKp])
         .rpc();
       assert(false);
     } catch (error) {
-      assertError(error, "Signature verification failed");
+      assertStakingProgramError(error, "invalidProgramAdmin");
     }
 
+    await program.methods
+      .proposeProgramAdmin({
+        newProgramAdmin: setup.signerKp.publicKey,
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
     try {
       await program.methods
-        .updatePoolOverviewAuthorities({
-          newRewardDistributionAuthorities: null,
-          newHaltAuthorities: null,
-          newSlashingAuthorities: null,
-        })
+        .acceptProgramAdmin()
         .accountsStrict({
-          newProgramAdmin: setup.signerKp.publicKey,
-          programAdmin: setup.poolOverviewAdmin,
+          newProgramAdmin: setup.poolOverviewAdmin,
           poolOverview: setup.poolOverview,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.signerKp])
+        .signers([setup.poolOverviewAdminKp])
         .rpc();
       assert(false);
     } catch (error) {
-      assertError(error, "Signature verification failed");
+      assertStakingProgramError(error, "invalidPendingProgramAdmin");
     }
 
-    const poolOverviewAfterFailedUpdates =
-      await program.account.poolOverview.fetch(setup.poolOverview);
+    const poolOverviewAfterProposal = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
 
     assert(
-      poolOverviewAfterFailedUpdates.programAdmin.equals(
-        setup.poolOverviewAdmin
+      poolOverviewAfterProposal.programAdmin.equals(setup.poolOverviewAdmin)
+    );
+    assert(
+      poolOverviewAfterProposal.pendingProgramAdmin?.equals(
+        setup.signerKp.publicKey
       )
     );
 
     await program.methods
-      .updatePoolOverviewAuthorities({
-        newRewardDistributionAuthorities: null,
-        newHaltAuthorities: null,
-        newSlashingAuthorities: null,
-      })
+      .acceptProgramAdmin()
       .accountsStrict({
         newProgramAdmin: setup.signerKp.publicKey,
-        programAdmin: setup.poolOverviewAdmin,
         poolOverview: setup.poolOverview,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
-      .signers([setup.poolOverviewAdminKp, setup.signerKp])
+      .signers([setup.signerKp])
       .rpc();
 
     const poolOverviewPost = await program.account.poolOverview.fetch(
@@ -194,6 +286,7 @@ describe("Additional tests for instruction constraints", () => {
     );
 
     assert(poolOverviewPost.programAdmin.equals(setup.signerKp.publicKey));
+    assert(poolOverviewPost.pendingProgramAdmin === null);
   });
 
   it("Fail to update PoolOverview authorities with invalid admin", async () => {
@@ -205,11 +298,19 @@ describe("Additional tests for instruction constraints", () => {
           ],
           newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
           newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+          newEpochAuthorities: [
+            setup.rewardDistributionAuthorityKp.publicKey,
+          ],
         })
         .accountsStrict({
-          newProgramAdmin: null,
+          payer: setup.poolOverviewAdminKp.publicKey,
           programAdmin: setup.poolOverviewAdminKp.publicKey,
           poolOverview: setup.poolOverview,
+          systemProgram: SystemProgram.programId,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.poolOverviewAdminKp])
         .rpc();
@@ -219,27 +320,28 @@ describe("Additional tests for instruction constraints", () => {
     }
   });
 
-  it("Fail to update PoolOverview authorities with more than 5 keys", async () => {
+  it("Fail to update PoolOverview authorities with more than 32 keys", async () => {
     try {
       await program.methods
         .updatePoolOverviewAuthorities({
           newRewardDistributionAuthorities: [
             setup.poolOverviewAdminKp.publicKey,
           ],
-          newHaltAuthorities: [
-            PublicKey.unique(),
-            PublicKey.unique(),
-            PublicKey.unique(),
-            PublicKey.unique(),
-            PublicKey.unique(),
-            PublicKey.unique(),
-          ],
+          newHaltAuthorities: Array.from({ length: 33 }, () =>
+            PublicKey.unique()
+          ),
           newSlashingAuthorities: [setup.poolOverviewAdminKp.publicKey],
+          newEpochAuthorities: null,
         })
         .accountsStrict({
-          newProgramAdmin: null,
+          payer: setup.signerKp.publicKey,
           programAdmin: setup.signerKp.publicKey,
           poolOverview: setup.poolOverview,
+          systemProgram: SystemProgram.programId,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.signerKp])
         .rpc();
@@ -249,6 +351,60 @@ describe("Additional tests for instruction constraints", () => {
     }
   });
 
+  it("Fail to set an approval threshold above the number of authorities", async () => {
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    try {
+      await program.methods
+        .updatePoolOverview({
+          ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+          slashingApprovalThreshold:
+            poolOverview.slashingAuthorities.length + 1,
+        })
+        .accountsStrict({
+          programAdmin: setup.signerKp.publicKey,
+          poolOverview: setup.poolOverview,
+          registrationFeePayoutWallet: null,
+          slashingDestinationTokenAccount: null,
+          slashingDestinationUsdcAccount: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.signerKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidSlashingApprovalThreshold");
+    }
+
+    try {
+      await program.methods
+        .updatePoolOverview({
+          ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+          haltApprovalThreshold: poolOverview.haltAuthorities.length + 1,
+        })
+        .accountsStrict({
+          programAdmin: setup.signerKp.publicKey,
+          poolOverview: setup.poolOverview,
+          registrationFeePayoutWallet: null,
+          slashingDestinationTokenAccount: null,
+          slashingDestinationUsdcAccount: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.signerKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidApprovalThreshold");
+    }
+  });
+
   it("Fail to create OperatorPool with invalid commission rate", async () => {
     try {
       // Expect failure as commission cannot exceed 100%.
@@ -281,7 +437,13 @@ describe("Additional tests for instruction constraints", () => {
             setup.registrationFeePayoutTokenAccount,
           operatorUsdcVault: setup.pool1.poolUsdcVault,
           usdcMint: setup.usdcTokenMint,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
+        .remainingAccounts(
+          setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+        )
         .signers([setup.payerKp, setup.pool1.adminKp])
         .rpc();
       assert(false);
@@ -307,12 +469,121 @@ describe("Additional tests for instruction constraints", () => {
         .accountsStrict({
           poolOverview: setup.poolOverview,
           authority: setup.poolOverviewAdminKp.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.poolOverviewAdminKp])
         .rpc();
       assert(false);
     } catch (error) {
-      assertStakingProgramError(error, "invalidRewardDistributionAuthority");
+      assertStakingProgramError(error, "invalidEpochAuthority");
+    }
+  });
+
+  it("Program admin grows the authority lists past the initial account space", async () => {
+    const connection = program.provider.connection;
+    const [poolOverviewPre, accountInfoPre] = await Promise.all([
+      program.account.poolOverview.fetch(setup.poolOverview),
+      connection.getAccountInfo(setup.poolOverview),
+    ]);
+    basePoolOverviewSpace =
+      (accountInfoPre?.data.length ?? 0) -
+      getExtraAuthoritiesSpace([
+        poolOverviewPre.rewardDistributionAuthorities.length,
+        poolOverviewPre.haltAuthorities.length,
+        poolOverviewPre.slashingAuthorities.length,
+        poolOverviewPre.epochAuthorities.length,
+      ]);
+
+    const newSlashingAuthorities = [
+      setup.slashingAuthorityKp.publicKey,
+      ...Array.from({ length: 6 }, () => PublicKey.unique()),
+    ];
+    const newEpochAuthorities = [
+      epochAuthorityKp.publicKey,
+      ...Array.from({ length: 5 }, () => PublicKey.unique()),
+    ];
+    await updateAuthorities(newSlashingAuthorities, newEpochAuthorities);
+
+    const [poolOverview, accountInfo] = await Promise.all([
+      program.account.poolOverview.fetch(setup.poolOverview),
+      connection.getAccountInfo(setup.poolOverview),
+    ]);
+    assert.deepEqual(
+      poolOverview.slashingAuthorities.map((key) => key.toBase58()),
+      newSlashingAuthorities.map((key) => key.toBase58())
+    );
+    assert.deepEqual(
+      poolOverview.epochAuthorities.map((key) => key.toBase58()),
+      newEpochAuthorities.map((key) => key.toBase58())
+    );
+    const expectedSpace =
+      basePoolOverviewSpace +
+      getExtraAuthoritiesSpace([
+        poolOverview.rewardDistributionAuthorities.length,
+        poolOverview.haltAuthorities.length,
+        newSlashingAuthorities.length,
+        newEpochAuthorities.length,
+      ]);
+    assert.equal(accountInfo?.data.length, expectedSpace);
+    assert.equal(
+      accountInfo?.lamports,
+      await connection.getMinimumBalanceForRentExemption(expectedSpace)
+    );
+  });
+
+  it("Fail to mark an epoch as finalizing as a reward distribution authority that is not an epoch authority", async () => {
+    try {
+      await markEpochAsFinalizing(setup.rewardDistributionAuthorityKp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidEpochAuthority");
     }
   });
+
+  it("Epoch authority marks an epoch as finalizing", async () => {
+    await markEpochAsFinalizing(epochAuthorityKp);
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.isTrue(poolOverview.isEpochFinalizing);
+  });
+
+  it("Program admin shrinks the authority lists and is refunded the rent", async () => {
+    const connection = program.provider.connection;
+    const [accountInfoPre, adminBalancePre] = await Promise.all([
+      connection.getAccountInfo(setup.poolOverview),
+      connection.getBalance(setup.signerKp.publicKey),
+    ]);
+
+    await updateAuthorities(
+      [setup.slashingAuthorityKp.publicKey],
+      [epochAuthorityKp.publicKey]
+    );
+
+    const [poolOverview, accountInfo, adminBalance] = await Promise.all([
+      program.account.poolOverview.fetch(setup.poolOverview),
+      connection.getAccountInfo(setup.poolOverview),
+      connection.getBalance(setup.signerKp.publicKey),
+    ]);
+    assert.lengthOf(poolOverview.slashingAuthorities, 1);
+    assert.lengthOf(poolOverview.epochAuthorities, 1);
+    const expectedSpace =
+      basePoolOverviewSpace +
+      getExtraAuthoritiesSpace([
+        poolOverview.rewardDistributionAuthorities.length,
+        poolOverview.haltAuthorities.length,
+      ]);
+    assert.equal(accountInfo?.data.length, expectedSpace);
+    assert.equal(
+      accountInfo?.lamports,
+      await connection.getMinimumBalanceForRentExemption(expectedSpace)
+    );
+    assert.equal(
+      adminBalance - adminBalancePre,
+      (accountInfoPre?.lamports ?? 0) - (accountInfo?.lamports ?? 0)
+    );
+  });
 });
@@ -0,0 +1,915 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
+import { assert } from "chai";
+import { createHash } from "crypto";
+
+import type { InferenceStaking } from "@sdk/src/idl";
+
+import type { SetupTestResult } from "@tests/lib/setup";
+import { setupTests } from "@tests/lib/setup";
+import {
+  assertError,
+  assertStakingProgramError,
+  decodeConfirmedTransaction,
+  handleMarkEpochAsFinalizing,
+  sleep,
+} from "@tests/lib/utils";
+
+describe("Governance tests", () => {
+  let setup: SetupTestResult;
+  let program: anchor.Program<InferenceStaking>;
+
+  const configChangeTimelockSeconds = new anchor.BN(4);
+  const newMinOperatorTokenStake = new anchor.BN(500);
+  const secondRewardDistributionAuthorityKp = Keypair.generate();
+  const rewardRecordArgs = {
+    merkleRoots: [] as number[][],
+    totalRewards: new anchor.BN(0),
+    totalUsdcPayout: new anchor.BN(0),
+    rewardMintPayouts: [] as anchor.BN[],
+  };
+
+  // Set once the AdminAuditLog is created, after which it must be passed.
+  let adminAuditLog: PublicKey | null = null;
+
+  type UpdatePoolOverviewArgs = ReturnType<
+    typeof setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction
+  >;
+
+  const updatePoolOverview = async (
+    args: Partial<UpdatePoolOverviewArgs>,
+    registrationFeePayoutWallet: PublicKey | null = null
+  ) => {
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        ...args,
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+  };
+
+  const queueConfigChange = async (
+    args: Partial<UpdatePoolOverviewArgs>,
+    registrationFeePayoutWallet: PublicKey | null = null,
+    adminKp = setup.poolOverviewAdminKp
+  ) => {
+    const { configChangeCount } = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    const pendingConfigChange =
+      setup.sdk.pendingConfigChangePda(configChangeCount);
+
+    await program.methods
+      .queueConfigChange({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        ...args,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: adminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        pendingConfigChange,
+        registrationFeePayoutWallet,
+        slashingDestinationUsdcAccount: null,
+        slashingDestinationTokenAccount: null,
+        adminAuditLog,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, adminKp])
+      .rpc();
+
+    return pendingConfigChange;
+  };
+
+  const executeConfigChange = async (
+    pendingConfigChange: PublicKey,
+    rentPayer = setup.payer
+  ) => {
+    await program.methods
+      .executeConfigChange()
+      .accountsStrict({
+        caller: setup.signer,
+        poolOverview: setup.poolOverview,
+        pendingConfigChange,
+        rentPayer,
+        adminAuditLog,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.signerKp])
+      .rpc();
+  };
+
+  const cancelConfigChange = async (
+    pendingConfigChange: PublicKey,
+    adminKp: Keypair
+  ) => {
+    await program.methods
+      .cancelConfigChange()
+      .accountsStrict({
+        programAdmin: adminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        pendingConfigChange,
+        rentPayer: setup.payer,
+        adminAuditLog,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([adminKp])
+      .rpc();
+  };
+
+  // Mirrors CreateRewardRecordArgs::action_hash.
+  const getCreateRewardRecordActionHash = (
+    args: typeof rewardRecordArgs,
+    epoch: anchor.BN
+  ) =>
+    Array.from(
+      createHash("sha256")
+        .update(
+          Buffer.concat([
+            Buffer.from("create_reward_record", "utf-8"),
+            epoch.toArrayLike(Buffer, "le", 8),
+            program.coder.types.encode("createRewardRecordArgs", args),
+          ])
+        )
+        .digest()
+    );
+
+  // Mirrors AdminAuditLog::hash_args for args that serialize to a single key.
+  const getKeyArgsHash = (key: PublicKey) =>
+    Array.from(createHash("sha256").update(key.toBuffer()).digest());
+
+  const proposeProgramAdmin = async (newProgramAdmin: PublicKey) => {
+    await program.methods
+      .proposeProgramAdmin({ newProgramAdmin })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        adminAuditLog,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+  };
+
+  const cancelProgramAdminTransfer = async (programAdminKp: Keypair) => {
+    await program.methods
+      .cancelProgramAdminTransfer()
+      .accountsStrict({
+        programAdmin: programAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        adminAuditLog,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([programAdminKp])
+      .rpc();
+  };
+
+  const acceptProgramAdmin = async (newProgramAdminKp: Keypair) => {
+    await program.methods
+      .acceptProgramAdmin()
+      .accountsStrict({
+        newProgramAdmin: newProgramAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        adminAuditLog,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([newProgramAdminKp])
+      .rpc();
+  };
+
+  const createPendingAction = async (
+    actionHash: number[],
+    authorityKp: Keypair
+  ) => {
+    const { pendingActionCount } = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    const pendingAction = setup.sdk.pendingActionPda(pendingActionCount);
+
+    await program.methods
+      .createPendingAction({
+        role: { rewardDistribution: {} },
+        actionHash,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: authorityKp.publicKey,
+        poolOverview: setup.poolOverview,
+        pendingAction,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, authorityKp])
+      .rpc();
+
+    return pendingAction;
+  };
+
+  const approvePendingAction = async (
+    pendingAction: PublicKey,
+    authorityKp: Keypair
+  ) => {
+    await program.methods
+      .approvePendingAction()
+      .accountsStrict({
+        authority: authorityKp.publicKey,
+        poolOverview: setup.poolOverview,
+        pendingAction,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([authorityKp])
+      .rpc();
+  };
+
+  const createRewardRecord = async (
+    args: typeof rewardRecordArgs,
+    pendingAction: PublicKey | null
+  ) => {
+    await program.methods
+      .createRewardRecord(args)
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[1],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+  };
+
+  before(async () => {
+    setup = await setupTests();
+    program = setup.sdk.program;
+
+    await program.methods
+      .createPoolOverview()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+        newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
+        newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await updatePoolOverview({
+      allowPoolCreation: true,
+      minOperatorTokenStake: new anchor.BN(0),
+      delegatorUnstakeDelaySeconds: new anchor.BN(8),
+      operatorUnstakeDelaySeconds: new anchor.BN(20),
+      operatorPoolRegistrationFee: new anchor.BN(1_000),
+      slashingDelaySeconds: new anchor.BN(3),
+    });
+  });
+
+  it("Program admin sets a config change timelock", async () => {
+    await updatePoolOverview({ configChangeTimelockSeconds });
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(
+      poolOverview.configChangeTimelockSeconds.eq(configChangeTimelockSeconds)
+    );
+  });
+
+  it("Fail to update settings directly once a timelock is set", async () => {
+    try {
+      await updatePoolOverview({
+        minOperatorTokenStake: newMinOperatorTokenStake,
+      });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "configChangeRequiresTimelock");
+    }
+
+    try {
+      await updatePoolOverview({}, setup.signer);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "configChangeRequiresTimelock");
+    }
+  });
+
+  it("Program admin can halt staking without the timelock", async () => {
+    await updatePoolOverview({ isStakingHalted: true });
+    let poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(poolOverview.isStakingHalted);
+
+    await updatePoolOverview({ isStakingHalted: false });
+    poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(!poolOverview.isStakingHalted);
+  });
+
+  it("Fail to queue a config change with invalid admin", async () => {
+    try {
+      await queueConfigChange(
+        { minOperatorTokenStake: newMinOperatorTokenStake },
+        null,
+        setup.pool1.adminKp
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidProgramAdmin");
+    }
+  });
+
+  it("Fail to queue an invalid config change", async () => {
+    try {
+      await queueConfigChange({
+        slashRedistributionBps: 6_000,
+        slashBurnBps: 5_000,
+      });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidSlashSplit");
+    }
+  });
+
+  it("Program admin queues a config change", async () => {
+    const pendingConfigChange = await queueConfigChange(
+      { minOperatorTokenStake: newMinOperatorTokenStake },
+      setup.signer
+    );
+
+    const [configChange, poolOverview] = await Promise.all([
+      program.account.pendingConfigChange.fetch(pendingConfigChange),
+      program.account.poolOverview.fetch(setup.poolOverview),
+    ]);
+    assert(configChange.id.isZero());
+    assert(configChange.rentPayer.equals(setup.payer));
+    assert(configChange.queuedBy.equals(setup.poolOverviewAdmin));
+    assert(
+      configChange.args.minOperatorTokenStake?.eq(newMinOperatorTokenStake)
+    );
+    assert(configChange.registrationFeePayoutWallet?.equals(setup.signer));
+    assert.isNull(configChange.slashingDestinationTokenAccount);
+    assert.isNull(configChange.slashingDestinationUsdcAccount);
+    assert(
+      configChange.executeAt.eq(
+        configChange.queuedAt.add(configChangeTimelockSeconds)
+      )
+    );
+    assert(poolOverview.configChangeCount.eqn(1));
+    assert(poolOverview.minOperatorTokenStake.isZero());
+  });
+
+  it("Fail to execute a config change before the timelock has passed", async () => {
+    try {
+      await executeConfigChange(
+        setup.sdk.pendingConfigChangePda(new anchor.BN(0))
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "configChangeNotMatured");
+    }
+  });
+
+  it("Fail to execute a config change with an invalid rent payer", async () => {
+    try {
+      await executeConfigChange(
+        setup.sdk.pendingConfigChangePda(new anchor.BN(0)),
+        setup.signer
+      );
+      assert(false);
+    } catch (error) {
+      assertError(error, "ConstraintHasOne");
+    }
+  });
+
+  it("Anyone can execute a config change once the timelock has passed", async () => {
+    await sleep((configChangeTimelockSeconds.toNumber() + 1) * 1_000);
+
+    const pendingConfigChange = setup.sdk.pendingConfigChangePda(
+      new anchor.BN(0)
+    );
+    await executeConfigChange(pendingConfigChange);
+
+    const [poolOverview, configChange] = await Promise.all([
+      program.account.poolOverview.fetch(setup.poolOverview),
+      program.account.pendingConfigChange.fetchNullable(pendingConfigChange),
+    ]);
+    assert(poolOverview.minOperatorTokenStake.eq(newMinOperatorTokenStake));
+    assert(poolOverview.registrationFeePayoutWallet.equals(setup.signer));
+    assert.isNull(configChange);
+  });
+
+  it("Fail to cancel a config change with invalid admin", async () => {
+    const pendingConfigChange = await queueConfigChange({
+      minOperatorTokenStake: new anchor.BN(0),
+    });
+
+    try {
+      await cancelConfigChange(pendingConfigChange, setup.pool1.adminKp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidProgramAdmin");
+    }
+  });
+
+  it("Program admin cancels a queued config change", async () => {
+    const pendingConfigChange = setup.sdk.pendingConfigChangePda(
+      new anchor.BN(1)
+    );
+    await cancelConfigChange(pendingConfigChange, setup.poolOverviewAdminKp);
+
+    const configChange =
+      await program.account.pendingConfigChange.fetchNullable(
+        pendingConfigChange
+      );
+    assert.isNull(configChange);
+
+    await sleep((configChangeTimelockSeconds.toNumber() + 1) * 1_000);
+    try {
+      await executeConfigChange(pendingConfigChange);
+      assert(false);
+    } catch (error) {
+      assertError(error, "AccountNotInitialized");
+    }
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(poolOverview.minOperatorTokenStake.eq(newMinOperatorTokenStake));
+  });
+
+  it("Program admin requires two reward distribution approvals", async () => {
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+          secondRewardDistributionAuthorityKp.publicKey,
+        ],
+        newHaltAuthorities: null,
+        newSlashingAuthorities: null,
+        newEpochAuthorities: null,
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    const pendingConfigChange = await queueConfigChange({
+      rewardDistributionApprovalThreshold: 2,
+    });
+    await sleep((configChangeTimelockSeconds.toNumber() + 1) * 1_000);
+    await executeConfigChange(pendingConfigChange);
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.equal(poolOverview.rewardDistributionApprovalThreshold, 2);
+  });
+
+  it("Fail to create a RewardRecord without a PendingAction", async () => {
+    await handleMarkEpochAsFinalizing({ program, setup });
+
+    try {
+      await createRewardRecord(rewardRecordArgs, null);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "pendingActionRequired");
+    }
+  });
+
+  it("Fail to create a PendingAction with invalid authority", async () => {
+    try {
+      await createPendingAction(
+        getCreateRewardRecordActionHash(rewardRecordArgs, new anchor.BN(1)),
+        setup.haltingAuthorityKp
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidActionAuthority");
+    }
+  });
+
+  it("Reward distribution authority creates a PendingAction", async () => {
+    const actionHash = getCreateRewardRecordActionHash(
+      rewardRecordArgs,
+      new anchor.BN(1)
+    );
+    const pendingAction = await createPendingAction(
+      actionHash,
+      setup.rewardDistributionAuthorityKp
+    );
+
+    const [action, poolOverview] = await Promise.all([
+      program.account.pendingAction.fetch(pendingAction),
+      program.account.poolOverview.fetch(setup.poolOverview),
+    ]);
+    assert(action.id.isZero());
+    assert.deepEqual(action.role, { rewardDistribution: {} });
+    assert.deepEqual(action.actionHash, actionHash);
+    assert(action.proposer.equals(setup.rewardDistributionAuthority));
+    assert.lengthOf(action.approvals, 1);
+    assert(action.approvals[0]?.equals(setup.rewardDistributionAuthority));
+    assert.isNull(action.executedAt);
+    assert(poolOverview.pendingActionCount.eqn(1));
+  });
+
+  it("Fail to create a RewardRecord before the PendingAction is approved", async () => {
+    try {
+      await createRewardRecord(
+        rewardRecordArgs,
+        setup.sdk.pendingActionPda(new anchor.BN(0))
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "pendingActionNotApproved");
+    }
+  });
+
+  it("Fail to approve a PendingAction with invalid or duplicate authority", async () => {
+    const pendingAction = setup.sdk.pendingActionPda(new anchor.BN(0));
+
+    try {
+      await approvePendingAction(pendingAction, setup.haltingAuthorityKp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidActionAuthority");
+    }
+
+    try {
+      await approvePendingAction(
+        pendingAction,
+        setup.rewardDistributionAuthorityKp
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "duplicateActionApproval");
+    }
+  });
+
+  it("Second reward distribution authority approves the PendingAction", async () => {
+    const pendingAction = setup.sdk.pendingActionPda(new anchor.BN(0));
+    await approvePendingAction(
+      pendingAction,
+      secondRewardDistributionAuthorityKp
+    );
+
+    const action = await program.account.pendingAction.fetch(pendingAction);
+    assert.lengthOf(action.approvals, 2);
+    assert(
+      action.approvals[1]?.equals(
+        secondRewardDistributionAuthorityKp.publicKey
+      )
+    );
+  });
+
+  it("Fail to create a RewardRecord that does not match the PendingAction", async () => {
+    try {
+      await createRewardRecord(
+        { ...rewardRecordArgs, merkleRoots: [Array(32).fill(1)] },
+        setup.sdk.pendingActionPda(new anchor.BN(0))
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "pendingActionMismatch");
+    }
+  });
+
+  it("Create a RewardRecord with an approved PendingAction", async () => {
+    const pendingAction = setup.sdk.pendingActionPda(new anchor.BN(0));
+    await createRewardRecord(rewardRecordArgs, pendingAction);
+
+    const [action, rewardRecord] = await Promise.all([
+      program.account.pendingAction.fetch(pendingAction),
+      program.account.rewardRecord.fetch(setup.rewardRecords[1]),
+    ]);
+    assert.isNotNull(action.executedAt);
+    assert(rewardRecord.epoch.eqn(1));
+  });
+
+  it("Fail to approve an executed PendingAction", async () => {
+    try {
+      await approvePendingAction(
+        setup.sdk.pendingActionPda(new anchor.BN(0)),
+        secondRewardDistributionAuthorityKp
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "pendingActionAlreadyExecuted");
+    }
+  });
+
+  it("Fail to accept the program admin role with a signer that is not the pending admin", async () => {
+    const pendingProgramAdminKp = Keypair.generate();
+    await proposeProgramAdmin(pendingProgramAdminKp.publicKey);
+
+    try {
+      await acceptProgramAdmin(Keypair.generate());
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidPendingProgramAdmin");
+    }
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(poolOverview.programAdmin.equals(setup.poolOverviewAdmin));
+    assert(
+      poolOverview.pendingProgramAdmin?.equals(pendingProgramAdminKp.publicKey)
+    );
+  });
+
+  it("Fail to cancel a program admin transfer with invalid admin", async () => {
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    const pendingProgramAdmin = poolOverview.pendingProgramAdmin;
+    assert(pendingProgramAdmin != null);
+
+    try {
+      await cancelProgramAdminTransfer(Keypair.generate());
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidProgramAdmin");
+    }
+
+    const poolOverviewPost = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(poolOverviewPost.pendingProgramAdmin?.equals(pendingProgramAdmin));
+  });
+
+  it("Program admin cancels a pending program admin transfer", async () => {
+    const pendingProgramAdminKp = Keypair.generate();
+    await proposeProgramAdmin(pendingProgramAdminKp.publicKey);
+    await cancelProgramAdminTransfer(setup.poolOverviewAdminKp);
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(poolOverview.programAdmin.equals(setup.poolOverviewAdmin));
+    assert.isNull(poolOverview.pendingProgramAdmin);
+
+    // The cancelled pending admin can no longer accept the role.
+    try {
+      await acceptProgramAdmin(pendingProgramAdminKp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidPendingProgramAdmin");
+    }
+  });
+
+  it("Decode self-CPI events with their version and instruction index", async () => {
+    const pendingProgramAdmin = Keypair.generate().publicKey;
+    const proposeInstruction = await program.methods
+      .proposeProgramAdmin({ newProgramAdmin: pendingProgramAdmin })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        adminAuditLog,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .instruction();
+
+    // Propose and cancel the transfer as the second and third instructions, so
+    // each event must report the index of the instruction that emitted it.
+    const signature = await program.methods
+      .cancelProgramAdminTransfer()
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        adminAuditLog,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 }),
+        proposeInstruction,
+      ])
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    const { events, getEventByType, getEventByInstructionIndex } =
+      await decodeConfirmedTransaction(setup, signature);
+    assert.lengthOf(events, 2);
+    assert.isEmpty(getEventByInstructionIndex(0));
+
+    const proposed = getEventByType("proposeProgramAdminEvent", 1);
+    assert(proposed != null, "ProposeProgramAdminEvent must be emitted");
+    assert.equal(proposed.data.version, 1);
+    assert.equal(proposed.data.instructionIndex, 1);
+    assert(proposed.data.programAdmin.equals(setup.poolOverviewAdmin));
+    assert(proposed.data.pendingProgramAdmin.equals(pendingProgramAdmin));
+
+    const cancelled = getEventByType("cancelProgramAdminTransferEvent", 2);
+    assert(
+      cancelled != null,
+      "CancelProgramAdminTransferEvent must be emitted"
+    );
+    assert.equal(cancelled.data.version, 1);
+    assert.equal(cancelled.data.instructionIndex, 2);
+    assert(cancelled.data.programAdmin.equals(setup.poolOverviewAdmin));
+    assert(cancelled.data.pendingProgramAdmin.equals(pendingProgramAdmin));
+    assert(cancelled.data.epoch.eq(proposed.data.epoch));
+  });
+
+  it("Fail to cancel a program admin transfer when none is pending", async () => {
+    try {
+      await cancelProgramAdminTransfer(setup.poolOverviewAdminKp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "noPendingProgramAdmin");
+    }
+  });
+
+  it("Program admin creates the AdminAuditLog", async () => {
+    await program.methods
+      .createAdminAuditLog()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        adminAuditLog: setup.sdk.adminAuditLogPda(),
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    const [log, poolOverview] = await Promise.all([
+      program.account.adminAuditLog.fetch(setup.sdk.adminAuditLogPda()),
+      program.account.poolOverview.fetch(setup.poolOverview),
+    ]);
+    assert(log.totalEntries.isZero());
+    assert.isEmpty(log.entries);
+    assert.isTrue(poolOverview.isAdminAuditLogEnabled);
+  });
+
+  it("Fail to skip the AdminAuditLog once it is created", async () => {
+    try {
+      await proposeProgramAdmin(Keypair.generate().publicKey);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "adminAuditLogRequired");
+    }
+  });
+
+  it("Record the program admin that queued an executed config change", async () => {
+    adminAuditLog = setup.sdk.adminAuditLogPda();
+
+    const pendingConfigChange = await queueConfigChange({
+      minOperatorTokenStake: new anchor.BN(1_000),
+    });
+    await sleep((configChangeTimelockSeconds.toNumber() + 1) * 1_000);
+    await executeConfigChange(pendingConfigChange);
+
+    const log = await program.account.adminAuditLog.fetch(adminAuditLog);
+    assert(log.totalEntries.eqn(2));
+    const [queued, executed] = log.entries;
+    assert.deepEqual(queued?.kind, { queueConfigChange: {} });
+    assert(queued?.actor.equals(setup.poolOverviewAdmin));
+    // The permissionless execution is recorded with the program admin that
+    // queued the change rather than the signer that executed it.
+    assert.deepEqual(executed?.kind, { executeConfigChange: {} });
+    assert(executed?.actor.equals(setup.poolOverviewAdmin));
+    assert.deepEqual(executed?.argsHash, queued?.argsHash);
+  });
+
+  it("Record a cancelled program admin transfer", async () => {
+    const newProgramAdmin = Keypair.generate().publicKey;
+    await proposeProgramAdmin(newProgramAdmin);
+    await cancelProgramAdminTransfer(setup.poolOverviewAdminKp);
+
+    const log = await program.account.adminAuditLog.fetch(
+      setup.sdk.adminAuditLogPda()
+    );
+    assert(log.totalEntries.eqn(4));
+    const [proposed, cancelled] = log.entries.slice(2);
+    assert.deepEqual(proposed?.kind, { proposeProgramAdmin: {} });
+    assert(proposed?.actor.equals(setup.poolOverviewAdmin));
+    assert.deepEqual(proposed?.argsHash, getKeyArgsHash(newProgramAdmin));
+    assert.deepEqual(cancelled?.kind, { cancelProgramAdminTransfer: {} });
+    assert(cancelled?.actor.equals(setup.poolOverviewAdmin));
+    assert.deepEqual(cancelled?.argsHash, getKeyArgsHash(newProgramAdmin));
+  });
+
+  it("Overwrite the oldest entries once the AdminAuditLog is full", async () => {
+    // Fill the 28 remaining entries and overwrite the two oldest.
+    const newProgramAdmins = Array.from(
+      { length: 30 },
+      () => Keypair.generate().publicKey
+    );
+    for (const newProgramAdmin of newProgramAdmins) {
+      await proposeProgramAdmin(newProgramAdmin);
+    }
+
+    const log = await program.account.adminAuditLog.fetch(
+      setup.sdk.adminAuditLogPda()
+    );
+    assert(log.totalEntries.eqn(34));
+    assert.lengthOf(log.entries, 32);
+
+    // The two newest entries replaced the queued and executed config change.
+    const newProgramAdminHashes = newProgramAdmins.map(getKeyArgsHash);
+    assert.deepEqual(log.entries[0]?.argsHash, newProgramAdminHashes[28]);
+    assert.deepEqual(log.entries[1]?.argsHash, newProgramAdminHashes[29]);
+    assert.deepEqual(log.entries[2]?.kind, { proposeProgramAdmin: {} });
+    assert.deepEqual(log.entries[3]?.kind, { cancelProgramAdminTransfer: {} });
+    for (let i = 4; i < 32; i++) {
+      assert.deepEqual(log.entries[i]?.argsHash, newProgramAdminHashes[i - 4]);
+    }
+    assert(
+      log.entries.every((entry) => entry.actor.equals(setup.poolOverviewAdmin))
+    );
+  });
+});
@@ -0,0 +1,530 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
+import { assert } from "chai";
+import { createHash } from "crypto";
+
+import type { InferenceStaking } from "@sdk/src/idl";
+
+import type { SetupTestResult } from "@tests/lib/setup";
+import { setupTests } from "@tests/lib/setup";
+import { assertStakingProgramError, sleep } from "@tests/lib/utils";
+
+describe("Halt expiry and equivocation tests", () => {
+  let setup: SetupTestResult;
+  let program: anchor.Program<InferenceStaking>;
+
+  const slashingDelaySeconds = new anchor.BN(3);
+  const haltDurationSeconds = 4;
+  const operatorStake = new anchor.BN(100_000);
+  const haltEvidenceHash = Array(32).fill(1);
+  const slashEvidenceHash = Array(32).fill(2);
+
+  const authKeyKp = Keypair.generate();
+  const otherKeyKp = Keypair.generate();
+  const equivocationReasonCode = 255;
+
+  const setHaltStatus = async (
+    isHalted: boolean,
+    expiresAt: anchor.BN | null
+  ) => {
+    await program.methods
+      .setHaltStatus({
+        isHalted,
+        reasonCode: 1,
+        evidenceHash: haltEvidenceHash,
+        expiresAt,
+      })
+      .accountsStrict({
+        authority: setup.haltingAuthorityKp.publicKey,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.haltingAuthorityKp])
+      .rpc();
+  };
+
+  const expireHalt = async () => {
+    await program.methods
+      .expireHalt()
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
+
+  const getAttestation = ({
+    operatorPool = setup.pool1.pool,
+    subjectId,
+    resultHash,
+  }: {
+    operatorPool?: PublicKey;
+    subjectId: number;
+    resultHash: number;
+  }) =>
+    Buffer.concat([
+      operatorPool.toBuffer(),
+      Buffer.alloc(32, subjectId),
+      Buffer.alloc(32, resultHash),
+    ]);
+
+  const reportEquivocation = async (
+    [firstSignerKp, firstAttestation]: [Keypair, Buffer],
+    [secondSignerKp, secondAttestation]: [Keypair, Buffer],
+    firstInstructionIndex = 0
+  ) => {
+    await program.methods
+      .reportEquivocation({ firstInstructionIndex, secondInstructionIndex: 1 })
+      .accountsStrict({
+        reporter: setup.signer,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .preInstructions([
+        Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: firstSignerKp.secretKey,
+          message: firstAttestation,
+        }),
+        Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: secondSignerKp.secretKey,
+          message: secondAttestation,
+        }),
+      ])
+      .signers([setup.signerKp])
+      .rpc();
+  };
+
+  const getHaltExpiry = (seconds: number) =>
+    new anchor.BN(Math.floor(Date.now() / 1_000) + seconds);
+
+  before(async () => {
+    setup = await setupTests();
+    program = setup.sdk.program;
+
+    await program.methods
+      .createPoolOverview()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+        newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
+        newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        allowPoolCreation: true,
+        minOperatorTokenStake: new anchor.BN(0),
+        delegatorUnstakeDelaySeconds: new anchor.BN(8),
+        operatorUnstakeDelaySeconds: new anchor.BN(20),
+        operatorPoolRegistrationFee: new anchor.BN(1_000),
+        slashingDelaySeconds,
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .createOperatorPool({
+        autoStakeFees: false,
+        rewardCommissionRateBps: setup.pool1.rewardCommissionRateBps,
+        usdcCommissionRateBps: setup.pool1.usdcCommissionRateBps,
+        allowDelegation: true,
+        name: setup.pool1.name,
+        description: setup.pool1.description,
+        websiteUrl: setup.pool1.websiteUrl,
+        avatarImageUrl: setup.pool1.avatarImageUrl,
+        operatorAuthKeys: null,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        stakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        poolOverview: setup.poolOverview,
+        mint: setup.tokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        adminTokenAccount: setup.pool1.adminTokenAccount,
+        registrationFeePayoutTokenAccount:
+          setup.registrationFeePayoutTokenAccount,
+        operatorUsdcVault: setup.pool1.poolUsdcVault,
+        usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+      )
+      .signers([setup.payerKp, setup.pool1.adminKp])
+      .rpc();
+
+    await program.methods
+      .stake({ tokenAmount: operatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.pool1.admin,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.stakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: setup.pool1.adminTokenAccount,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.pool1.adminKp])
+      .rpc();
+  });
+
+  it("Fail to halt an OperatorPool with an expiry in the past", async () => {
+    try {
+      await setHaltStatus(true, getHaltExpiry(-60));
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidHaltExpiry");
+    }
+  });
+
+  it("Fail to expire the halt of an OperatorPool that is not halted", async () => {
+    try {
+      await expireHalt();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "haltNotExpired");
+    }
+  });
+
+  it("Halting authority halts OperatorPool 1 with an expiry", async () => {
+    const expiresAt = getHaltExpiry(haltDurationSeconds);
+    await setHaltStatus(true, expiresAt);
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.isNotNull(operatorPool.haltedAtTimestamp);
+    assert(operatorPool.haltExpiresAt?.eq(expiresAt));
+    assert.equal(operatorPool.haltReasonCode, 1);
+    assert.deepEqual(operatorPool.haltEvidenceHash, haltEvidenceHash);
+  });
+
+  it("Fail to expire a halt before its expiry", async () => {
+    try {
+      await expireHalt();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "haltNotExpired");
+    }
+  });
+
+  it("Anyone can expire a halt once its expiry has passed", async () => {
+    await sleep((haltDurationSeconds + 2) * 1_000);
+    await expireHalt();
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.isNull(operatorPool.haltedAtTimestamp);
+    assert.isNull(operatorPool.haltExpiresAt);
+    assert.equal(operatorPool.haltReasonCode, 0);
+    assert.deepEqual(operatorPool.haltEvidenceHash, Array(32).fill(0));
+  });
+
+  it("Fail to expire the halt of an OperatorPool slashed since the halt", async () => {
+    // The halt expires after the slashing delay, so the pool can be slashed
+    // while halted.
+    await setHaltStatus(
+      true,
+      getHaltExpiry(slashingDelaySeconds.toNumber() + haltDurationSeconds)
+    );
+    await sleep(
+      (slashingDelaySeconds.toNumber() + haltDurationSeconds + 2) * 1_000
+    );
+
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      slashEvidenceHash
+    );
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
+    await program.methods
+      .createSlashProposal({
+        evidenceHash: slashEvidenceHash,
+        slashArgs: {
+          sharesAmount: new anchor.BN(10_000),
+          poolSlashBps: 0,
+          unstakingSlashBps: 0,
+          usdcEarningsConfiscation: { none: {} },
+          rewardCommissionConfiscation: { none: {} },
+          usdcCommissionConfiscation: { none: {} },
+        },
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        slashProposal,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+    await program.methods
+      .slashStake()
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        slashProposal,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        slashEscrow,
+        escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+        escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    try {
+      await expireHalt();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "slashedSinceHalt");
+    }
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.isNotNull(operatorPool.haltedAtTimestamp);
+  });
+
+  it("Operator admin sets an operator auth key", async () => {
+    await program.methods
+      .updateOperatorPool({
+        ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+        operatorAuthKeys: [authKeyKp.publicKey],
+      })
+      .accountsStrict({
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        poolOverview: setup.poolOverview,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([authKeyKp.publicKey])
+      )
+      .signers([setup.pool1.adminKp])
+      .rpc();
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.lengthOf(operatorPool.operatorAuthKeys, 1);
+    assert(operatorPool.operatorAuthKeys[0]?.equals(authKeyKp.publicKey));
+  });
+
+  it("Fail to report equivocation with invalid attestations", async () => {
+    const attestation = getAttestation({ subjectId: 1, resultHash: 1 });
+    const conflictingAttestation = getAttestation({
+      subjectId: 1,
+      resultHash: 2,
+    });
+
+    for (const [first, second, firstInstructionIndex] of [
+      // Same result for the same subject.
+      [
+        [authKeyKp, attestation],
+        [authKeyKp, attestation],
+        0,
+      ],
+      // Different subjects.
+      [
+        [authKeyKp, attestation],
+        [authKeyKp, getAttestation({ subjectId: 2, resultHash: 2 })],
+        0,
+      ],
+      // Signed by different keys.
+      [
+        [authKeyKp, attestation],
+        [otherKeyKp, conflictingAttestation],
+        0,
+      ],
+      // Signed by a key that is not an operator auth key of the pool.
+      [
+        [otherKeyKp, attestation],
+        [otherKeyKp, conflictingAttestation],
+        0,
+      ],
+      // Attestations for another OperatorPool.
+      [
+        [
+          authKeyKp,
+          getAttestation({
+            operatorPool: setup.pool2.pool,
+            subjectId: 1,
+            resultHash: 1,
+          }),
+        ],
+        [
+          authKeyKp,
+          getAttestation({
+            operatorPool: setup.pool2.pool,
+            subjectId: 1,
+            resultHash: 2,
+          }),
+        ],
+        0,
+      ],
+      // Instruction index that is not an ed25519 program instruction.
+      [
+        [authKeyKp, attestation],
+        [authKeyKp, conflictingAttestation],
+        2,
+      ],
+    ] as [[Keypair, Buffer], [Keypair, Buffer], number][]) {
+      try {
+        await reportEquivocation(first, second, firstInstructionIndex);
+        assert(false);
+      } catch (error) {
+        assertStakingProgramError(error, "invalidEquivocationProof");
+      }
+    }
+  });
+
+  it("Reporting equivocation keeps an existing halt and removes its expiry", async () => {
+    const operatorPoolPre = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.isNotNull(operatorPoolPre.haltedAtTimestamp);
+
+    const firstAttestation = getAttestation({ subjectId: 1, resultHash: 1 });
+    const secondAttestation = getAttestation({ subjectId: 1, resultHash: 2 });
+    await reportEquivocation(
+      [authKeyKp, firstAttestation],
+      [authKeyKp, secondAttestation]
+    );
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    const evidenceHash = createHash("sha256")
+      .update(Buffer.concat([firstAttestation, secondAttestation]))
+      .digest();
+    assert(
+      operatorPool.haltedAtTimestamp?.eq(operatorPoolPre.haltedAtTimestamp)
+    );
+    assert.isNull(operatorPool.haltExpiresAt);
+    assert.equal(operatorPool.haltReasonCode, equivocationReasonCode);
+    assert.deepEqual(operatorPool.haltEvidenceHash, Array.from(evidenceHash));
+
+    try {
+      await expireHalt();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "haltNotExpired");
+    }
+  });
+
+  it("Anyone can report equivocation to halt an OperatorPool", async () => {
+    await setHaltStatus(false, null);
+
+    await reportEquivocation(
+      [authKeyKp, getAttestation({ subjectId: 3, resultHash: 1 })],
+      [authKeyKp, getAttestation({ subjectId: 3, resultHash: 2 })]
+    );
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.isNotNull(operatorPool.haltedAtTimestamp);
+    assert.isNull(operatorPool.haltExpiresAt);
+    assert.equal(operatorPool.haltReasonCode, equivocationReasonCode);
+  });
+});
@@ -12,6 +12,7 @@ import { Keypair, SystemProgram } from "@solana/web3.js";
 import { assert } from "chai";
 
 import type { InferenceStaking } from "@sdk/src/idl";
+import { getInstructionPauseMask } from "@sdk/src/utils";
 
 import type { GenerateMerkleProofInput } from "@tests/lib/merkle";
 import { MerkleUtils } from "@tests/lib/merkle";
@@ -25,6 +26,7 @@ import {
   setStakingHalted,
   shortId,
   generateRewardsForEpoch,
+  decodeConfirmedTransaction,
 } from "@tests/lib/utils";
 
 describe("inference-staking program tests", () => {
@@ -45,6 +47,14 @@ describe("inference-staking program tests", () => {
   const isWithdrawalHalted = false;
   const isAccrueRewardHalted = false;
   const slashingDelaySeconds = new anchor.BN(3);
+  const slashEvidenceHash = Array(32).fill(1);
+  const tombstoneEvidenceHash = Array(32).fill(2);
+  const percentageSlashEvidenceHash = Array(32).fill(4);
+  const tombstonedAuthKeyKp = Keypair.generate();
+  const maxCommissionRateBps = 2_000;
+  const maxCommissionIncreaseBps = 300;
+
+  let commissionIncreaseNoticeEpochsPre = new anchor.BN(0);
 
   before(async () => {
     setup = await setupTests();
@@ -53,7 +63,7 @@ describe("inference-staking program tests", () => {
   });
 
   it("Create PoolOverview successfully", async () => {
-    await program.methods
+    const signature = await program.methods
       .createPoolOverview()
       .accountsStrict({
         payer: setup.payer,
@@ -68,6 +78,9 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
         slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
         slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.poolOverviewAdminKp])
       .rpc();
@@ -88,6 +101,25 @@ describe("inference-staking program tests", () => {
     assert(poolOverview.totalPools.isZero());
     assert(poolOverview.completedRewardEpoch.isZero());
     assert(poolOverview.unclaimedRewards.isZero());
+
+    const { getEventByType } = await decodeConfirmedTransaction(
+      setup,
+      signature
+    );
+    const event = getEventByType("createPoolOverviewEvent", 0);
+    assert(event != null, "CreatePoolOverviewEvent must be emitted");
+    assert.equal(event.data.version, 1);
+    assert.equal(event.data.instructionIndex, 0);
+    assert(event.data.epoch.eqn(1));
+    assert(event.data.programAdmin.equals(setup.poolOverviewAdmin));
+    assert(event.data.mint.equals(setup.tokenMint));
+    assert(
+      event.data.config.registrationFeePayoutWallet.equals(
+        setup.registrationFeePayoutWallet
+      )
+    );
+    assert.isFalse(event.data.config.allowPoolCreation);
+    assert(event.data.config.minOperatorTokenStake.isZero());
   });
 
   it("Fail to create OperatorPool when pool creation is disabled", async () => {
@@ -121,7 +153,13 @@ describe("inference-staking program tests", () => {
             setup.registrationFeePayoutTokenAccount,
           operatorUsdcVault: setup.pool1.poolUsdcVault,
           usdcMint: setup.usdcTokenMint,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
+        .remainingAccounts(
+          setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+        )
         .signers([setup.payerKp, setup.pool1.adminKp])
         .rpc();
       assert(false);
@@ -131,8 +169,9 @@ describe("inference-staking program tests", () => {
   });
 
   it("Update PoolOverview successfully", async () => {
-    await program.methods
+    const signature = await program.methods
       .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
         isStakingHalted,
         isWithdrawalHalted,
         isAccrueRewardHalted,
@@ -149,6 +188,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -174,20 +217,56 @@ describe("inference-staking program tests", () => {
     assert(poolOverview.totalPools.isZero());
     assert(poolOverview.completedRewardEpoch.isZero());
     assert(poolOverview.unclaimedRewards.isZero());
+
+    const { getEventByType } = await decodeConfirmedTransaction(
+      setup,
+      signature
+    );
+    const event = getEventByType("updatePoolOverviewEvent", 0);
+    assert(event != null, "UpdatePoolOverviewEvent must be emitted");
+    assert.equal(event.data.version, 1);
+    assert.equal(event.data.instructionIndex, 0);
+    const { oldConfig, newConfig } = event.data;
+    assert.isFalse(oldConfig.allowPoolCreation);
+    assert.equal(newConfig.allowPoolCreation, allowPoolCreation);
+    assert(oldConfig.minOperatorTokenStake.isZero());
+    assert(newConfig.minOperatorTokenStake.eq(minOperatorTokenStake));
+    assert(oldConfig.operatorPoolRegistrationFee.isZero());
+    assert(
+      newConfig.operatorPoolRegistrationFee.eq(operatorPoolRegistrationFee)
+    );
+    assert(newConfig.slashingDelaySeconds.eq(slashingDelaySeconds));
+    // Settings that were not part of the update are unchanged.
+    assert(
+      newConfig.registrationFeePayoutWallet.equals(
+        oldConfig.registrationFeePayoutWallet
+      )
+    );
+    assert(
+      newConfig.configChangeTimelockSeconds.eq(
+        oldConfig.configChangeTimelockSeconds
+      )
+    );
   });
 
   it("Update partial PoolOverview authorities successfully", async () => {
     // Update only halt authorities
-    await program.methods
+    const signature = await program.methods
       .updatePoolOverviewAuthorities({
         newRewardDistributionAuthorities: null,
         newHaltAuthorities: [setup.delegator1],
         newSlashingAuthorities: null,
+        newEpochAuthorities: null,
       })
       .accountsStrict({
-        newProgramAdmin: null,
+        payer: setup.poolOverviewAdmin,
         programAdmin: setup.poolOverviewAdmin,
         poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -200,6 +279,23 @@ describe("inference-staking program tests", () => {
     assert(poolOverview.haltAuthorities.length === 1);
     assert(poolOverview.haltAuthorities[0]?.equals(setup.delegator1));
     assert(poolOverview.rewardDistributionAuthorities.length === 0);
+
+    const { getEventByType } = await decodeConfirmedTransaction(
+      setup,
+      signature
+    );
+    const event = getEventByType("updatePoolOverviewAuthoritiesEvent", 0);
+    assert(event != null, "UpdatePoolOverviewAuthoritiesEvent must be emitted");
+    assert.equal(event.data.version, 1);
+    assert.equal(event.data.instructionIndex, 0);
+    assert.isEmpty(event.data.oldHaltAuthorities);
+    assert.lengthOf(event.data.newHaltAuthorities, 1);
+    assert(event.data.newHaltAuthorities[0]?.equals(setup.delegator1));
+    // Authorities that were not updated are reported unchanged.
+    assert.isEmpty(event.data.oldSlashingAuthorities);
+    assert.isEmpty(event.data.newSlashingAuthorities);
+    assert.isEmpty(event.data.oldRewardDistributionAuthorities);
+    assert.isEmpty(event.data.newRewardDistributionAuthorities);
   });
 
   it("Update PoolOverview authorities successfully", async () => {
@@ -210,11 +306,19 @@ describe("inference-staking program tests", () => {
         ],
         newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
         newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
       })
       .accountsStrict({
-        newProgramAdmin: null,
+        payer: setup.poolOverviewAdmin,
         programAdmin: setup.poolOverviewAdmin,
         poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -246,6 +350,7 @@ describe("inference-staking program tests", () => {
           merkleRoots: [],
           totalRewards: new anchor.BN(0),
           totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -255,6 +360,11 @@ describe("inference-staking program tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.pool1.adminKp])
         .rpc();
@@ -276,6 +386,7 @@ describe("inference-staking program tests", () => {
         merkleRoots: [],
         totalRewards: new anchor.BN(0),
         totalUsdcPayout: new anchor.BN(0),
+        rewardMintPayouts: [],
       })
       .accountsStrict({
         payer: setup.payer,
@@ -285,6 +396,11 @@ describe("inference-staking program tests", () => {
         rewardTokenAccount: setup.rewardTokenAccount,
         usdcTokenAccount: setup.usdcTokenAccount,
         systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
       .rpc();
@@ -302,6 +418,7 @@ describe("inference-staking program tests", () => {
           merkleRoots: [],
           totalRewards: new anchor.BN(0),
           totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -311,6 +428,11 @@ describe("inference-staking program tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -357,7 +479,13 @@ describe("inference-staking program tests", () => {
           setup.registrationFeePayoutTokenAccount,
         operatorUsdcVault: setup.pool1.poolUsdcVault,
         usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+      )
       .signers([setup.payerKp, setup.pool1.adminKp])
       .rpc();
 
@@ -394,7 +522,8 @@ describe("inference-staking program tests", () => {
     );
     assert.equal(operatorPool.autoStakeFees, autoStakeFees);
     assert.equal(operatorPool.rewardCommissionRateBps, rewardCommissionRateBps);
-    assert.isNull(operatorPool.newRewardCommissionRateBps);
+    assert.isEmpty(operatorPool.rewardCommissionRateSchedule);
+    assert.isEmpty(operatorPool.usdcCommissionRateSchedule);
     assert.equal(operatorPool.allowDelegation, allowDelegation);
     assert(operatorPool.totalStakedAmount.isZero());
     assert(operatorPool.totalShares.isZero());
@@ -447,7 +576,13 @@ describe("inference-staking program tests", () => {
             setup.registrationFeePayoutTokenAccount,
           operatorUsdcVault: setup.pool1.poolUsdcVault,
           usdcMint: setup.usdcTokenMint,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
+        .remainingAccounts(
+          setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+        )
         .signers([setup.payerKp, setup.pool1.adminKp])
         .rpc();
       assert(false);
@@ -466,8 +601,11 @@ describe("inference-staking program tests", () => {
         admin: setup.pool1.admin,
         newAdmin: setup.signer,
         operatorPool: setup.pool1.pool,
+        newAdminTombstone: setup.sdk.tombstonePda(setup.signer),
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp, setup.signerKp])
       .rpc();
@@ -487,8 +625,11 @@ describe("inference-staking program tests", () => {
         admin: setup.signer,
         newAdmin: setup.pool1.admin,
         operatorPool: setup.pool1.pool,
+        newAdminTombstone: setup.sdk.tombstonePda(setup.pool1.admin),
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.signerKp, setup.pool1.adminKp])
       .rpc();
@@ -509,6 +650,8 @@ describe("inference-staking program tests", () => {
       operatorPool: setup.pool1.pool,
       poolOverview: setup.poolOverview,
       instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      eventAuthority: setup.eventAuthority,
+      program: program.programId,
     } as const;
 
     const signers = [setup.pool1.adminKp];
@@ -595,12 +738,20 @@ describe("inference-staking program tests", () => {
   });
 
   it("Fail to update operator pool with invalid commission rate", async () => {
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
     try {
       // Expect failure as commission cannot exceed 100%.
       await program.methods
         .updateOperatorPool({
           ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
-          newRewardCommissionRateBps: { rateBps: 150_00 },
+          rewardCommissionRateSchedule: [
+            {
+              effectiveEpoch: poolOverview.completedRewardEpoch.addn(2),
+              rateBps: 150_00,
+            },
+          ],
           autoStakeFees: true,
           allowDelegation: false,
         })
@@ -609,6 +760,8 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           poolOverview: setup.poolOverview,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -618,10 +771,209 @@ describe("inference-staking program tests", () => {
     }
   });
 
+  it("Program admin sets the commission guardrails", async () => {
+    const poolOverviewPre = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    commissionIncreaseNoticeEpochsPre =
+      poolOverviewPre.commissionIncreaseNoticeEpochs;
+
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        maxCommissionRateBps,
+        maxCommissionIncreaseBps,
+        commissionIncreaseNoticeEpochs: new anchor.BN(3),
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.equal(poolOverview.maxCommissionRateBps, maxCommissionRateBps);
+    assert.equal(
+      poolOverview.maxCommissionIncreaseBps,
+      maxCommissionIncreaseBps
+    );
+    assert(poolOverview.commissionIncreaseNoticeEpochs.eqn(3));
+  });
+
+  it("Fail to schedule a commission rate above the program-wide max", async () => {
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    try {
+      await program.methods
+        .updateOperatorPool({
+          ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+          rewardCommissionRateSchedule: [
+            {
+              effectiveEpoch: poolOverview.completedRewardEpoch.addn(4),
+              rateBps: maxCommissionRateBps + 1,
+            },
+          ],
+        })
+        .accountsStrict({
+          admin: setup.pool1.admin,
+          operatorPool: setup.pool1.pool,
+          poolOverview: setup.poolOverview,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool1.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "commissionRateAboveMax");
+    }
+  });
+
+  it("Fail to schedule a commission increase larger than the max increase", async () => {
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    try {
+      await program.methods
+        .updateOperatorPool({
+          ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+          rewardCommissionRateSchedule: [
+            {
+              effectiveEpoch: poolOverview.completedRewardEpoch.addn(4),
+              rateBps: rewardCommissionRateBps + maxCommissionIncreaseBps + 1,
+            },
+          ],
+        })
+        .accountsStrict({
+          admin: setup.pool1.admin,
+          operatorPool: setup.pool1.pool,
+          poolOverview: setup.poolOverview,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool1.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "commissionIncreaseTooLarge");
+    }
+  });
+
+  it("Fail to schedule a commission increase without enough notice", async () => {
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    try {
+      await program.methods
+        .updateOperatorPool({
+          ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+          rewardCommissionRateSchedule: [
+            {
+              effectiveEpoch: poolOverview.completedRewardEpoch.addn(2),
+              rateBps: rewardCommissionRateBps + 100,
+            },
+          ],
+        })
+        .accountsStrict({
+          admin: setup.pool1.admin,
+          operatorPool: setup.pool1.pool,
+          poolOverview: setup.poolOverview,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool1.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "commissionNoticePeriodNotMet");
+    }
+  });
+
+  it("Fail to set and clear the max commission rate at once", async () => {
+    try {
+      await program.methods
+        .updatePoolOverview({
+          ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+          maxCommissionRateBps,
+          clearMaxCommissionRateBps: true,
+        })
+        .accountsStrict({
+          programAdmin: setup.poolOverviewAdminKp.publicKey,
+          poolOverview: setup.poolOverview,
+          registrationFeePayoutWallet: null,
+          slashingDestinationTokenAccount: null,
+          slashingDestinationUsdcAccount: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.poolOverviewAdminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "conflictingConfigChange");
+    }
+  });
+
+  it("Program admin clears the commission guardrails", async () => {
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        clearMaxCommissionRateBps: true,
+        clearMaxCommissionIncreaseBps: true,
+        commissionIncreaseNoticeEpochs: commissionIncreaseNoticeEpochsPre,
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.isNull(poolOverview.maxCommissionRateBps);
+    assert.isNull(poolOverview.maxCommissionIncreaseBps);
+    assert(
+      poolOverview.commissionIncreaseNoticeEpochs.eq(
+        commissionIncreaseNoticeEpochsPre
+      )
+    );
+  });
+
   it("Should update OperatorPool successfully", async () => {
     const newRewardCommissionRateBps = 5_500;
     const newUsdcCommissionRateBps = 8_500;
 
+    // Rates apply from the epoch after the current epoch at the earliest.
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    const effectiveEpoch = poolOverview.completedRewardEpoch.addn(2);
+
     const newName = `Test Operator ${shortId()}`;
     const newDescription = `Test Description ${shortId()}`;
     const newWebsiteUrl = `https://test.com/${shortId()}`;
@@ -629,21 +981,27 @@ describe("inference-staking program tests", () => {
 
     await program.methods
       .updateOperatorPool({
-        newRewardCommissionRateBps: { rateBps: newRewardCommissionRateBps },
-        newUsdcCommissionRateBps: { rateBps: newUsdcCommissionRateBps },
+        ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+        rewardCommissionRateSchedule: [
+          { effectiveEpoch, rateBps: newRewardCommissionRateBps },
+        ],
+        usdcCommissionRateSchedule: [
+          { effectiveEpoch, rateBps: newUsdcCommissionRateBps },
+        ],
         autoStakeFees: true,
         allowDelegation: false,
         name: newName,
         description: newDescription,
         websiteUrl: newWebsiteUrl,
         avatarImageUrl: newAvatarImageUrl,
-        operatorAuthKeys: null,
       })
       .accountsStrict({
         admin: setup.pool1.admin,
         operatorPool: setup.pool1.pool,
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -651,9 +1009,23 @@ describe("inference-staking program tests", () => {
     let operatorPool = await program.account.operatorPool.fetch(
       setup.pool1.pool
     );
+    assert.equal(operatorPool.rewardCommissionRateSchedule.length, 1);
+    assert(
+      operatorPool.rewardCommissionRateSchedule[0].effectiveEpoch.eq(
+        effectiveEpoch
+      ),
+      "Scheduled commission rate should apply from the effective epoch"
+    );
     assert(
-      operatorPool.newRewardCommissionRateBps === newRewardCommissionRateBps,
-      "New commission rate should be set"
+      operatorPool.rewardCommissionRateSchedule[0].rateBps ===
+        newRewardCommissionRateBps,
+      "New commission rate should be scheduled"
+    );
+    assert.equal(operatorPool.usdcCommissionRateSchedule.length, 1);
+    assert(
+      operatorPool.usdcCommissionRateSchedule[0].rateBps ===
+        newUsdcCommissionRateBps,
+      "New USDC commission rate should be scheduled"
     );
     assert(operatorPool.autoStakeFees === true, "Auto stake should be true");
     assert(
@@ -678,7 +1050,8 @@ describe("inference-staking program tests", () => {
     await program.methods
       .updateOperatorPool({
         ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
-        newRewardCommissionRateBps: { rateBps: null },
+        rewardCommissionRateSchedule: [],
+        usdcCommissionRateSchedule: [],
         autoStakeFees,
         allowDelegation,
       })
@@ -687,14 +1060,20 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
 
     operatorPool = await program.account.operatorPool.fetch(setup.pool1.pool);
-    assert.isNull(
-      operatorPool.newRewardCommissionRateBps,
-      "New commission rate should be set back to None"
+    assert.isEmpty(
+      operatorPool.rewardCommissionRateSchedule,
+      "Scheduled commission rates should be cleared"
+    );
+    assert.isEmpty(
+      operatorPool.usdcCommissionRateSchedule,
+      "Scheduled USDC commission rates should be cleared"
     );
     assert(
       operatorPool.autoStakeFees === autoStakeFees,
@@ -708,14 +1087,10 @@ describe("inference-staking program tests", () => {
 
   it("Fail to update OperatorPool with invalid operator auth keys", async () => {
     try {
-      const tooManyAuthKeys = [
-        Keypair.generate().publicKey,
-        Keypair.generate().publicKey,
-        Keypair.generate().publicKey,
-        Keypair.generate().publicKey,
-        Keypair.generate().publicKey,
-        Keypair.generate().publicKey,
-      ];
+      const tooManyAuthKeys = Array.from(
+        { length: 17 },
+        () => Keypair.generate().publicKey
+      );
 
       await program.methods
         .updateOperatorPool({
@@ -727,6 +1102,8 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           poolOverview: setup.poolOverview,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -752,6 +1129,8 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           poolOverview: setup.poolOverview,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -783,7 +1162,12 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts(newAuthKeys)
+      )
       .signers([setup.pool1.adminKp])
       .rpc();
 
@@ -832,7 +1216,12 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([singleAuthKey.publicKey])
+      )
       .signers([setup.pool1.adminKp])
       .rpc();
 
@@ -862,6 +1251,8 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -878,7 +1269,7 @@ describe("inference-staking program tests", () => {
   });
 
   it("Create StakingRecord successfully", async () => {
-    await program.methods
+    const signature = await program.methods
       .createStakingRecord()
       .accountsStrict({
         payer: setup.payer,
@@ -886,6 +1277,10 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: setup.pool1.delegatorStakingRecord,
         systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.delegator1Kp])
       .rpc();
@@ -898,6 +1293,18 @@ describe("inference-staking program tests", () => {
     assert(stakingRecord.shares.isZero());
     assert(stakingRecord.tokensUnstakeAmount.isZero());
     assert(stakingRecord.unstakeAtTimestamp.isZero());
+
+    const { getEventByType } = await decodeConfirmedTransaction(
+      setup,
+      signature
+    );
+    const event = getEventByType("createStakingRecordEvent", 0);
+    assert(event != null, "CreateStakingRecordEvent must be emitted");
+    assert.equal(event.data.version, 1);
+    assert.equal(event.data.instructionIndex, 0);
+    assert(event.data.operatorPool.equals(setup.pool1.pool));
+    assert(event.data.stakingRecord.equals(setup.pool1.delegatorStakingRecord));
+    assert(event.data.owner.equals(setup.delegator1));
   });
 
   it("Fail to stake for delegator when min. operator shares is not met", async () => {
@@ -907,7 +1314,7 @@ describe("inference-staking program tests", () => {
         setup.delegator1
       );
       await program.methods
-        .stake({ tokenAmount: new anchor.BN(400_000) })
+        .stake({ tokenAmount: new anchor.BN(400_000), referrer: null })
         .accountsStrict({
           owner: setup.delegator1,
           poolOverview: setup.poolOverview,
@@ -917,7 +1324,10 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           ownerTokenAccount,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -942,7 +1352,7 @@ describe("inference-staking program tests", () => {
 
     try {
       await program.methods
-        .stake({ tokenAmount: stakeAmount })
+        .stake({ tokenAmount: stakeAmount, referrer: null })
         .accountsStrict({
           owner: setup.pool1.admin,
           poolOverview: setup.poolOverview,
@@ -952,7 +1362,10 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           ownerTokenAccount,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -976,7 +1389,7 @@ describe("inference-staking program tests", () => {
     const stakeAmount = new anchor.BN(150_000);
 
     await program.methods
-      .stake({ tokenAmount: stakeAmount })
+      .stake({ tokenAmount: stakeAmount, referrer: null })
       .accountsStrict({
         owner: setup.pool1.admin,
         poolOverview: setup.poolOverview,
@@ -986,7 +1399,10 @@ describe("inference-staking program tests", () => {
         stakedTokenAccount: setup.pool1.stakedTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         ownerTokenAccount,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -1024,6 +1440,8 @@ describe("inference-staking program tests", () => {
           operatorStakingRecord: setup.pool1.stakingRecord,
           newStakingRecord: setup.pool1.delegatorStakingRecord,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp, setup.delegator1Kp])
         .rpc();
@@ -1044,6 +1462,8 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -1054,7 +1474,7 @@ describe("inference-staking program tests", () => {
 
     try {
       await program.methods
-        .stake({ tokenAmount: new anchor.BN(400_000) })
+        .stake({ tokenAmount: new anchor.BN(400_000), referrer: null })
         .accountsStrict({
           owner: setup.delegator1,
           poolOverview: setup.poolOverview,
@@ -1067,7 +1487,10 @@ describe("inference-staking program tests", () => {
             setup.tokenMint,
             setup.delegator1
           ),
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -1079,7 +1502,7 @@ describe("inference-staking program tests", () => {
 
   it("Stake for operator successfully even when delegation is disabled", async () => {
     await program.methods
-      .stake({ tokenAmount: new anchor.BN(100) })
+      .stake({ tokenAmount: new anchor.BN(100), referrer: null })
       .accountsStrict({
         owner: setup.pool1.admin,
         poolOverview: setup.poolOverview,
@@ -1092,7 +1515,10 @@ describe("inference-staking program tests", () => {
           setup.tokenMint,
           setup.pool1.admin
         ),
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -1108,6 +1534,8 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -1121,7 +1549,7 @@ describe("inference-staking program tests", () => {
 
     try {
       await program.methods
-        .stake({ tokenAmount: new anchor.BN(0) })
+        .stake({ tokenAmount: new anchor.BN(0), referrer: null })
         .accountsStrict({
           owner: setup.delegator1,
           poolOverview: setup.poolOverview,
@@ -1131,7 +1559,10 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           ownerTokenAccount,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -1158,7 +1589,7 @@ describe("inference-staking program tests", () => {
       ]);
 
     await program.methods
-      .stake({ tokenAmount: stakeAmount })
+      .stake({ tokenAmount: stakeAmount, referrer: null })
       .accountsStrict({
         owner: setup.delegator1,
         poolOverview: setup.poolOverview,
@@ -1168,7 +1599,10 @@ describe("inference-staking program tests", () => {
         stakedTokenAccount: setup.pool1.stakedTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         ownerTokenAccount,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.delegator1Kp])
       .rpc();
@@ -1225,6 +1659,10 @@ describe("inference-staking program tests", () => {
           ownerStakingRecord: setup.pool1.delegatorStakingRecord,
           operatorPool: setup.pool1.pool,
           systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          poolOverview: setup.poolOverview,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -1234,16 +1672,32 @@ describe("inference-staking program tests", () => {
     }
   });
 
-  it("Fail to unstake when global staking setting is disabled", async () => {
+  it("Fail to unstake when the unstake instruction is paused", async () => {
     const stakingRecord = await program.account.stakingRecord.fetch(
       setup.pool1.delegatorStakingRecord
     );
 
-    await setStakingHalted({
-      setup,
-      program,
-      isStakingHalted: true,
-    });
+    const instructionsMask = getInstructionPauseMask(["unstake"]);
+    const setUnstakePaused = async (isPaused: boolean) => {
+      await program.methods
+        .setInstructionPause({
+          instructionsMask,
+          isPaused,
+          expiresAt: null,
+        })
+        .accountsStrict({
+          authority: setup.poolOverviewAdmin,
+          poolOverview: setup.poolOverview,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.poolOverviewAdminKp])
+        .rpc();
+    };
+
+    await setUnstakePaused(true);
 
     try {
       await program.methods
@@ -1254,20 +1708,71 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           ownerStakingRecord: setup.pool1.delegatorStakingRecord,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
       assert(false);
     } catch (error) {
-      assertStakingProgramError(error, "stakingHalted");
+      assertStakingProgramError(error, "instructionPaused");
     } finally {
-      await setStakingHalted({
-        setup,
-        program,
-        isStakingHalted: false,
-      });
+      await setUnstakePaused(false);
+    }
+  });
+
+  it("Fail to unpause or shorten a pause as a halt authority", async () => {
+    const instructionsMask = getInstructionPauseMask(["unstake"]);
+    const setUnstakePause = async (
+      authorityKp: Keypair,
+      isPaused: boolean,
+      expiresAt: anchor.BN | null
+    ) => {
+      await program.methods
+        .setInstructionPause({ instructionsMask, isPaused, expiresAt })
+        .accountsStrict({
+          authority: authorityKp.publicKey,
+          poolOverview: setup.poolOverview,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([authorityKp])
+        .rpc();
+    };
+
+    await setUnstakePause(setup.haltingAuthorityKp, true, null);
+
+    try {
+      await setUnstakePause(setup.haltingAuthorityKp, false, null);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidUnpauseAuthority");
     }
+
+    try {
+      await setUnstakePause(
+        setup.haltingAuthorityKp,
+        true,
+        new anchor.BN(Math.floor(Date.now() / 1_000) + 60)
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidUnpauseAuthority");
+    }
+
+    let poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(!poolOverview.pausedInstructions.and(instructionsMask).isZero());
+
+    await setUnstakePause(setup.poolOverviewAdminKp, false, null);
+
+    poolOverview = await program.account.poolOverview.fetch(setup.poolOverview);
+    assert(poolOverview.pausedInstructions.and(instructionsMask).isZero());
   });
 
   it("Fail to unstake more shares than in StakingRecord", async () => {
@@ -1284,7 +1789,10 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           ownerStakingRecord: setup.pool1.delegatorStakingRecord,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -1306,6 +1814,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -1319,7 +1831,10 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           ownerStakingRecord: setup.pool1.delegatorStakingRecord,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -1339,6 +1854,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -1356,6 +1875,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -1369,7 +1892,10 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           ownerStakingRecord: setup.pool1.stakingRecord,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -1395,7 +1921,10 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: setup.pool1.delegatorStakingRecord,
         operatorStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.delegator1Kp])
       .rpc();
@@ -1442,6 +1971,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -1464,7 +1997,10 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: setup.pool1.stakingRecord,
         operatorStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -1517,7 +2053,10 @@ describe("inference-staking program tests", () => {
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -1565,7 +2104,10 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: setup.pool1.stakingRecord,
         operatorStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -1589,6 +2131,8 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
 
@@ -1628,9 +2172,15 @@ describe("inference-staking program tests", () => {
         setup.registrationFeePayoutTokenAccount,
       operatorUsdcVault: setup.pool2.poolUsdcVault,
       usdcMint: setup.usdcTokenMint,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      eventAuthority: setup.eventAuthority,
+      program: program.programId,
     } as const;
 
     const signers = [setup.payerKp, setup.pool2.adminKp];
+    const remainingAccounts = setup.sdk.getTombstoneRemainingAccounts([
+      setup.pool2.admin,
+    ]);
 
     try {
       const longName = "a".repeat(65);
@@ -1640,6 +2190,7 @@ describe("inference-staking program tests", () => {
           name: longName,
         })
         .accountsStrict(accounts)
+        .remainingAccounts(remainingAccounts)
         .signers(signers)
         .rpc();
 
@@ -1658,6 +2209,7 @@ describe("inference-staking program tests", () => {
           description: longDescription,
         })
         .accountsStrict(accounts)
+        .remainingAccounts(remainingAccounts)
         .signers(signers)
         .rpc();
 
@@ -1674,6 +2226,7 @@ describe("inference-staking program tests", () => {
           websiteUrl: longWebsiteUrl,
         })
         .accountsStrict(accounts)
+        .remainingAccounts(remainingAccounts)
         .signers(signers)
         .rpc();
 
@@ -1690,6 +2243,7 @@ describe("inference-staking program tests", () => {
           websiteUrl: invalidWebsiteUrl,
         })
         .accountsStrict(accounts)
+        .remainingAccounts(remainingAccounts)
         .signers(signers)
         .rpc();
 
@@ -1706,6 +2260,7 @@ describe("inference-staking program tests", () => {
           avatarImageUrl: longAvatarImageUrl,
         })
         .accountsStrict(accounts)
+        .remainingAccounts(remainingAccounts)
         .signers(signers)
         .rpc();
 
@@ -1753,7 +2308,13 @@ describe("inference-staking program tests", () => {
           setup.registrationFeePayoutTokenAccount,
         operatorUsdcVault: setup.pool2.poolUsdcVault,
         usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool2.admin])
+      )
       .signers([setup.payerKp, setup.pool2.adminKp])
       .rpc();
 
@@ -1790,7 +2351,7 @@ describe("inference-staking program tests", () => {
     );
     assert.equal(operatorPool.autoStakeFees, autoStakeFees);
     assert.equal(operatorPool.rewardCommissionRateBps, rewardCommissionRateBps);
-    assert.isNull(operatorPool.newRewardCommissionRateBps);
+    assert.isEmpty(operatorPool.rewardCommissionRateSchedule);
     assert.equal(operatorPool.allowDelegation, allowDelegation);
     assert(operatorPool.totalStakedAmount.isZero());
     assert(operatorPool.totalShares.isZero());
@@ -1861,6 +2422,7 @@ describe("inference-staking program tests", () => {
         merkleRoots,
         totalRewards,
         totalUsdcPayout: totalUsdcAmount,
+        rewardMintPayouts: [],
       })
       .accountsStrict({
         payer: setup.payer,
@@ -1870,6 +2432,11 @@ describe("inference-staking program tests", () => {
         rewardTokenAccount: setup.rewardTokenAccount,
         usdcTokenAccount: setup.usdcTokenAccount,
         systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
       .rpc();
@@ -1894,7 +2461,7 @@ describe("inference-staking program tests", () => {
         setup.delegator1
       );
       await program.methods
-        .stake({ tokenAmount: new anchor.BN(400_000) })
+        .stake({ tokenAmount: new anchor.BN(400_000), referrer: null })
         .accountsStrict({
           owner: setup.delegator1,
           poolOverview: setup.poolOverview,
@@ -1904,7 +2471,10 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           ownerTokenAccount,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -1924,7 +2494,10 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           ownerStakingRecord: setup.pool1.stakingRecord,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -1943,7 +2516,10 @@ describe("inference-staking program tests", () => {
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
           ownerStakingRecord: setup.pool1.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -1973,6 +2549,8 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
 
@@ -2026,6 +2604,7 @@ describe("inference-staking program tests", () => {
         proofPath,
         rewardAmount,
         usdcAmount,
+        rewardMintAmounts: [],
       })
       .accountsStrict({
         poolOverview: setup.poolOverview,
@@ -2042,6 +2621,8 @@ describe("inference-staking program tests", () => {
         ),
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .rpc();
 
@@ -2080,7 +2661,7 @@ describe("inference-staking program tests", () => {
     assert(operatorPool.totalUnstaking.eq(operatorPre.totalUnstaking));
     assert(operatorPool.accruedRewards.isZero());
     assert(operatorPool.accruedRewardCommission.isZero());
-    assert.isNull(operatorPool.newRewardCommissionRateBps);
+    assert.isEmpty(operatorPool.rewardCommissionRateSchedule);
 
     // Verify that operator's shares remain unchanged with auto-stake disabled.
     const operatorStakingRecord = await program.account.stakingRecord.fetch(
@@ -2145,6 +2726,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -2165,6 +2750,8 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -2184,6 +2771,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -2201,6 +2792,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -2221,6 +2816,8 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -2240,6 +2837,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -2269,6 +2870,8 @@ describe("inference-staking program tests", () => {
         stakedTokenAccount: setup.pool1.stakedTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .rpc();
 
@@ -2302,12 +2905,19 @@ describe("inference-staking program tests", () => {
     await program.methods
       .setHaltStatus({
         isHalted: true,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
       })
       .accountsStrict({
         authority: setup.haltingAuthorityKp.publicKey,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.haltingAuthorityKp])
       .rpc();
@@ -2328,6 +2938,8 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -2339,12 +2951,19 @@ describe("inference-staking program tests", () => {
     await program.methods
       .setHaltStatus({
         isHalted: false,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
       })
       .accountsStrict({
         authority: setup.haltingAuthorityKp.publicKey,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.haltingAuthorityKp])
       .rpc();
@@ -2374,6 +2993,8 @@ describe("inference-staking program tests", () => {
         stakedTokenAccount: setup.pool1.stakedTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .rpc();
 
@@ -2402,28 +3023,35 @@ describe("inference-staking program tests", () => {
     assert(stakingRecord.unstakeAtTimestamp.isZero());
   });
 
-  it("Fail to slash OperatorPool stake with invalid authority", async () => {
+  it("Fail to propose a slash of OperatorPool stake with invalid authority", async () => {
     try {
       await program.methods
-        .slashStake({ sharesAmount: new anchor.BN(1) })
+        .createSlashProposal({
+          evidenceHash: slashEvidenceHash,
+          slashArgs: {
+            sharesAmount: new anchor.BN(1),
+            poolSlashBps: 0,
+            unstakingSlashBps: 0,
+            usdcEarningsConfiscation: { all: {} },
+            rewardCommissionConfiscation: { all: {} },
+            usdcCommissionConfiscation: { all: {} },
+          },
+        })
         .accountsStrict({
+          payer: setup.payer,
           authority: setup.pool1.adminKp.publicKey,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
-          operatorStakingRecord: setup.pool1.stakingRecord,
-          stakedTokenAccount: setup.pool1.stakedTokenAccount,
-          slashingDestinationTokenAccount:
-            setup.slashingDestinationTokenAccount,
-          slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
-          poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
-            setup.pool1.pool
+          slashProposal: setup.sdk.slashProposalPda(
+            setup.pool1.pool,
+            slashEvidenceHash
           ),
-          tokenProgram: TOKEN_PROGRAM_ID,
-          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
-          rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+          systemProgram: SystemProgram.programId,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.pool1.adminKp])
+        .signers([setup.payerKp, setup.pool1.adminKp])
         .rpc();
       assert(false);
     } catch (error) {
@@ -2436,12 +3064,19 @@ describe("inference-staking program tests", () => {
       await program.methods
         .setHaltStatus({
           isHalted: true,
+          reasonCode: 0,
+          evidenceHash: Array(32).fill(0),
+          expiresAt: null,
         })
         .accountsStrict({
           authority: setup.pool1.adminKp.publicKey,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
+          pendingAction: null,
+          adminAuditLog: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -2452,33 +3087,90 @@ describe("inference-staking program tests", () => {
     }
   });
 
-  it("Slashing requires a pool to be halted", async () => {
+  it("Slashing authority should propose a slash of OperatorPool 1 stake", async () => {
     const operatorStakingRecordPre = await program.account.stakingRecord.fetch(
       setup.pool1.stakingRecord
     );
     const sharesToSlash = operatorStakingRecordPre.shares.divn(20);
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      slashEvidenceHash
+    );
+
+    await program.methods
+      .createSlashProposal({
+        evidenceHash: slashEvidenceHash,
+        slashArgs: {
+          sharesAmount: sharesToSlash,
+          poolSlashBps: 0,
+          unstakingSlashBps: 0,
+          usdcEarningsConfiscation: { all: {} },
+          rewardCommissionConfiscation: { all: {} },
+          usdcCommissionConfiscation: { all: {} },
+        },
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        slashProposal,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    const slashProposalAccount = await program.account.slashProposal.fetch(
+      slashProposal
+    );
+    assert(slashProposalAccount.operatorPool.equals(setup.pool1.pool));
+    assert(slashProposalAccount.proposer.equals(setup.slashingAuthority));
+    assert.deepEqual(slashProposalAccount.evidenceHash, slashEvidenceHash);
+    assert(slashProposalAccount.args.sharesAmount.eq(sharesToSlash));
+    assert.lengthOf(slashProposalAccount.approvals, 1);
+    assert(slashProposalAccount.approvals[0]?.equals(setup.slashingAuthority));
+    assert.isNull(slashProposalAccount.executedAt);
+  });
+
+  it("Slashing requires a pool to be halted", async () => {
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      slashEvidenceHash
+    );
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
 
     try {
       await program.methods
-        .slashStake({ sharesAmount: sharesToSlash })
+        .slashStake()
         .accountsStrict({
+          payer: setup.payer,
           authority: setup.slashingAuthority,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          slashProposal,
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
             setup.pool1.pool
           ),
-          slashingDestinationTokenAccount:
-            setup.slashingDestinationTokenAccount,
-          slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
           rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+          slashEscrow,
+          escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+          escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+          mint: setup.tokenMint,
+          usdcMint: setup.usdcTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.slashingAuthorityKp])
+        .signers([setup.payerKp, setup.slashingAuthorityKp])
         .rpc();
       assert(false);
     } catch (err) {
@@ -2490,12 +3182,19 @@ describe("inference-staking program tests", () => {
     await program.methods
       .setHaltStatus({
         isHalted: true,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
       })
       .accountsStrict({
         authority: setup.haltingAuthorityKp.publicKey,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.haltingAuthorityKp])
       .rpc();
@@ -2519,7 +3218,10 @@ describe("inference-staking program tests", () => {
           operatorPool: setup.pool1.pool,
           ownerStakingRecord: setup.pool1.stakingRecord,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -2537,7 +3239,7 @@ describe("inference-staking program tests", () => {
         setup.delegator1
       );
       await program.methods
-        .stake({ tokenAmount: new anchor.BN(400_000) })
+        .stake({ tokenAmount: new anchor.BN(400_000), referrer: null })
         .accountsStrict({
           owner: setup.delegator1,
           poolOverview: setup.poolOverview,
@@ -2547,7 +3249,10 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           ownerTokenAccount,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -2566,6 +3271,9 @@ describe("inference-staking program tests", () => {
           admin: setup.pool1.admin,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -2590,6 +3298,8 @@ describe("inference-staking program tests", () => {
           ),
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -2602,12 +3312,19 @@ describe("inference-staking program tests", () => {
     await program.methods
       .setHaltStatus({
         isHalted: false,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
       })
       .accountsStrict({
         authority: setup.haltingAuthorityKp.publicKey,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.haltingAuthorityKp])
       .rpc();
@@ -2626,12 +3343,19 @@ describe("inference-staking program tests", () => {
     await program.methods
       .setHaltStatus({
         isHalted: true,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
       })
       .accountsStrict({
         authority: setup.haltingAuthorityKp.publicKey,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.haltingAuthorityKp])
       .rpc();
@@ -2650,6 +3374,8 @@ describe("inference-staking program tests", () => {
           ),
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -2660,32 +3386,41 @@ describe("inference-staking program tests", () => {
   });
 
   it("Slashing requires the minimum slashing delay to have passed", async () => {
-    const operatorStakingRecordPre = await program.account.stakingRecord.fetch(
-      setup.pool1.stakingRecord
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      slashEvidenceHash
     );
-    const sharesToSlash = operatorStakingRecordPre.shares.divn(20);
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
 
     try {
       await program.methods
-        .slashStake({ sharesAmount: sharesToSlash })
+        .slashStake()
         .accountsStrict({
+          payer: setup.payer,
           authority: setup.slashingAuthority,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          slashProposal,
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
             setup.pool1.pool
           ),
-          slashingDestinationTokenAccount:
-            setup.slashingDestinationTokenAccount,
-          slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
           rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+          slashEscrow,
+          escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+          escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+          mint: setup.tokenMint,
+          usdcMint: setup.usdcTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.slashingAuthorityKp])
+        .signers([setup.payerKp, setup.slashingAuthorityKp])
         .rpc();
       assert(false);
     } catch (err) {
@@ -2696,27 +3431,38 @@ describe("inference-staking program tests", () => {
   it("Fail to slash OperatorPool stake with invalid shares amount", async () => {
     await sleep(slashingDelaySeconds.toNumber() * 2 * 1_000);
 
+    const invalidEvidenceHash = Array(32).fill(2);
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      invalidEvidenceHash
+    );
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
+
     try {
       await program.methods
-        .slashStake({ sharesAmount: new anchor.BN(0) })
+        .createSlashProposal({
+          evidenceHash: invalidEvidenceHash,
+          slashArgs: {
+            sharesAmount: new anchor.BN(0),
+            poolSlashBps: 0,
+            unstakingSlashBps: 0,
+            usdcEarningsConfiscation: { all: {} },
+            rewardCommissionConfiscation: { all: {} },
+            usdcCommissionConfiscation: { all: {} },
+          },
+        })
         .accountsStrict({
+          payer: setup.payer,
           authority: setup.slashingAuthority,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
-          operatorStakingRecord: setup.pool1.stakingRecord,
-          stakedTokenAccount: setup.pool1.stakedTokenAccount,
-          poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
-            setup.pool1.pool
-          ),
-          slashingDestinationTokenAccount:
-            setup.slashingDestinationTokenAccount,
-          slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
-          rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+          slashProposal,
+          systemProgram: SystemProgram.programId,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.slashingAuthorityKp])
+        .signers([setup.payerKp, setup.slashingAuthorityKp])
         .rpc();
       assert(false);
     } catch (error) {
@@ -2727,27 +3473,61 @@ describe("inference-staking program tests", () => {
       setup.pool1.stakingRecord
     );
 
+    await program.methods
+      .createSlashProposal({
+        evidenceHash: invalidEvidenceHash,
+        slashArgs: {
+          sharesAmount: operatorStakingRecord.shares.addn(1),
+          poolSlashBps: 0,
+          unstakingSlashBps: 0,
+          usdcEarningsConfiscation: { all: {} },
+          rewardCommissionConfiscation: { all: {} },
+          usdcCommissionConfiscation: { all: {} },
+        },
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        slashProposal,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
     try {
       await program.methods
-        .slashStake({ sharesAmount: operatorStakingRecord.shares.addn(1) })
+        .slashStake()
         .accountsStrict({
+          payer: setup.payer,
           authority: setup.slashingAuthority,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
           operatorStakingRecord: setup.pool1.stakingRecord,
+          slashProposal,
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
             setup.pool1.pool
           ),
-          slashingDestinationTokenAccount:
-            setup.slashingDestinationTokenAccount,
-          slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
           rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+          usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+          slashEscrow,
+          escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+          escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+          mint: setup.tokenMint,
+          usdcMint: setup.usdcTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.slashingAuthorityKp])
+        .signers([setup.payerKp, setup.slashingAuthorityKp])
         .rpc();
       assert(false);
     } catch (error) {
@@ -2755,27 +3535,239 @@ describe("inference-staking program tests", () => {
     }
   });
 
+  it("Slash OperatorPool 1 stake confiscating a percentage of earnings and commission", async () => {
+    const confiscationBps = 2_500;
+    const confiscation = { percentage: { 0: confiscationBps } };
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      percentageSlashEvidenceHash
+    );
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
+    const escrowTokenAccount = setup.sdk.slashEscrowTokenVaultPda(slashEscrow);
+    const escrowUsdcAccount = setup.sdk.slashEscrowUsdcVaultPda(slashEscrow);
+    const poolUsdcVault = setup.sdk.poolDelegatorUsdcEarningsVaultPda(
+      setup.pool1.pool
+    );
+
+    const operatorStakingRecordPre = await program.account.stakingRecord.fetch(
+      setup.pool1.stakingRecord
+    );
+    const sharesToSlash = operatorStakingRecordPre.shares.divn(40);
+    await program.methods
+      .createSlashProposal({
+        evidenceHash: percentageSlashEvidenceHash,
+        slashArgs: {
+          sharesAmount: sharesToSlash,
+          poolSlashBps: 0,
+          unstakingSlashBps: 0,
+          usdcEarningsConfiscation: confiscation,
+          rewardCommissionConfiscation: confiscation,
+          usdcCommissionConfiscation: confiscation,
+        },
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        slashProposal,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    const [
+      operatorPoolPre,
+      stakedBalancePre,
+      poolUsdcBalancePre,
+      rewardCommissionBalancePre,
+      usdcCommissionBalancePre,
+    ] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      connection.getTokenAccountBalance(setup.pool1.stakedTokenAccount),
+      connection.getTokenAccountBalance(poolUsdcVault),
+      connection.getTokenAccountBalance(
+        setup.pool1.rewardCommissionFeeTokenVault
+      ),
+      connection.getTokenAccountBalance(
+        setup.pool1.usdcCommissionFeeTokenVault
+      ),
+    ]);
+
+    const signature = await program.methods
+      .slashStake()
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        slashProposal,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        poolUsdcVault,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        slashEscrow,
+        escrowTokenAccount,
+        escrowUsdcAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    const [
+      operatorStakingRecord,
+      escrow,
+      stakedBalance,
+      poolUsdcBalance,
+      rewardCommissionBalance,
+      usdcCommissionBalance,
+      escrowBalance,
+      escrowUsdcBalance,
+    ] = await Promise.all([
+      program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
+      program.account.slashEscrow.fetch(slashEscrow),
+      connection.getTokenAccountBalance(setup.pool1.stakedTokenAccount),
+      connection.getTokenAccountBalance(poolUsdcVault),
+      connection.getTokenAccountBalance(
+        setup.pool1.rewardCommissionFeeTokenVault
+      ),
+      connection.getTokenAccountBalance(
+        setup.pool1.usdcCommissionFeeTokenVault
+      ),
+      connection.getTokenAccountBalance(escrowTokenAccount),
+      connection.getTokenAccountBalance(escrowUsdcAccount),
+    ]);
+    const toBN = (balance: typeof stakedBalance) =>
+      new anchor.BN(balance.value.amount);
+    const applyConfiscation = (amount: anchor.BN) =>
+      amount.muln(confiscationBps).divn(10_000);
+
+    const tokenAmountSlashed = operatorPoolPre.totalStakedAmount
+      .mul(sharesToSlash)
+      .div(operatorPoolPre.totalShares);
+    const rewardCommissionConfiscated = applyConfiscation(
+      toBN(rewardCommissionBalancePre)
+    );
+    const usdcCommissionConfiscated = applyConfiscation(
+      toBN(usdcCommissionBalancePre)
+    );
+    // USDC earnings are settled before the confiscation, so the confiscated
+    // amount is a percentage of the settled earnings.
+    const usdcConfiscated = toBN(poolUsdcBalancePre).sub(
+      toBN(poolUsdcBalance)
+    );
+    assert(usdcConfiscated.gtn(0));
+    assert(
+      usdcConfiscated.eq(
+        applyConfiscation(
+          operatorStakingRecord.accruedUsdcEarnings.add(usdcConfiscated)
+        )
+      )
+    );
+    assert(rewardCommissionConfiscated.gtn(0));
+    assert(usdcCommissionConfiscated.gtn(0));
+
+    // Only the confiscated percentage leaves the vaults.
+    assert(
+      toBN(stakedBalance).eq(toBN(stakedBalancePre).sub(tokenAmountSlashed))
+    );
+    assert(
+      toBN(rewardCommissionBalance).eq(
+        toBN(rewardCommissionBalancePre).sub(rewardCommissionConfiscated)
+      )
+    );
+    assert(
+      toBN(usdcCommissionBalance).eq(
+        toBN(usdcCommissionBalancePre).sub(usdcCommissionConfiscated)
+      )
+    );
+    assert(
+      toBN(escrowBalance).eq(
+        tokenAmountSlashed.add(rewardCommissionConfiscated)
+      )
+    );
+    assert(
+      toBN(escrowUsdcBalance).eq(
+        usdcConfiscated.add(usdcCommissionConfiscated)
+      )
+    );
+
+    assert(escrow.sharesSlashed.eq(sharesToSlash));
+    assert(escrow.tokenAmountSlashed.eq(tokenAmountSlashed));
+    assert(escrow.usdcConfiscated.eq(usdcConfiscated));
+    assert(escrow.rewardCommissionConfiscated.eq(rewardCommissionConfiscated));
+    assert(escrow.usdcCommissionConfiscated.eq(usdcCommissionConfiscated));
+
+    const { getEventByType } = await decodeConfirmedTransaction(
+      setup,
+      signature
+    );
+    const event = getEventByType("slashStakeEvent", 0);
+    assert(event != null, "SlashStakeEvent must be emitted");
+    assert.equal(event.data.version, 1);
+    assert.equal(event.data.instructionIndex, 0);
+    assert(event.data.operatorPool.equals(setup.pool1.pool));
+    assert(
+      event.data.operatorStakingRecord.equals(setup.pool1.stakingRecord)
+    );
+    assert(event.data.authority.equals(setup.slashingAuthority));
+    assert(event.data.destination.equals(escrowTokenAccount));
+    assert(event.data.destinationUsdc.equals(escrowUsdcAccount));
+    assert(event.data.slashProposal.equals(slashProposal));
+    assert(event.data.slashEscrow.equals(slashEscrow));
+    assert.deepEqual(event.data.evidenceHash, percentageSlashEvidenceHash);
+    assert(event.data.sharesSlashed.eq(sharesToSlash));
+    assert(event.data.tokenAmountSlashed.eq(tokenAmountSlashed));
+    assert(event.data.usdcConfiscated.eq(usdcConfiscated));
+    assert(
+      event.data.rewardCommissionConfiscated.eq(rewardCommissionConfiscated)
+    );
+    assert(event.data.usdcCommissionConfiscated.eq(usdcCommissionConfiscated));
+    assert.deepEqual(event.data.usdcEarningsConfiscation, confiscation);
+    assert.deepEqual(event.data.rewardCommissionConfiscation, confiscation);
+    assert.deepEqual(event.data.usdcCommissionConfiscation, confiscation);
+    assert(event.data.releaseAt.eq(escrow.releaseAt));
+  });
+
   it("Admin should be able to slash OperatorPool 1 stake after slashing delay", async () => {
     await sleep(slashingDelaySeconds.toNumber() * 2 * 1_000);
 
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      slashEvidenceHash
+    );
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
+    const escrowTokenAccount = setup.sdk.slashEscrowTokenVaultPda(slashEscrow);
+
     const [
-      destinationBalancePre,
       operatorPoolTokenAccountPre,
       operatorStakingRecordPre,
       operatorPoolPre,
       rewardCommissionBalancePre,
+      slashProposalPre,
     ] = await Promise.all([
-      connection.getTokenAccountBalance(setup.slashingDestinationTokenAccount),
       connection.getTokenAccountBalance(setup.pool1.stakedTokenAccount),
       program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
       program.account.operatorPool.fetch(setup.pool1.pool),
       connection.getTokenAccountBalance(
         setup.pool1.rewardCommissionFeeTokenVault
       ),
+      program.account.slashProposal.fetch(slashProposal),
     ]);
 
-    // Slash 5% of the operator's stake.
-    const sharesToSlash = operatorStakingRecordPre.shares.divn(20);
+    // Slash 5% of the operator's stake, as proposed.
+    const sharesToSlash = slashProposalPre.args.sharesAmount;
     const expectedStakeRemoved = operatorPoolPre.totalStakedAmount
       .mul(sharesToSlash)
       .div(operatorPoolPre.totalShares);
@@ -2785,40 +3777,53 @@ describe("inference-staking program tests", () => {
     );
 
     await program.methods
-      .slashStake({ sharesAmount: sharesToSlash })
+      .slashStake()
       .accountsStrict({
+        payer: setup.payer,
         authority: setup.slashingAuthority,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
         operatorStakingRecord: setup.pool1.stakingRecord,
+        slashProposal,
         stakedTokenAccount: setup.pool1.stakedTokenAccount,
         poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
           setup.pool1.pool
         ),
-        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
-        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
         rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        slashEscrow,
+        escrowTokenAccount,
+        escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
-      .signers([setup.slashingAuthorityKp])
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
       .rpc();
 
     const [
-      destinationBalancePost,
+      escrowBalancePost,
       operatorPoolTokenAccountPost,
       operatorStakingRecordPost,
       operatorPoolPost,
       rewardCommissionBalancePost,
+      slashProposalPost,
+      slashEscrowPost,
     ] = await Promise.all([
-      connection.getTokenAccountBalance(setup.slashingDestinationTokenAccount),
+      connection.getTokenAccountBalance(escrowTokenAccount),
       connection.getTokenAccountBalance(setup.pool1.stakedTokenAccount),
       program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
       program.account.operatorPool.fetch(setup.pool1.pool),
       connection.getTokenAccountBalance(
         setup.pool1.rewardCommissionFeeTokenVault
       ),
+      program.account.slashProposal.fetch(slashProposal),
+      program.account.slashEscrow.fetch(slashEscrow),
     ]);
 
     // Assert change in Operator stake
@@ -2861,44 +3866,159 @@ describe("inference-staking program tests", () => {
       expectedRewardCommissionConfiscated
     );
     assert(
-      new anchor.BN(destinationBalancePost.value.amount).eq(
-        new anchor.BN(destinationBalancePre.value.amount).add(
-          totalExpectedTokens
-        )
-      ),
-      "Destination token account must receive both slashed tokens and reward commission"
+      new anchor.BN(escrowBalancePost.value.amount).eq(totalExpectedTokens),
+      "Escrow token account must receive both slashed tokens and reward commission"
     );
-  });
-
-  it("Halted pool can be unhalted", async () => {
-    // Set back to unhalted
-    await program.methods
-      .setHaltStatus({
-        isHalted: false,
-      })
-      .accountsStrict({
-        authority: setup.haltingAuthorityKp.publicKey,
-        poolOverview: setup.poolOverview,
-        operatorPool: setup.pool1.pool,
-        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-      })
-      .signers([setup.haltingAuthorityKp])
-      .rpc();
 
-    const operatorPoolPost = await program.account.operatorPool.fetch(
-      setup.pool1.pool
-    );
-    assert.isNull(
-      operatorPoolPost.haltedAtTimestamp,
-      "OperatorPool must be unhalted"
+    // Assert the proposal is executed and the slash is escrowed
+    assert.isNotNull(slashProposalPost.executedAt);
+    assert(slashEscrowPost.slashProposal.equals(slashProposal));
+    assert(slashEscrowPost.sharesSlashed.eq(sharesToSlash));
+    assert(slashEscrowPost.tokenAmountSlashed.eq(expectedStakeRemoved));
+    assert(
+      slashEscrowPost.rewardCommissionConfiscated.eq(
+        expectedRewardCommissionConfiscated
+      )
     );
+    assert(slashEscrowPost.releaseAt.eq(slashEscrowPost.slashedAt));
   });
 
-  it("Fail to withdraw Operator commission if global withdrawal is halted", async () => {
-    // Halt withdrawal
-    await program.methods
-      .updatePoolOverview({
-        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+  it("Fail to approve an executed slash proposal", async () => {
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      slashEvidenceHash
+    );
+
+    try {
+      await program.methods
+        .approveSlashProposal()
+        .accountsStrict({
+          authority: setup.slashingAuthority,
+          poolOverview: setup.poolOverview,
+          slashProposal,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.slashingAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "slashProposalAlreadyExecuted");
+    }
+  });
+
+  it("Anyone can release escrowed slash proceeds to the slashing destination after the appeal period", async () => {
+    const slashEscrow = setup.sdk.slashEscrowPda(
+      setup.sdk.slashProposalPda(setup.pool1.pool, slashEvidenceHash)
+    );
+    const escrowTokenAccount = setup.sdk.slashEscrowTokenVaultPda(slashEscrow);
+    const escrowUsdcAccount = setup.sdk.slashEscrowUsdcVaultPda(slashEscrow);
+
+    const [
+      destinationBalancePre,
+      destinationUsdcBalancePre,
+      escrowBalancePre,
+      escrowUsdcBalancePre,
+    ] = await Promise.all([
+      connection.getTokenAccountBalance(setup.slashingDestinationTokenAccount),
+      connection.getTokenAccountBalance(setup.slashingDestinationUsdcAccount),
+      connection.getTokenAccountBalance(escrowTokenAccount),
+      connection.getTokenAccountBalance(escrowUsdcAccount),
+    ]);
+
+    await program.methods
+      .releaseSlashEscrow()
+      .accountsStrict({
+        caller: setup.signer,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        slashEscrow,
+        escrowTokenAccount,
+        escrowUsdcAccount,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        mint: setup.tokenMint,
+        insuranceFund: null,
+        insuranceTokenAccount: null,
+        insuranceUsdcAccount: null,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.signerKp])
+      .rpc();
+
+    const [
+      destinationBalancePost,
+      destinationUsdcBalancePost,
+      slashEscrowPost,
+    ] = await Promise.all([
+      connection.getTokenAccountBalance(setup.slashingDestinationTokenAccount),
+      connection.getTokenAccountBalance(setup.slashingDestinationUsdcAccount),
+      program.account.slashEscrow.fetch(slashEscrow),
+    ]);
+
+    // With no split configured, the slashing destination receives everything.
+    assert(
+      new anchor.BN(destinationBalancePost.value.amount).eq(
+        new anchor.BN(destinationBalancePre.value.amount).add(
+          new anchor.BN(escrowBalancePre.value.amount)
+        )
+      ),
+      "Destination token account must receive the escrowed tokens"
+    );
+    assert(
+      new anchor.BN(destinationUsdcBalancePost.value.amount).eq(
+        new anchor.BN(destinationUsdcBalancePre.value.amount).add(
+          new anchor.BN(escrowUsdcBalancePre.value.amount)
+        )
+      ),
+      "Destination USDC account must receive the escrowed USDC"
+    );
+    assert.isNotNull(slashEscrowPost.releasedAt);
+  });
+
+  it("Halted pool can be unhalted", async () => {
+    // Set back to unhalted
+    await program.methods
+      .setHaltStatus({
+        isHalted: false,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
+      })
+      .accountsStrict({
+        authority: setup.haltingAuthorityKp.publicKey,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.haltingAuthorityKp])
+      .rpc();
+
+    const operatorPoolPost = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert.isNull(
+      operatorPoolPost.haltedAtTimestamp,
+      "OperatorPool must be unhalted"
+    );
+  });
+
+  it("Fail to withdraw Operator commission if global withdrawal is halted", async () => {
+    // Halt withdrawal
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
         isWithdrawalHalted: true,
       })
       .accountsStrict({
@@ -2907,6 +4027,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -2925,6 +4049,8 @@ describe("inference-staking program tests", () => {
           ),
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -2945,6 +4071,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -2963,6 +4093,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -2981,6 +4115,8 @@ describe("inference-staking program tests", () => {
           ),
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -3001,6 +4137,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -3028,6 +4168,8 @@ describe("inference-staking program tests", () => {
         destination: destinationTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -3076,6 +4218,8 @@ describe("inference-staking program tests", () => {
         destination: destinationUsdcAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -3114,6 +4258,8 @@ describe("inference-staking program tests", () => {
         operatorStakingRecord: setup.pool1.stakingRecord,
         newStakingRecord: setup.pool1.delegatorStakingRecord,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp, setup.delegator1Kp])
       .rpc();
@@ -3139,6 +4285,8 @@ describe("inference-staking program tests", () => {
         operatorStakingRecord: setup.pool1.delegatorStakingRecord,
         newStakingRecord: setup.pool1.stakingRecord,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -3165,7 +4313,10 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: setup.pool1.delegatorStakingRecord,
         operatorStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.delegator1Kp])
       .rpc();
@@ -3186,6 +4337,10 @@ describe("inference-staking program tests", () => {
           ownerStakingRecord: setup.pool1.delegatorStakingRecord,
           operatorPool: setup.pool1.pool,
           systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          poolOverview: setup.poolOverview,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -3208,6 +4363,10 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: delegator2StakingRecord,
         systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.delegator2Kp])
       .rpc();
@@ -3224,6 +4383,10 @@ describe("inference-staking program tests", () => {
         ownerStakingRecord: delegator2StakingRecord,
         operatorPool: setup.pool1.pool,
         systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.delegator2Kp])
       .rpc();
@@ -3241,6 +4404,9 @@ describe("inference-staking program tests", () => {
         admin: setup.pool1.admin,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -3264,6 +4430,9 @@ describe("inference-staking program tests", () => {
           admin: setup.pool1.admin,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool1.pool,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -3280,7 +4449,7 @@ describe("inference-staking program tests", () => {
         setup.delegator1
       );
       await program.methods
-        .stake({ tokenAmount: new anchor.BN(400_000) })
+        .stake({ tokenAmount: new anchor.BN(400_000), referrer: null })
         .accountsStrict({
           owner: setup.delegator1,
           poolOverview: setup.poolOverview,
@@ -3290,7 +4459,10 @@ describe("inference-staking program tests", () => {
           stakedTokenAccount: setup.pool1.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           ownerTokenAccount,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.delegator1Kp])
         .rpc();
@@ -3319,7 +4491,10 @@ describe("inference-staking program tests", () => {
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: setup.pool1.stakingRecord,
         operatorStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -3359,6 +4534,8 @@ describe("inference-staking program tests", () => {
         stakedTokenAccount: setup.pool1.stakedTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .rpc();
 
@@ -3417,7 +4594,13 @@ describe("inference-staking program tests", () => {
           setup.registrationFeePayoutTokenAccount,
         operatorUsdcVault: setup.pool3.poolUsdcVault,
         usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool3.admin])
+      )
       .signers([setup.payerKp, setup.pool3.adminKp])
       .rpc();
 
@@ -3501,7 +4684,13 @@ describe("inference-staking program tests", () => {
           setup.registrationFeePayoutTokenAccount,
         operatorUsdcVault: setup.pool4.poolUsdcVault,
         usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool4.admin])
+      )
       .signers([setup.payerKp, setup.pool4.adminKp])
       .rpc();
 
@@ -3555,6 +4744,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -3596,7 +4789,13 @@ describe("inference-staking program tests", () => {
           setup.registrationFeePayoutTokenAccount,
         operatorUsdcVault: setup.pool5.poolUsdcVault,
         usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool5.admin])
+      )
       .signers([setup.payerKp, setup.pool5.adminKp])
       .rpc();
 
@@ -3635,6 +4834,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -3680,7 +4883,13 @@ describe("inference-staking program tests", () => {
             setup.registrationFeePayoutTokenAccount,
           operatorUsdcVault: setup.pool6.poolUsdcVault,
           usdcMint: setup.usdcTokenMint,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
+        .remainingAccounts(
+          setup.sdk.getTombstoneRemainingAccounts([setup.pool6.admin])
+        )
         .signers([setup.payerKp, setup.pool6.adminKp])
         .rpc();
 
@@ -3723,6 +4932,10 @@ describe("inference-staking program tests", () => {
           newSlashingDestinationTokenAccount.publicKey,
         slashingDestinationUsdcAccount:
           newSlashingDestinationUsdcAccount.publicKey,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -3755,6 +4968,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
         slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -3774,6 +4991,10 @@ describe("inference-staking program tests", () => {
           registrationFeePayoutWallet: null,
           slashingDestinationTokenAccount: null,
           slashingDestinationUsdcAccount: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.poolOverviewAdminKp])
         .rpc();
@@ -3797,6 +5018,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -3822,12 +5047,16 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
   });
 
-  it("Slashing instruction with invalid destination accounts fails", async () => {
+  it("Releasing a slash escrow with invalid destination accounts fails", async () => {
     // Create invalid destination accounts (not matching pool overview configuration)
     const invalidDestinationWallet = Keypair.generate();
 
@@ -3852,12 +5081,19 @@ describe("inference-staking program tests", () => {
     await program.methods
       .setHaltStatus({
         isHalted: true,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
       })
       .accountsStrict({
         authority: setup.haltingAuthorityKp.publicKey,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool2.pool,
+        pendingAction: null,
+        adminAuditLog: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.haltingAuthorityKp])
       .rpc();
@@ -3865,28 +5101,99 @@ describe("inference-staking program tests", () => {
     // Wait for slashing delay
     await sleep(slashingDelaySeconds.toNumber() * 2 * 1_000);
 
+    // Slash into escrow, destinations are only used when the escrow is released
+    const evidenceHash = Array(32).fill(3);
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool2.pool,
+      evidenceHash
+    );
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
+    const escrowTokenAccount = setup.sdk.slashEscrowTokenVaultPda(slashEscrow);
+    const escrowUsdcAccount = setup.sdk.slashEscrowUsdcVaultPda(slashEscrow);
+
+    await program.methods
+      .createSlashProposal({
+        evidenceHash,
+        slashArgs: {
+          sharesAmount: new anchor.BN(1000),
+          poolSlashBps: 0,
+          unstakingSlashBps: 0,
+          usdcEarningsConfiscation: { all: {} },
+          rewardCommissionConfiscation: { all: {} },
+          usdcCommissionConfiscation: { all: {} },
+        },
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool2.pool,
+        slashProposal,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    await program.methods
+      .slashStake()
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool2.pool,
+        operatorStakingRecord: setup.pool2.stakingRecord,
+        slashProposal,
+        stakedTokenAccount: setup.pool2.stakedTokenAccount,
+        poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
+          setup.pool2.pool
+        ),
+        rewardFeeTokenAccount: setup.pool2.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool2.usdcCommissionFeeTokenVault,
+        slashEscrow,
+        escrowTokenAccount,
+        escrowUsdcAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
     try {
-      // Try to slash with invalid destination accounts
+      // Try to release with invalid destination accounts
       await program.methods
-        .slashStake({ sharesAmount: new anchor.BN(1000) })
+        .releaseSlashEscrow()
         .accountsStrict({
-          authority: setup.slashingAuthority,
+          caller: setup.signer,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool2.pool,
           operatorStakingRecord: setup.pool2.stakingRecord,
+          slashEscrow,
+          escrowTokenAccount,
+          escrowUsdcAccount,
           stakedTokenAccount: setup.pool2.stakedTokenAccount,
-          poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
-            setup.pool2.pool
-          ),
+          mint: setup.tokenMint,
+          insuranceFund: null,
+          insuranceTokenAccount: null,
+          insuranceUsdcAccount: null,
           slashingDestinationTokenAccount:
             invalidDestinationTokenAccount.address,
           slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
-          usdcFeeTokenAccount: setup.pool2.usdcCommissionFeeTokenVault,
-          rewardFeeTokenAccount: setup.pool2.rewardCommissionFeeTokenVault,
+          adminAuditLog: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.slashingAuthorityKp])
+        .signers([setup.signerKp])
         .rpc();
       assert(false, "Should fail with invalid token destination");
     } catch (error) {
@@ -3895,44 +5202,55 @@ describe("inference-staking program tests", () => {
     }
 
     try {
-      // Try to slash with invalid USDC destination account
+      // Try to release with invalid USDC destination account
       await program.methods
-        .slashStake({ sharesAmount: new anchor.BN(1000) })
+        .releaseSlashEscrow()
         .accountsStrict({
-          authority: setup.slashingAuthority,
+          caller: setup.signer,
           poolOverview: setup.poolOverview,
           operatorPool: setup.pool2.pool,
           operatorStakingRecord: setup.pool2.stakingRecord,
+          slashEscrow,
+          escrowTokenAccount,
+          escrowUsdcAccount,
           stakedTokenAccount: setup.pool2.stakedTokenAccount,
-          poolUsdcVault: setup.sdk.poolDelegatorUsdcEarningsVaultPda(
-            setup.pool2.pool
-          ),
+          mint: setup.tokenMint,
+          insuranceFund: null,
+          insuranceTokenAccount: null,
+          insuranceUsdcAccount: null,
           slashingDestinationTokenAccount:
             setup.slashingDestinationTokenAccount,
           slashingDestinationUsdcAccount: invalidDestinationUsdcAccount.address,
           tokenProgram: TOKEN_PROGRAM_ID,
-          usdcFeeTokenAccount: setup.pool2.usdcCommissionFeeTokenVault,
-          rewardFeeTokenAccount: setup.pool2.rewardCommissionFeeTokenVault,
+          adminAuditLog: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
-        .signers([setup.slashingAuthorityKp])
+        .signers([setup.signerKp])
         .rpc();
       assert(false, "Should fail with invalid USDC destination");
     } catch (error) {
       // This should fail with constraint violation
       assertError(error, "ConstraintAddress");
     }
-
     // Unhalt the pool after test
     await program.methods
       .setHaltStatus({
         isHalted: false,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
       })
       .accountsStrict({
         authority: setup.haltingAuthorityKp.publicKey,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool2.pool,
+        pendingAction: null,
+        adminAuditLog: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.haltingAuthorityKp])
       .rpc();
@@ -3951,6 +5269,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -3969,6 +5291,8 @@ describe("inference-staking program tests", () => {
           ),
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -3989,6 +5313,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -4007,6 +5335,10 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -4025,6 +5357,8 @@ describe("inference-staking program tests", () => {
           ),
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -4045,8 +5379,373 @@ describe("inference-staking program tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
   });
+
+  it("Slashing authority proposes a slash to tombstone OperatorPool keys", async () => {
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool5.pool,
+      tombstoneEvidenceHash
+    );
+    await program.methods
+      .createSlashProposal({
+        evidenceHash: tombstoneEvidenceHash,
+        slashArgs: {
+          sharesAmount: new anchor.BN(0),
+          poolSlashBps: 0,
+          unstakingSlashBps: 0,
+          usdcEarningsConfiscation: { none: {} },
+          rewardCommissionConfiscation: { none: {} },
+          usdcCommissionConfiscation: { none: {} },
+        },
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool5.pool,
+        slashProposal,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    const slashProposalAccount = await program.account.slashProposal.fetch(
+      slashProposal
+    );
+    assert(slashProposalAccount.operatorPool.equals(setup.pool5.pool));
+    assert.lengthOf(slashProposalAccount.approvals, 1);
+  });
+
+  it("Fail to tombstone an operator key with a slash proposal of another OperatorPool", async () => {
+    try {
+      await program.methods
+        .tombstoneOperatorKey({ key: setup.pool5.admin })
+        .accountsStrict({
+          payer: setup.payer,
+          authority: setup.slashingAuthority,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool5.pool,
+          slashProposal: setup.sdk.slashProposalPda(
+            setup.pool1.pool,
+            slashEvidenceHash
+          ),
+          tombstone: setup.sdk.tombstonePda(setup.pool5.admin),
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.slashingAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertError(error, "ConstraintSeeds");
+    }
+  });
+
+  it("Fail to tombstone an operator key with invalid authority", async () => {
+    try {
+      await program.methods
+        .tombstoneOperatorKey({ key: setup.pool5.admin })
+        .accountsStrict({
+          payer: setup.payer,
+          authority: setup.haltingAuthority,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool5.pool,
+          slashProposal: setup.sdk.slashProposalPda(
+            setup.pool5.pool,
+            tombstoneEvidenceHash
+          ),
+          tombstone: setup.sdk.tombstonePda(setup.pool5.admin),
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.haltingAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidSlashingAuthority");
+    }
+  });
+
+  it("Fail to tombstone a key that does not belong to the OperatorPool", async () => {
+    try {
+      await program.methods
+        .tombstoneOperatorKey({ key: setup.pool4.admin })
+        .accountsStrict({
+          payer: setup.payer,
+          authority: setup.slashingAuthority,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool5.pool,
+          slashProposal: setup.sdk.slashProposalPda(
+            setup.pool5.pool,
+            tombstoneEvidenceHash
+          ),
+          tombstone: setup.sdk.tombstonePda(setup.pool4.admin),
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.slashingAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidTombstoneKey");
+    }
+  });
+
+  it("Slashing authority can tombstone an operator auth key", async () => {
+    const authKey = tombstonedAuthKeyKp.publicKey;
+    await program.methods
+      .updateOperatorPool({
+        ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+        operatorAuthKeys: [authKey],
+      })
+      .accountsStrict({
+        admin: setup.pool5.admin,
+        operatorPool: setup.pool5.pool,
+        poolOverview: setup.poolOverview,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(setup.sdk.getTombstoneRemainingAccounts([authKey]))
+      .signers([setup.pool5.adminKp])
+      .rpc();
+
+    const operatorPoolPre = await program.account.operatorPool.fetch(
+      setup.pool5.pool
+    );
+    assert.isNull(operatorPoolPre.tombstonedAt);
+
+    const tombstone = setup.sdk.tombstonePda(authKey);
+    await program.methods
+      .tombstoneOperatorKey({ key: authKey })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool5.pool,
+        slashProposal: setup.sdk.slashProposalPda(
+          setup.pool5.pool,
+          tombstoneEvidenceHash
+        ),
+        tombstone,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    const tombstoneAccount = await program.account.tombstone.fetch(tombstone);
+    assert(tombstoneAccount.key.equals(authKey));
+    assert(tombstoneAccount.operatorPool.equals(setup.pool5.pool));
+    assert(tombstoneAccount.authority.equals(setup.slashingAuthority));
+    assert(
+      tombstoneAccount.slashProposal.equals(
+        setup.sdk.slashProposalPda(setup.pool5.pool, tombstoneEvidenceHash)
+      )
+    );
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool5.pool
+    );
+    assert(
+      operatorPool.tombstonedAt?.eq(tombstoneAccount.tombstonedAt),
+      "OperatorPool should be marked as tombstoned"
+    );
+
+    // A key can only be tombstoned once.
+    try {
+      await program.methods
+        .tombstoneOperatorKey({ key: authKey })
+        .accountsStrict({
+          payer: setup.payer,
+          authority: setup.slashingAuthority,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool5.pool,
+          slashProposal: setup.sdk.slashProposalPda(
+            setup.pool5.pool,
+            tombstoneEvidenceHash
+          ),
+          tombstone,
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.slashingAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertError(error, "already in use");
+    }
+  });
+
+  it("Fail to change the admin of a tombstoned OperatorPool", async () => {
+    try {
+      await program.methods
+        .changeOperatorAdmin()
+        .accountsStrict({
+          admin: setup.pool5.admin,
+          newAdmin: setup.signer,
+          operatorPool: setup.pool5.pool,
+          newAdminTombstone: setup.sdk.tombstonePda(setup.signer),
+          poolOverview: setup.poolOverview,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool5.adminKp, setup.signerKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "operatorPoolTombstoned");
+    }
+  });
+
+  it("Fail to hand a tombstoned key to another OperatorPool", async () => {
+    const tombstonedKey = tombstonedAuthKeyKp.publicKey;
+
+    try {
+      await program.methods
+        .changeOperatorAdmin()
+        .accountsStrict({
+          admin: setup.pool4.admin,
+          newAdmin: tombstonedKey,
+          operatorPool: setup.pool4.pool,
+          newAdminTombstone: setup.sdk.tombstonePda(tombstonedKey),
+          poolOverview: setup.poolOverview,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.pool4.adminKp, tombstonedAuthKeyKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "keyTombstoned");
+    }
+
+    try {
+      await program.methods
+        .updateOperatorPool({
+          ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+          operatorAuthKeys: [tombstonedKey],
+        })
+        .accountsStrict({
+          admin: setup.pool4.admin,
+          operatorPool: setup.pool4.pool,
+          poolOverview: setup.poolOverview,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .remainingAccounts(
+          setup.sdk.getTombstoneRemainingAccounts([tombstonedKey])
+        )
+        .signers([setup.pool4.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "keyTombstoned");
+    }
+  });
+
+  it("Fail to create an OperatorPool with a tombstoned or unchecked auth key", async () => {
+    const tombstonedKey = tombstonedAuthKeyKp.publicKey;
+
+    // Fund the registration fee, which is charged before the tombstone check.
+    await mintTo(
+      connection,
+      setup.payerKp,
+      setup.tokenMint,
+      setup.pool6.adminTokenAccount,
+      setup.tokenHolderKp,
+      BigInt(operatorPoolRegistrationFee.toString())
+    );
+
+    const createOperatorPool = (remainingAccounts: PublicKey[]) =>
+      program.methods
+        .createOperatorPool({
+          autoStakeFees,
+          rewardCommissionRateBps,
+          usdcCommissionRateBps,
+          allowDelegation,
+          name: setup.pool6.name,
+          description: setup.pool6.description,
+          websiteUrl: setup.pool6.websiteUrl,
+          avatarImageUrl: setup.pool6.avatarImageUrl,
+          operatorAuthKeys: [tombstonedKey],
+        })
+        .accountsStrict({
+          payer: setup.payer,
+          admin: setup.pool6.admin,
+          operatorPool: setup.pool6.pool,
+          stakingRecord: setup.pool6.stakingRecord,
+          stakedTokenAccount: setup.pool6.stakedTokenAccount,
+          rewardFeeTokenAccount: setup.pool6.rewardCommissionFeeTokenVault,
+          poolOverview: setup.poolOverview,
+          mint: setup.tokenMint,
+          usdcFeeTokenAccount: setup.pool6.usdcCommissionFeeTokenVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          adminTokenAccount: setup.pool6.adminTokenAccount,
+          registrationFeePayoutTokenAccount:
+            setup.registrationFeePayoutTokenAccount,
+          operatorUsdcVault: setup.pool6.poolUsdcVault,
+          usdcMint: setup.usdcTokenMint,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .remainingAccounts(
+          setup.sdk.getTombstoneRemainingAccounts(remainingAccounts)
+        )
+        .signers([setup.payerKp, setup.pool6.adminKp])
+        .rpc();
+
+    // Tombstone PDA of the auth key is missing.
+    try {
+      await createOperatorPool([setup.pool6.admin]);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidTombstoneAccount");
+    }
+
+    // Tombstone PDAs are passed in the wrong order.
+    try {
+      await createOperatorPool([tombstonedKey, setup.pool6.admin]);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidTombstoneAccount");
+    }
+
+    try {
+      await createOperatorPool([setup.pool6.admin, tombstonedKey]);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "keyTombstoned");
+    }
+  });
 });
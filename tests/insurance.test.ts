@@ -0,0 +1,975 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { SystemProgram, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { assert } from "chai";
+
+import type { InferenceStaking } from "@sdk/src/idl";
+
+import type { ConstructMerkleTreeInput } from "@tests/lib/merkle";
+import { MerkleUtils } from "@tests/lib/merkle";
+import type { SetupTestResult } from "@tests/lib/setup";
+import { setupTests } from "@tests/lib/setup";
+import {
+  assertError,
+  assertStakingProgramError,
+  handleMarkEpochAsFinalizing,
+  sleep,
+} from "@tests/lib/utils";
+
+describe("Insurance fund tests", () => {
+  let setup: SetupTestResult;
+  let connection: Connection;
+  let program: anchor.Program<InferenceStaking>;
+
+  const insuranceCommissionBps = 2_000;
+  const insuranceSlashBps = 5_000;
+  const slashingDelaySeconds = new anchor.BN(3);
+  const operatorStake = new anchor.BN(100_000);
+  const delegatorStake = new anchor.BN(300_000);
+  const slashEvidenceHash = Array(32).fill(1);
+  const claimEvidenceHash = Array(32).fill(2);
+
+  let insuranceFund: PublicKey;
+  let insuranceTokenAccount: PublicKey;
+  let insuranceUsdcAccount: PublicKey;
+  let delegatorTokenAccount: PublicKey;
+  let delegatorUsdcAccount: PublicKey;
+  let expectedInsuranceRewards: bigint;
+  let expectedInsuranceUsdc: bigint;
+
+  const createInsuranceClaim = async ({
+    claimantKp,
+    stakingRecord,
+    tokenAmount,
+    usdcAmount,
+  }: {
+    claimantKp: Keypair;
+    stakingRecord: PublicKey;
+    tokenAmount: anchor.BN;
+    usdcAmount: anchor.BN;
+  }) => {
+    const { claimCount } = await program.account.insuranceFund.fetch(
+      insuranceFund
+    );
+    const insuranceClaim = setup.sdk.insuranceClaimPda(claimCount);
+
+    await program.methods
+      .createInsuranceClaim({
+        evidenceHash: claimEvidenceHash,
+        tokenAmount,
+        usdcAmount,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        claimant: claimantKp.publicKey,
+        poolOverview: setup.poolOverview,
+        insuranceFund,
+        operatorPool: setup.pool1.pool,
+        stakingRecord,
+        insuranceClaim,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, claimantKp])
+      .rpc();
+
+    return insuranceClaim;
+  };
+
+  const approveInsuranceClaim = async (
+    insuranceClaim: PublicKey,
+    tokenAmount: anchor.BN,
+    usdcAmount: anchor.BN,
+    adminKp = setup.poolOverviewAdminKp
+  ) => {
+    await program.methods
+      .approveInsuranceClaim({ tokenAmount, usdcAmount })
+      .accountsStrict({
+        programAdmin: adminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        insuranceClaim,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([adminKp])
+      .rpc();
+  };
+
+  const claimInsurance = async (
+    insuranceClaim: PublicKey,
+    claimantKp: Keypair
+  ) => {
+    await program.methods
+      .claimInsurance()
+      .accountsStrict({
+        claimant: claimantKp.publicKey,
+        poolOverview: setup.poolOverview,
+        insuranceFund,
+        insuranceClaim,
+        insuranceTokenAccount,
+        insuranceUsdcAccount,
+        tokenDestination: delegatorTokenAccount,
+        usdcDestination: delegatorUsdcAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([claimantKp])
+      .rpc();
+  };
+
+  const releaseSlashEscrow = async (
+    slashEscrow: PublicKey,
+    withInsuranceFund: boolean
+  ) => {
+    await program.methods
+      .releaseSlashEscrow()
+      .accountsStrict({
+        caller: setup.signer,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        slashEscrow,
+        escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+        escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        mint: setup.tokenMint,
+        insuranceFund: withInsuranceFund ? insuranceFund : null,
+        insuranceTokenAccount: withInsuranceFund
+          ? insuranceTokenAccount
+          : null,
+        insuranceUsdcAccount: withInsuranceFund ? insuranceUsdcAccount : null,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.signerKp])
+      .rpc();
+  };
+
+  before(async () => {
+    setup = await setupTests();
+    program = setup.sdk.program;
+    connection = program.provider.connection;
+
+    insuranceFund = setup.sdk.insuranceFundPda();
+    insuranceTokenAccount = setup.sdk.insuranceTokenVaultPda();
+    insuranceUsdcAccount = setup.sdk.insuranceUsdcVaultPda();
+
+    await program.methods
+      .createPoolOverview()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+        newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
+        newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        allowPoolCreation: true,
+        minOperatorTokenStake: new anchor.BN(0),
+        delegatorUnstakeDelaySeconds: new anchor.BN(8),
+        operatorUnstakeDelaySeconds: new anchor.BN(20),
+        operatorPoolRegistrationFee: new anchor.BN(1_000),
+        slashingDelaySeconds,
+        insuranceCommissionBps,
+        insuranceSlashBps,
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    // Create the first RewardRecord before OperatorPool 1 joins, so that the
+    // pool starts accruing rewards from epoch 2.
+    await handleMarkEpochAsFinalizing({ program, setup });
+    await program.methods
+      .createRewardRecord({
+        merkleRoots: [],
+        totalRewards: new anchor.BN(0),
+        totalUsdcPayout: new anchor.BN(0),
+        rewardMintPayouts: [],
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[1],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+
+    await program.methods
+      .createOperatorPool({
+        autoStakeFees: false,
+        rewardCommissionRateBps: 1_000,
+        usdcCommissionRateBps: 1_000,
+        allowDelegation: true,
+        name: setup.pool1.name,
+        description: setup.pool1.description,
+        websiteUrl: setup.pool1.websiteUrl,
+        avatarImageUrl: setup.pool1.avatarImageUrl,
+        operatorAuthKeys: null,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        stakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        poolOverview: setup.poolOverview,
+        mint: setup.tokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        adminTokenAccount: setup.pool1.adminTokenAccount,
+        registrationFeePayoutTokenAccount:
+          setup.registrationFeePayoutTokenAccount,
+        operatorUsdcVault: setup.pool1.poolUsdcVault,
+        usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+      )
+      .signers([setup.payerKp, setup.pool1.adminKp])
+      .rpc();
+
+    await program.methods
+      .stake({ tokenAmount: operatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.pool1.admin,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.stakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: setup.pool1.adminTokenAccount,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.pool1.adminKp])
+      .rpc();
+
+    await program.methods
+      .createStakingRecord()
+      .accountsStrict({
+        payer: setup.payer,
+        owner: setup.delegator1,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.delegator1Kp])
+      .rpc();
+
+    delegatorTokenAccount = getAssociatedTokenAddressSync(
+      setup.tokenMint,
+      setup.delegator1
+    );
+    await program.methods
+      .stake({ tokenAmount: delegatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.delegator1,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: delegatorTokenAccount,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.delegator1Kp])
+      .rpc();
+
+    const usdcAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      setup.payerKp,
+      setup.usdcTokenMint,
+      setup.delegator1
+    );
+    delegatorUsdcAccount = usdcAccount.address;
+  });
+
+  it("Fail to create the InsuranceFund with invalid admin", async () => {
+    try {
+      await program.methods
+        .createInsuranceFund()
+        .accountsStrict({
+          payer: setup.payer,
+          programAdmin: setup.pool1.admin,
+          poolOverview: setup.poolOverview,
+          insuranceFund,
+          insuranceTokenAccount,
+          insuranceUsdcAccount,
+          mint: setup.tokenMint,
+          usdcMint: setup.usdcTokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.pool1.adminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidProgramAdmin");
+    }
+  });
+
+  it("Program admin creates the InsuranceFund", async () => {
+    await program.methods
+      .createInsuranceFund()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        insuranceFund,
+        insuranceTokenAccount,
+        insuranceUsdcAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    const [fund, tokenAccount, usdcAccount] = await Promise.all([
+      program.account.insuranceFund.fetch(insuranceFund),
+      getAccount(connection, insuranceTokenAccount),
+      getAccount(connection, insuranceUsdcAccount),
+    ]);
+    assert(fund.totalTokenDeposited.isZero());
+    assert(fund.totalUsdcDeposited.isZero());
+    assert(fund.claimCount.isZero());
+    assert(tokenAccount.mint.equals(setup.tokenMint));
+    assert(tokenAccount.owner.equals(setup.poolOverview));
+    assert(usdcAccount.mint.equals(setup.usdcTokenMint));
+    assert(usdcAccount.owner.equals(setup.poolOverview));
+  });
+
+  it("Fail to deposit insurance contributions when none are pending", async () => {
+    try {
+      await program.methods
+        .depositInsuranceContributions()
+        .accountsStrict({
+          poolOverview: setup.poolOverview,
+          insuranceFund,
+          rewardTokenAccount: setup.rewardTokenAccount,
+          usdcTokenAccount: setup.usdcTokenAccount,
+          insuranceTokenAccount,
+          insuranceUsdcAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "noPendingInsuranceContributions");
+    }
+  });
+
+  it("Accruing rewards sets aside the insurance share of commission", async () => {
+    const rewardsInput: ConstructMerkleTreeInput[] = setup.rewardEpochs[2];
+    const merkleTree = MerkleUtils.constructMerkleTree(rewardsInput);
+    const totalRewards = rewardsInput.reduce(
+      (acc, curr) => acc + curr.tokenAmount,
+      0n
+    );
+    const totalUsdc = rewardsInput.reduce(
+      (acc, curr) => acc + curr.usdcAmount,
+      0n
+    );
+    await Promise.all([
+      mintTo(
+        connection,
+        setup.payerKp,
+        setup.tokenMint,
+        setup.rewardTokenAccount,
+        setup.tokenHolderKp,
+        totalRewards
+      ),
+      mintTo(
+        connection,
+        setup.payerKp,
+        setup.usdcTokenMint,
+        setup.usdcTokenAccount,
+        setup.tokenHolderKp,
+        totalUsdc
+      ),
+    ]);
+
+    await handleMarkEpochAsFinalizing({ program, setup });
+    await program.methods
+      .createRewardRecord({
+        merkleRoots: [Array.from(MerkleUtils.getTreeRoot(merkleTree))],
+        totalRewards: new anchor.BN(totalRewards.toString()),
+        totalUsdcPayout: new anchor.BN(totalUsdc.toString()),
+        rewardMintPayouts: [],
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[2],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+
+    const nodeIndex = rewardsInput.findIndex(
+      (x) => x.address == setup.pool1.pool.toString()
+    );
+    const proofInputs = rewardsInput[nodeIndex];
+    assert(proofInputs != null);
+    const { proof, proofPath } = MerkleUtils.generateMerkleProof({
+      ...proofInputs,
+      index: nodeIndex,
+      merkleTree,
+    });
+
+    const operatorPoolPre = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+
+    await program.methods
+      .accrueReward({
+        merkleIndex: 0,
+        proof: proof.map((arr) => Array.from(arr)),
+        proofPath,
+        rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
+        usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+        rewardMintAmounts: [],
+      })
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[2],
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+    // Mirrors PoolOverview::calc_insurance_commission on the total commission.
+    const calcInsuranceCommission = (amount: bigint, rateBps: number) => {
+      const commission = (amount * BigInt(rateBps)) / 10_000n;
+      return (commission * BigInt(insuranceCommissionBps)) / 10_000n;
+    };
+    expectedInsuranceRewards = calcInsuranceCommission(
+      proofInputs.tokenAmount,
+      operatorPoolPre.rewardCommissionRateBps
+    );
+    expectedInsuranceUsdc = calcInsuranceCommission(
+      proofInputs.usdcAmount,
+      operatorPoolPre.usdcCommissionRateBps
+    );
+    assert(expectedInsuranceRewards > 0n);
+    assert(expectedInsuranceUsdc > 0n);
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.equal(
+      BigInt(poolOverview.pendingInsuranceRewards.toString()),
+      expectedInsuranceRewards
+    );
+    assert.equal(
+      BigInt(poolOverview.pendingInsuranceUsdc.toString()),
+      expectedInsuranceUsdc
+    );
+  });
+
+  it("Anyone can deposit insurance contributions into the InsuranceFund", async () => {
+    const poolOverviewPre = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+
+    await program.methods
+      .depositInsuranceContributions()
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        insuranceFund,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        insuranceTokenAccount,
+        insuranceUsdcAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+    const [poolOverview, fund, tokenAccount, usdcAccount] = await Promise.all([
+      program.account.poolOverview.fetch(setup.poolOverview),
+      program.account.insuranceFund.fetch(insuranceFund),
+      getAccount(connection, insuranceTokenAccount),
+      getAccount(connection, insuranceUsdcAccount),
+    ]);
+    assert.equal(tokenAccount.amount, expectedInsuranceRewards);
+    assert.equal(usdcAccount.amount, expectedInsuranceUsdc);
+    assert.equal(
+      BigInt(fund.totalTokenDeposited.toString()),
+      expectedInsuranceRewards
+    );
+    assert.equal(
+      BigInt(fund.totalUsdcDeposited.toString()),
+      expectedInsuranceUsdc
+    );
+    assert(poolOverview.pendingInsuranceRewards.isZero());
+    assert(poolOverview.pendingInsuranceUsdc.isZero());
+    assert.equal(
+      BigInt(poolOverviewPre.unclaimedRewards.toString()) -
+        BigInt(poolOverview.unclaimedRewards.toString()),
+      expectedInsuranceRewards
+    );
+    assert.equal(
+      BigInt(poolOverviewPre.unclaimedUsdc.toString()) -
+        BigInt(poolOverview.unclaimedUsdc.toString()),
+      expectedInsuranceUsdc
+    );
+  });
+
+  it("Fail to file an insurance claim as the Operator", async () => {
+    try {
+      await createInsuranceClaim({
+        claimantKp: setup.pool1.adminKp,
+        stakingRecord: setup.pool1.stakingRecord,
+        tokenAmount: new anchor.BN(1),
+        usdcAmount: new anchor.BN(0),
+      });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "operatorInsuranceClaim");
+    }
+  });
+
+  it("Fail to file an insurance claim without an amount", async () => {
+    try {
+      await createInsuranceClaim({
+        claimantKp: setup.delegator1Kp,
+        stakingRecord: setup.pool1.delegatorStakingRecord,
+        tokenAmount: new anchor.BN(0),
+        usdcAmount: new anchor.BN(0),
+      });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidAmount");
+    }
+  });
+
+  it("Delegator files an insurance claim", async () => {
+    const tokenAmount = new anchor.BN(
+      (expectedInsuranceRewards * 2n).toString()
+    );
+    const usdcAmount = new anchor.BN(expectedInsuranceUsdc.toString());
+    const insuranceClaim = await createInsuranceClaim({
+      claimantKp: setup.delegator1Kp,
+      stakingRecord: setup.pool1.delegatorStakingRecord,
+      tokenAmount,
+      usdcAmount,
+    });
+
+    const [claim, fund] = await Promise.all([
+      program.account.insuranceClaim.fetch(insuranceClaim),
+      program.account.insuranceFund.fetch(insuranceFund),
+    ]);
+    assert(claim.id.isZero());
+    assert(claim.claimant.equals(setup.delegator1));
+    assert(claim.operatorPool.equals(setup.pool1.pool));
+    assert(claim.stakingRecord.equals(setup.pool1.delegatorStakingRecord));
+    assert.deepEqual(claim.evidenceHash, claimEvidenceHash);
+    assert(claim.requestedTokenAmount.eq(tokenAmount));
+    assert(claim.requestedUsdcAmount.eq(usdcAmount));
+    assert.isNull(claim.approvedAt);
+    assert.isNull(claim.paidAt);
+    assert(fund.claimCount.eqn(1));
+  });
+
+  it("Fail to claim insurance before the claim is approved", async () => {
+    try {
+      await claimInsurance(
+        setup.sdk.insuranceClaimPda(new anchor.BN(0)),
+        setup.delegator1Kp
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "insuranceClaimNotApproved");
+    }
+  });
+
+  it("Fail to approve an insurance claim with invalid admin", async () => {
+    try {
+      await approveInsuranceClaim(
+        setup.sdk.insuranceClaimPda(new anchor.BN(0)),
+        new anchor.BN(1),
+        new anchor.BN(1),
+        setup.pool1.adminKp
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidProgramAdmin");
+    }
+  });
+
+  it("Fail to approve more than the requested amount", async () => {
+    const insuranceClaim = setup.sdk.insuranceClaimPda(new anchor.BN(0));
+    const claim = await program.account.insuranceClaim.fetch(insuranceClaim);
+
+    for (const [tokenAmount, usdcAmount] of [
+      [claim.requestedTokenAmount.addn(1), claim.requestedUsdcAmount],
+      [claim.requestedTokenAmount, claim.requestedUsdcAmount.addn(1)],
+    ] as const) {
+      try {
+        await approveInsuranceClaim(insuranceClaim, tokenAmount, usdcAmount);
+        assert(false);
+      } catch (error) {
+        assertStakingProgramError(error, "insuranceClaimAmountExceeded");
+      }
+    }
+  });
+
+  it("Program admin approves part of an insurance claim", async () => {
+    const insuranceClaim = setup.sdk.insuranceClaimPda(new anchor.BN(0));
+    const tokenAmount = new anchor.BN(expectedInsuranceRewards.toString());
+    const usdcAmount = new anchor.BN((expectedInsuranceUsdc / 2n).toString());
+    await approveInsuranceClaim(insuranceClaim, tokenAmount, usdcAmount);
+
+    const claim = await program.account.insuranceClaim.fetch(insuranceClaim);
+    assert(claim.approvedTokenAmount.eq(tokenAmount));
+    assert(claim.approvedUsdcAmount.eq(usdcAmount));
+    assert.isNotNull(claim.approvedAt);
+
+    try {
+      await approveInsuranceClaim(insuranceClaim, tokenAmount, usdcAmount);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "insuranceClaimAlreadyApproved");
+    }
+  });
+
+  it("Fail to claim insurance of another claimant", async () => {
+    try {
+      await claimInsurance(
+        setup.sdk.insuranceClaimPda(new anchor.BN(0)),
+        setup.pool1.adminKp
+      );
+      assert(false);
+    } catch (error) {
+      assertError(error, "ConstraintHasOne");
+    }
+  });
+
+  it("Delegator claims an approved insurance claim", async () => {
+    const insuranceClaim = setup.sdk.insuranceClaimPda(new anchor.BN(0));
+    const [claim, tokenDestinationPre, usdcDestinationPre] = await Promise.all(
+      [
+        program.account.insuranceClaim.fetch(insuranceClaim),
+        getAccount(connection, delegatorTokenAccount),
+        getAccount(connection, delegatorUsdcAccount),
+      ]
+    );
+
+    await claimInsurance(insuranceClaim, setup.delegator1Kp);
+
+    const [
+      claimPost,
+      fund,
+      tokenDestination,
+      usdcDestination,
+      tokenAccount,
+      usdcAccount,
+    ] = await Promise.all([
+      program.account.insuranceClaim.fetch(insuranceClaim),
+      program.account.insuranceFund.fetch(insuranceFund),
+      getAccount(connection, delegatorTokenAccount),
+      getAccount(connection, delegatorUsdcAccount),
+      getAccount(connection, insuranceTokenAccount),
+      getAccount(connection, insuranceUsdcAccount),
+    ]);
+    const tokenAmount = BigInt(claim.approvedTokenAmount.toString());
+    const usdcAmount = BigInt(claim.approvedUsdcAmount.toString());
+    assert.equal(
+      tokenDestination.amount,
+      tokenDestinationPre.amount + tokenAmount
+    );
+    assert.equal(
+      usdcDestination.amount,
+      usdcDestinationPre.amount + usdcAmount
+    );
+    assert.equal(tokenAccount.amount, expectedInsuranceRewards - tokenAmount);
+    assert.equal(usdcAccount.amount, expectedInsuranceUsdc - usdcAmount);
+    assert(fund.totalTokenPaid.eq(claim.approvedTokenAmount));
+    assert(fund.totalUsdcPaid.eq(claim.approvedUsdcAmount));
+    assert.isNotNull(claimPost.paidAt);
+
+    try {
+      await claimInsurance(insuranceClaim, setup.delegator1Kp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "insuranceClaimAlreadyPaid");
+    }
+  });
+
+  it("Fail to claim more than the InsuranceFund balance", async () => {
+    const insuranceClaim = await createInsuranceClaim({
+      claimantKp: setup.delegator1Kp,
+      stakingRecord: setup.pool1.delegatorStakingRecord,
+      tokenAmount: new anchor.BN(expectedInsuranceRewards.toString()),
+      usdcAmount: new anchor.BN(0),
+    });
+    await approveInsuranceClaim(
+      insuranceClaim,
+      new anchor.BN(expectedInsuranceRewards.toString()),
+      new anchor.BN(0)
+    );
+
+    try {
+      await claimInsurance(insuranceClaim, setup.delegator1Kp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "insufficientInsuranceFundBalance");
+    }
+  });
+
+  it("Fail to release a slash escrow with an insurance share without the InsuranceFund", async () => {
+    await program.methods
+      .setHaltStatus({
+        isHalted: true,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
+      })
+      .accountsStrict({
+        authority: setup.haltingAuthorityKp.publicKey,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.haltingAuthorityKp])
+      .rpc();
+    await sleep(slashingDelaySeconds.toNumber() * 2 * 1_000);
+
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      slashEvidenceHash
+    );
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
+    await program.methods
+      .createSlashProposal({
+        evidenceHash: slashEvidenceHash,
+        slashArgs: {
+          sharesAmount: new anchor.BN(10_000),
+          poolSlashBps: 0,
+          unstakingSlashBps: 0,
+          usdcEarningsConfiscation: { none: {} },
+          rewardCommissionConfiscation: { none: {} },
+          usdcCommissionConfiscation: { none: {} },
+        },
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        slashProposal,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+    await program.methods
+      .slashStake()
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        slashProposal,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        slashEscrow,
+        escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+        escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    const escrow = await program.account.slashEscrow.fetch(slashEscrow);
+    assert(
+      escrow.insuranceTokenAmount.eq(
+        escrow.tokenAmountSlashed.muln(insuranceSlashBps).divn(10_000)
+      )
+    );
+
+    try {
+      await releaseSlashEscrow(slashEscrow, false);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "insuranceFundRequired");
+    }
+  });
+
+  it("Releasing a slash escrow deposits the insurance share into the InsuranceFund", async () => {
+    const slashEscrow = setup.sdk.slashEscrowPda(
+      setup.sdk.slashProposalPda(setup.pool1.pool, slashEvidenceHash)
+    );
+    const [escrow, fundPre, tokenAccountPre, destinationPre] =
+      await Promise.all([
+        program.account.slashEscrow.fetch(slashEscrow),
+        program.account.insuranceFund.fetch(insuranceFund),
+        getAccount(connection, insuranceTokenAccount),
+        getAccount(connection, setup.slashingDestinationTokenAccount),
+      ]);
+
+    await releaseSlashEscrow(slashEscrow, true);
+
+    const [fund, tokenAccount, destination] = await Promise.all([
+      program.account.insuranceFund.fetch(insuranceFund),
+      getAccount(connection, insuranceTokenAccount),
+      getAccount(connection, setup.slashingDestinationTokenAccount),
+    ]);
+    const insuranceTokenAmount = BigInt(escrow.insuranceTokenAmount.toString());
+    assert.equal(
+      tokenAccount.amount,
+      tokenAccountPre.amount + insuranceTokenAmount
+    );
+    assert(
+      fund.totalTokenDeposited.eq(
+        fundPre.totalTokenDeposited.add(escrow.insuranceTokenAmount)
+      )
+    );
+    assert.equal(
+      destination.amount,
+      destinationPre.amount +
+        BigInt(escrow.tokenAmountSlashed.toString()) -
+        insuranceTokenAmount
+    );
+  });
+});
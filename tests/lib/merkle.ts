@@ -104,7 +104,10 @@ function fillAddresses(addresses: ConstructMerkleTreeInput[]): void {
 
 function formatLeaf(input: ConstructMerkleTreeInput): string {
   const { address, tokenAmount: tokenAmount, usdcAmount } = input;
-  return `${address},${tokenAmount},${usdcAmount}`;
+  // Additional reward mint amounts are appended in registry order, so leaves
+  // without additional reward mints keep their original format.
+  const rewardMintAmounts = input.rewardMintAmounts ?? [];
+  return [address, tokenAmount, usdcAmount, ...rewardMintAmounts].join(",");
 }
 
 // Token and USDC amounts are included here for simplicity even though they are
@@ -115,6 +118,7 @@ export type ConstructMerkleTreeInput = {
   address: string;
   tokenAmount: bigint;
   usdcAmount: bigint;
+  rewardMintAmounts?: bigint[];
 };
 
 function constructMerkleTree(
@@ -191,6 +195,7 @@ export type GenerateMerkleProofInput = {
   address: string;
   tokenAmount: bigint;
   usdcAmount: bigint;
+  rewardMintAmounts?: bigint[];
   index: number;
   merkleTree: Uint8Array[][];
   skipChecksForTests?: boolean;
@@ -208,6 +213,7 @@ function generateMerkleProof({
   address,
   tokenAmount,
   usdcAmount,
+  rewardMintAmounts,
   index,
   merkleTree,
   // This allows us to construct deliberately invalid proofs for testing purposes.
@@ -217,7 +223,12 @@ function generateMerkleProof({
     throw new Error(`Index is negative, received: ${index}`);
   }
 
-  const hash = hashLeafNode({ address, tokenAmount, usdcAmount });
+  const hash = hashLeafNode({
+    address,
+    tokenAmount,
+    usdcAmount,
+    rewardMintAmounts,
+  });
 
   // Verify that leaf node matches expected hash.
   const leaf = merkleTree[0]?.[index];
@@ -225,6 +225,7 @@ export async function setupTests() {
   );
 
   const poolOverview = sdk.poolOverviewPda();
+  const eventAuthority = sdk.eventAuthorityPda();
   const rewardTokenAccount = sdk.globalTokenRewardVaultPda();
   const usdcTokenAccount = sdk.globalUsdcEarningsVaultPda();
   const operatorPool1 = sdk.operatorPoolPda(admin1Kp.publicKey);
@@ -409,6 +410,7 @@ export async function setupTests() {
     delegatorKeypairs,
     tokenMint,
     poolOverview,
+    eventAuthority,
     pool1,
     pool2,
     pool3,
@@ -11,6 +11,8 @@ import type {
 } from "@solana/web3.js";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { Keypair } from "@solana/web3.js";
+import { SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { VersionedTransaction } from "@solana/web3.js";
 import { assert } from "chai";
 import { Pool } from "pg";
 
@@ -255,6 +257,9 @@ export const handleMarkEpochAsFinalizing = async ({
     .accountsStrict({
       poolOverview: setup.poolOverview,
       authority: setup.rewardDistributionAuthority,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      eventAuthority: setup.eventAuthority,
+      program: program.programId,
     })
     .signers([setup.rewardDistributionAuthorityKp])
     .rpc();
@@ -290,6 +295,10 @@ export const setStakingHalted = async ({
       registrationFeePayoutWallet: null,
       slashingDestinationTokenAccount: null,
       slashingDestinationUsdcAccount: null,
+      adminAuditLog: null,
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      eventAuthority: setup.eventAuthority,
+      program: program.programId,
     })
     .signers([setup.poolOverviewAdminKp])
     .rpc();
@@ -431,6 +440,30 @@ export const convertToTokenUnitAmount = (amount: number) => {
   return new anchor.BN(amount).mul(new anchor.BN(10 ** 9));
 };
 
+/**
+ * Fetches a confirmed transaction and decodes it, including the events emitted
+ * via self-CPI in its inner instructions.
+ */
+export const decodeConfirmedTransaction = async (
+  setup: SetupTestResult,
+  signature: string
+) => {
+  const connection = setup.sdk.program.provider.connection;
+  await confirmTransaction(connection, signature);
+  const tx = await connection.getTransaction(signature, {
+    maxSupportedTransactionVersion: 0,
+    commitment: "confirmed",
+  });
+  assert(tx != null, "Transaction must be confirmed");
+
+  return setup.sdk.handleDecodeTransaction({
+    tx: new VersionedTransaction(tx.transaction.message),
+    logs: tx.meta?.logMessages ?? [],
+    innerInstructions: tx.meta?.innerInstructions ?? [],
+    version: "v2",
+  });
+};
+
 export const saveTransactionReceiptForDebugging = async (
   connection: Connection,
   signature: string
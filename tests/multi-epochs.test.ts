@@ -243,6 +243,7 @@ async function handleAccrueRewardForEpochs({
           proofPath,
           rewardAmount,
           usdcAmount,
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolOverview: setup.poolOverview,
@@ -257,6 +258,8 @@ async function handleAccrueRewardForEpochs({
           poolUsdcVault: pool.poolUsdcVault,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
 
@@ -287,9 +290,9 @@ async function handleAccrueRewardForEpochs({
         operatorPool.totalUnstaking.eq(operatorPre.totalUnstaking),
         "Total unstaking should remain unchanged"
       );
-      assert.isNull(
-        operatorPool.newRewardCommissionRateBps,
-        "New commission rate should be null"
+      assert.isEmpty(
+        operatorPool.rewardCommissionRateSchedule,
+        "No commission rates should be scheduled"
       );
 
       // Verify that operator's shares remain unchanged with auto-stake disabled.
@@ -586,6 +589,7 @@ describe("multi-epoch lifecycle tests", () => {
           merkleRoots,
           totalRewards,
           totalUsdcPayout: totalUsdcAmount,
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -595,6 +599,11 @@ describe("multi-epoch lifecycle tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -663,7 +672,7 @@ describe("multi-epoch lifecycle tests", () => {
       ]);
 
       await program.methods
-        .stake({ tokenAmount: stakeAmount })
+        .stake({ tokenAmount: stakeAmount, referrer: null })
         .accountsStrict({
           owner: pool.admin,
           poolOverview: setup.poolOverview,
@@ -673,7 +682,10 @@ describe("multi-epoch lifecycle tests", () => {
           stakedTokenAccount: pool.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           ownerTokenAccount: ownerTokenAccount.address,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([pool.adminKp])
         .rpc();
@@ -732,6 +744,10 @@ describe("multi-epoch lifecycle tests", () => {
             operatorPool: pool.pool,
             ownerStakingRecord: stakingRecord,
             systemProgram: SystemProgram.programId,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            poolOverview: setup.poolOverview,
+            eventAuthority: setup.eventAuthority,
+            program: program.programId,
           })
           .signers([setup.payerKp, delegatorKp])
           .rpc();
@@ -769,7 +785,7 @@ describe("multi-epoch lifecycle tests", () => {
       );
 
       await program.methods
-        .stake({ tokenAmount: stakeAmount })
+        .stake({ tokenAmount: stakeAmount, referrer: null })
         .accountsStrict({
           owner: delegatorKp.publicKey,
           poolOverview: setup.poolOverview,
@@ -779,7 +795,10 @@ describe("multi-epoch lifecycle tests", () => {
           stakedTokenAccount: pool.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           ownerTokenAccount: ownerTokenAccount.address,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([delegatorKp])
         .rpc();
@@ -836,6 +855,9 @@ describe("multi-epoch lifecycle tests", () => {
         slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
         systemProgram: SystemProgram.programId,
         registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.poolOverviewAdminKp])
       .rpc();
@@ -860,6 +882,7 @@ describe("multi-epoch lifecycle tests", () => {
   it("Update PoolOverview successfully", async () => {
     await program.methods
       .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
         isStakingHalted,
         isWithdrawalHalted,
         isAccrueRewardHalted,
@@ -876,6 +899,10 @@ describe("multi-epoch lifecycle tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -910,11 +937,19 @@ describe("multi-epoch lifecycle tests", () => {
         ],
         newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
         newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
       })
       .accountsStrict({
-        newProgramAdmin: null,
+        payer: setup.poolOverviewAdmin,
         programAdmin: setup.poolOverviewAdmin,
         poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -988,7 +1023,13 @@ describe("multi-epoch lifecycle tests", () => {
             pool.pool
           ),
           usdcMint: setup.usdcTokenMint,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
+        .remainingAccounts(
+          setup.sdk.getTombstoneRemainingAccounts([pool.admin])
+        )
         .signers([setup.payerKp, pool.adminKp])
         .rpc();
 
@@ -1005,7 +1046,8 @@ describe("multi-epoch lifecycle tests", () => {
         operatorPool.usdcCommissionRateBps,
         pool.usdcCommissionRateBps
       );
-      assert.isNull(operatorPool.newRewardCommissionRateBps);
+      assert.isEmpty(operatorPool.rewardCommissionRateSchedule);
+      assert.isEmpty(operatorPool.usdcCommissionRateSchedule);
       assert.equal(operatorPool.allowDelegation, allowDelegation);
       assert(operatorPool.totalStakedAmount.isZero());
       assert(operatorPool.totalShares.isZero());
@@ -1217,6 +1259,8 @@ describe("multi-epoch lifecycle tests", () => {
           destination: delegatorUsdcAccount.address,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([delegatorKp])
         .rpc();
@@ -1317,6 +1361,8 @@ describe("multi-epoch lifecycle tests", () => {
           destination: pool.usdcTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([pool.adminKp])
         .rpc();
@@ -1408,7 +1454,10 @@ describe("multi-epoch lifecycle tests", () => {
           operatorPool: pool.pool,
           ownerStakingRecord: stakingRecord,
           operatorStakingRecord: pool.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([delegatorKp])
         .rpc();
@@ -1529,6 +1578,8 @@ describe("multi-epoch lifecycle tests", () => {
           stakedTokenAccount: pool.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
 
@@ -1623,6 +1674,10 @@ describe("multi-epoch lifecycle tests", () => {
           ownerStakingRecord: stakingRecord,
           operatorPool: pool.pool,
           systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          poolOverview: setup.poolOverview,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([delegatorKp])
         .rpc();
@@ -1679,6 +1734,8 @@ describe("multi-epoch lifecycle tests", () => {
           destination: ownerTokenAccount.address,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([pool.adminKp])
         .rpc();
@@ -1750,6 +1807,8 @@ describe("multi-epoch lifecycle tests", () => {
           destination: pool.usdcTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([pool.adminKp])
         .rpc();
@@ -1813,6 +1872,9 @@ describe("multi-epoch lifecycle tests", () => {
           admin: pool.admin,
           poolOverview: setup.poolOverview,
           operatorPool: pool.pool,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([pool.adminKp])
         .rpc();
@@ -1871,7 +1933,10 @@ describe("multi-epoch lifecycle tests", () => {
           operatorPool: pool.pool,
           ownerStakingRecord: pool.stakingRecord,
           operatorStakingRecord: pool.stakingRecord,
+          referralRecord: null,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([pool.adminKp])
         .rpc();
@@ -1986,6 +2051,8 @@ describe("multi-epoch lifecycle tests", () => {
           stakedTokenAccount: pool.stakedTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
 
@@ -2066,6 +2133,10 @@ describe("multi-epoch lifecycle tests", () => {
           ownerStakingRecord: pool.stakingRecord,
           operatorPool: pool.pool,
           systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          poolOverview: setup.poolOverview,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([pool.adminKp])
         .rpc();
@@ -2112,6 +2183,7 @@ describe("multi-epoch lifecycle tests", () => {
           merkleRoots: [],
           totalRewards: new anchor.BN(0),
           totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -2121,6 +2193,11 @@ describe("multi-epoch lifecycle tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -2156,6 +2233,8 @@ describe("multi-epoch lifecycle tests", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([pool.adminKp])
         .rpc();
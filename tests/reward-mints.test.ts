@@ -0,0 +1,781 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { Connection, PublicKey } from "@solana/web3.js";
+import { SystemProgram, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { assert } from "chai";
+
+import type { InferenceStaking } from "@sdk/src/idl";
+
+import type { ConstructMerkleTreeInput } from "@tests/lib/merkle";
+import { MerkleUtils } from "@tests/lib/merkle";
+import type { SetupTestResult } from "@tests/lib/setup";
+import { setupTests } from "@tests/lib/setup";
+import {
+  assertError,
+  assertStakingProgramError,
+  handleMarkEpochAsFinalizing,
+} from "@tests/lib/utils";
+
+describe("Additional reward mint tests", () => {
+  let setup: SetupTestResult;
+  let connection: Connection;
+  let program: anchor.Program<InferenceStaking>;
+
+  let rewardMint: PublicKey;
+  let rewardMintVault: PublicKey;
+  let rewardsInput: ConstructMerkleTreeInput[];
+  let merkleTree: Uint8Array[][];
+
+  const operatorStake = new anchor.BN(100_000);
+  const delegatorStake = new anchor.BN(300_000);
+  const rewardMintPayout = 400_000n;
+
+  before(async () => {
+    setup = await setupTests();
+    program = setup.sdk.program;
+    connection = program.provider.connection;
+
+    await program.methods
+      .createPoolOverview()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+        newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
+        newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        allowPoolCreation: true,
+        minOperatorTokenStake: new anchor.BN(0),
+        delegatorUnstakeDelaySeconds: new anchor.BN(8),
+        operatorUnstakeDelaySeconds: new anchor.BN(20),
+        operatorPoolRegistrationFee: new anchor.BN(1_000),
+        slashingDelaySeconds: new anchor.BN(3),
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    rewardMint = await createMint(
+      connection,
+      setup.payerKp,
+      setup.tokenHolder,
+      null,
+      6
+    );
+    rewardMintVault = setup.sdk.globalRewardMintVaultPda(rewardMint);
+
+    // Pool 1 earns the full reward mint payout of epoch 2.
+    rewardsInput = setup.rewardEpochs[2].map((input) => ({
+      ...input,
+      usdcAmount: 0n,
+      rewardMintAmounts: [
+        input.address === setup.pool1.pool.toString() ? rewardMintPayout : 0n,
+      ],
+    }));
+    merkleTree = MerkleUtils.constructMerkleTree(rewardsInput);
+  });
+
+  it("Fail to add a reward mint with invalid admin", async () => {
+    try {
+      await program.methods
+        .addRewardMint()
+        .accountsStrict({
+          payer: setup.payer,
+          programAdmin: setup.signer,
+          poolOverview: setup.poolOverview,
+          rewardMint,
+          rewardMintTokenAccount: rewardMintVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.signerKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidProgramAdmin");
+    }
+  });
+
+  it("Fail to add the staking token or USDC mint as a reward mint", async () => {
+    for (const invalidRewardMint of [setup.tokenMint, setup.usdcTokenMint]) {
+      try {
+        await program.methods
+          .addRewardMint()
+          .accountsStrict({
+            payer: setup.payer,
+            programAdmin: setup.poolOverviewAdmin,
+            poolOverview: setup.poolOverview,
+            rewardMint: invalidRewardMint,
+            rewardMintTokenAccount:
+              setup.sdk.globalRewardMintVaultPda(invalidRewardMint),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            adminAuditLog: null,
+            systemProgram: SystemProgram.programId,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            eventAuthority: setup.eventAuthority,
+            program: program.programId,
+          })
+          .signers([setup.payerKp, setup.poolOverviewAdminKp])
+          .rpc();
+        assert(false);
+      } catch (error) {
+        assertStakingProgramError(error, "invalidRewardMint");
+      }
+    }
+  });
+
+  it("Program admin adds a reward mint", async () => {
+    await program.methods
+      .addRewardMint()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        rewardMint,
+        rewardMintTokenAccount: rewardMintVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.lengthOf(poolOverview.rewardMints, 1);
+    assert(poolOverview.rewardMints[0]?.mint.equals(rewardMint));
+    assert(poolOverview.rewardMints[0]?.unclaimedAmount.isZero());
+
+    const vault = await getAccount(connection, rewardMintVault);
+    assert(vault.mint.equals(rewardMint));
+    assert(vault.owner.equals(setup.poolOverview));
+  });
+
+  it("Fail to add the same reward mint twice", async () => {
+    try {
+      await program.methods
+        .addRewardMint()
+        .accountsStrict({
+          payer: setup.payer,
+          programAdmin: setup.poolOverviewAdmin,
+          poolOverview: setup.poolOverview,
+          rewardMint,
+          rewardMintTokenAccount: rewardMintVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.poolOverviewAdminKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertError(error, "already in use");
+    }
+  });
+
+  it("Fail to create RewardRecord without a payout for each reward mint", async () => {
+    try {
+      await handleMarkEpochAsFinalizing({ program, setup });
+      await program.methods
+        .createRewardRecord({
+          merkleRoots: [],
+          totalRewards: new anchor.BN(0),
+          totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [],
+        })
+        .accountsStrict({
+          payer: setup.payer,
+          authority: setup.rewardDistributionAuthority,
+          poolOverview: setup.poolOverview,
+          rewardRecord: setup.rewardRecords[1],
+          rewardTokenAccount: setup.rewardTokenAccount,
+          usdcTokenAccount: setup.usdcTokenAccount,
+          systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .remainingAccounts(
+          setup.sdk.getRewardMintVaultRemainingAccounts([rewardMint])
+        )
+        .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidRewardMintAmounts");
+    }
+  });
+
+  it("Create RewardRecord 1 successfully", async () => {
+    await handleMarkEpochAsFinalizing({ program, setup });
+    await program.methods
+      .createRewardRecord({
+        merkleRoots: [],
+        totalRewards: new anchor.BN(0),
+        totalUsdcPayout: new anchor.BN(0),
+        rewardMintPayouts: [new anchor.BN(0)],
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[1],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getRewardMintVaultRemainingAccounts([rewardMint])
+      )
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+  });
+
+  it("Create OperatorPool 1 and stake as Operator and delegator", async () => {
+    await program.methods
+      .createOperatorPool({
+        autoStakeFees: false,
+        rewardCommissionRateBps: setup.pool1.rewardCommissionRateBps,
+        usdcCommissionRateBps: setup.pool1.usdcCommissionRateBps,
+        allowDelegation: true,
+        name: setup.pool1.name,
+        description: setup.pool1.description,
+        websiteUrl: setup.pool1.websiteUrl,
+        avatarImageUrl: setup.pool1.avatarImageUrl,
+        operatorAuthKeys: null,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        stakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        poolOverview: setup.poolOverview,
+        mint: setup.tokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        adminTokenAccount: setup.pool1.adminTokenAccount,
+        registrationFeePayoutTokenAccount:
+          setup.registrationFeePayoutTokenAccount,
+        operatorUsdcVault: setup.pool1.poolUsdcVault,
+        usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+      )
+      .signers([setup.payerKp, setup.pool1.adminKp])
+      .rpc();
+
+    await program.methods
+      .stake({ tokenAmount: operatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.pool1.admin,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.stakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: setup.pool1.adminTokenAccount,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.pool1.adminKp])
+      .rpc();
+
+    await program.methods
+      .createStakingRecord()
+      .accountsStrict({
+        payer: setup.payer,
+        owner: setup.delegator1,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.delegator1Kp])
+      .rpc();
+
+    await program.methods
+      .stake({ tokenAmount: delegatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.delegator1,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          setup.tokenMint,
+          setup.delegator1
+        ),
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.delegator1Kp])
+      .rpc();
+  });
+
+  it("Fail to create RewardRecord with missing or underfunded reward mint vaults", async () => {
+    const merkleRoots = [Array.from(MerkleUtils.getTreeRoot(merkleTree))];
+    const totalRewards = rewardsInput.reduce(
+      (acc, curr) => acc + curr.tokenAmount,
+      0n
+    );
+    await mintTo(
+      connection,
+      setup.payerKp,
+      setup.tokenMint,
+      setup.rewardTokenAccount,
+      setup.tokenHolderKp,
+      totalRewards
+    );
+
+    try {
+      await handleMarkEpochAsFinalizing({ program, setup });
+      await program.methods
+        .createRewardRecord({
+          merkleRoots,
+          totalRewards: new anchor.BN(totalRewards.toString()),
+          totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [new anchor.BN(rewardMintPayout.toString())],
+        })
+        .accountsStrict({
+          payer: setup.payer,
+          authority: setup.rewardDistributionAuthority,
+          poolOverview: setup.poolOverview,
+          rewardRecord: setup.rewardRecords[2],
+          rewardTokenAccount: setup.rewardTokenAccount,
+          usdcTokenAccount: setup.usdcTokenAccount,
+          systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidRewardMintVault");
+    }
+
+    try {
+      await program.methods
+        .createRewardRecord({
+          merkleRoots,
+          totalRewards: new anchor.BN(totalRewards.toString()),
+          totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [new anchor.BN(rewardMintPayout.toString())],
+        })
+        .accountsStrict({
+          payer: setup.payer,
+          authority: setup.rewardDistributionAuthority,
+          poolOverview: setup.poolOverview,
+          rewardRecord: setup.rewardRecords[2],
+          rewardTokenAccount: setup.rewardTokenAccount,
+          usdcTokenAccount: setup.usdcTokenAccount,
+          systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .remainingAccounts(
+          setup.sdk.getRewardMintVaultRemainingAccounts([rewardMint])
+        )
+        .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "insufficientRewardMintTokens");
+    }
+  });
+
+  it("Create RewardRecord 2 with a reward mint payout", async () => {
+    const merkleRoots = [Array.from(MerkleUtils.getTreeRoot(merkleTree))];
+    const totalRewards = rewardsInput.reduce(
+      (acc, curr) => acc + curr.tokenAmount,
+      0n
+    );
+    await mintTo(
+      connection,
+      setup.payerKp,
+      rewardMint,
+      rewardMintVault,
+      setup.tokenHolderKp,
+      rewardMintPayout
+    );
+
+    await program.methods
+      .createRewardRecord({
+        merkleRoots,
+        totalRewards: new anchor.BN(totalRewards.toString()),
+        totalUsdcPayout: new anchor.BN(0),
+        rewardMintPayouts: [new anchor.BN(rewardMintPayout.toString())],
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[2],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getRewardMintVaultRemainingAccounts([rewardMint])
+      )
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+
+    const [rewardRecord, poolOverview] = await Promise.all([
+      program.account.rewardRecord.fetch(setup.rewardRecords[2]),
+      program.account.poolOverview.fetch(setup.poolOverview),
+    ]);
+    assert.lengthOf(rewardRecord.rewardMintPayouts, 1);
+    assert(
+      rewardRecord.rewardMintPayouts[0]?.eq(
+        new anchor.BN(rewardMintPayout.toString())
+      )
+    );
+    assert(
+      poolOverview.rewardMints[0]?.unclaimedAmount.eq(
+        new anchor.BN(rewardMintPayout.toString())
+      )
+    );
+  });
+
+  it("Fail to accrue rewards with invalid reward mint amounts", async () => {
+    const nodeIndex = rewardsInput.findIndex(
+      (x) => x.address == setup.pool1.pool.toString()
+    );
+    const proofInputs = rewardsInput[nodeIndex];
+    assert(proofInputs != null);
+    const { proof, proofPath } = MerkleUtils.generateMerkleProof({
+      ...proofInputs,
+      index: nodeIndex,
+      merkleTree,
+    });
+
+    for (const [rewardMintAmounts, errorCode] of [
+      [[], "invalidRewardMintAmounts"],
+      [[new anchor.BN(rewardMintPayout.toString()).addn(1)], "invalidProof"],
+    ] as const) {
+      try {
+        await program.methods
+          .accrueReward({
+            merkleIndex: 0,
+            proof: proof.map((arr) => Array.from(arr)),
+            proofPath,
+            rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
+            usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+            rewardMintAmounts: [...rewardMintAmounts],
+          })
+          .accountsStrict({
+            poolOverview: setup.poolOverview,
+            rewardRecord: setup.rewardRecords[2],
+            operatorPool: setup.pool1.pool,
+            operatorStakingRecord: setup.pool1.stakingRecord,
+            rewardTokenAccount: setup.rewardTokenAccount,
+            stakedTokenAccount: setup.pool1.stakedTokenAccount,
+            rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+            usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+            usdcTokenAccount: setup.usdcTokenAccount,
+            poolUsdcVault: setup.pool1.poolUsdcVault,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            eventAuthority: setup.eventAuthority,
+            program: program.programId,
+          })
+          .rpc();
+        assert(false);
+      } catch (error) {
+        assertStakingProgramError(error, errorCode);
+      }
+    }
+  });
+
+  it("Accrue rewards with a reward mint amount", async () => {
+    const nodeIndex = rewardsInput.findIndex(
+      (x) => x.address == setup.pool1.pool.toString()
+    );
+    const proofInputs = rewardsInput[nodeIndex];
+    assert(proofInputs != null);
+    const { proof, proofPath } = MerkleUtils.generateMerkleProof({
+      ...proofInputs,
+      index: nodeIndex,
+      merkleTree,
+    });
+
+    await program.methods
+      .accrueReward({
+        merkleIndex: 0,
+        proof: proof.map((arr) => Array.from(arr)),
+        proofPath,
+        rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
+        usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+        rewardMintAmounts: [new anchor.BN(rewardMintPayout.toString())],
+      })
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[2],
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert(operatorPool.accruedRewardMintAmounts[0]?.isZero());
+    assert(operatorPool.cumulativeRewardMintPerShare[0]?.gtn(0));
+  });
+
+  it("Fail to claim reward mint earnings to a destination of another mint", async () => {
+    try {
+      await program.methods
+        .claimRewardMintEarnings()
+        .accountsStrict({
+          owner: setup.delegator1,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          stakingRecord: setup.pool1.delegatorStakingRecord,
+          rewardMint,
+          rewardMintTokenAccount: rewardMintVault,
+          destination: getAssociatedTokenAddressSync(
+            setup.tokenMint,
+            setup.delegator1
+          ),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.delegator1Kp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidRewardMint");
+    }
+  });
+
+  it("Delegator and Operator claim reward mint earnings pro rata to shares", async () => {
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    const rewardPerShare = operatorPool.cumulativeRewardMintPerShare[0];
+    assert(rewardPerShare != null);
+    const precisionFactor = new anchor.BN(10).pow(new anchor.BN(18));
+
+    for (const { ownerKp, stakingRecord } of [
+      {
+        ownerKp: setup.delegator1Kp,
+        stakingRecord: setup.pool1.delegatorStakingRecord,
+      },
+      {
+        ownerKp: setup.pool1.adminKp,
+        stakingRecord: setup.pool1.stakingRecord,
+      },
+    ]) {
+      const destination = await getOrCreateAssociatedTokenAccount(
+        connection,
+        setup.payerKp,
+        rewardMint,
+        ownerKp.publicKey
+      );
+      const [stakingRecordPre, poolOverviewPre] = await Promise.all([
+        program.account.stakingRecord.fetch(stakingRecord),
+        program.account.poolOverview.fetch(setup.poolOverview),
+      ]);
+      const unclaimedAmountPre =
+        poolOverviewPre.rewardMints[0]?.unclaimedAmount;
+      assert(unclaimedAmountPre != null);
+      const expectedEarnings = stakingRecordPre.shares
+        .mul(rewardPerShare)
+        .div(precisionFactor);
+
+      await program.methods
+        .claimRewardMintEarnings()
+        .accountsStrict({
+          owner: ownerKp.publicKey,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          stakingRecord,
+          rewardMint,
+          rewardMintTokenAccount: rewardMintVault,
+          destination: destination.address,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([ownerKp])
+        .rpc();
+
+      const [destinationPost, stakingRecordPost, poolOverviewPost] =
+        await Promise.all([
+          getAccount(connection, destination.address),
+          program.account.stakingRecord.fetch(stakingRecord),
+          program.account.poolOverview.fetch(setup.poolOverview),
+        ]);
+      assert(
+        new anchor.BN(destinationPost.amount.toString()).eq(expectedEarnings),
+        "Destination must receive the reward mint earnings"
+      );
+      assert(stakingRecordPost.accruedRewardMintEarnings[0]?.isZero());
+      assert(
+        poolOverviewPost.rewardMints[0]?.unclaimedAmount.eq(
+          unclaimedAmountPre.sub(expectedEarnings)
+        ),
+        "Unclaimed reward mint amount must decrement"
+      );
+    }
+
+    // Shares are 1:1 with tokens staked, so earnings split pro rata to stake.
+    const delegatorDestination = await getAccount(
+      connection,
+      getAssociatedTokenAddressSync(rewardMint, setup.delegator1)
+    );
+    assert(
+      new anchor.BN(delegatorDestination.amount.toString()).eq(
+        new anchor.BN(rewardMintPayout.toString())
+          .mul(delegatorStake)
+          .div(delegatorStake.add(operatorStake))
+      )
+    );
+  });
+
+  it("Fail to claim reward mint earnings with nothing to claim", async () => {
+    try {
+      await program.methods
+        .claimRewardMintEarnings()
+        .accountsStrict({
+          owner: setup.delegator1,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          stakingRecord: setup.pool1.delegatorStakingRecord,
+          rewardMint,
+          rewardMintTokenAccount: rewardMintVault,
+          destination: getAssociatedTokenAddressSync(
+            rewardMint,
+            setup.delegator1
+          ),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.delegator1Kp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "noRewardMintEarningsToClaim");
+    }
+  });
+});
@@ -74,6 +74,9 @@ describe("Reward creation and accrual tests", () => {
         registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
         slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
         slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.poolOverviewAdminKp])
       .rpc();
@@ -85,17 +88,26 @@ describe("Reward creation and accrual tests", () => {
         ],
         newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
         newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
       })
       .accountsStrict({
-        newProgramAdmin: null,
+        payer: setup.poolOverviewAdminKp.publicKey,
         programAdmin: setup.poolOverviewAdminKp.publicKey,
         poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
 
     await program.methods
       .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
         isStakingHalted,
         isWithdrawalHalted,
         isAccrueRewardHalted,
@@ -112,6 +124,10 @@ describe("Reward creation and accrual tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -147,7 +163,13 @@ describe("Reward creation and accrual tests", () => {
           setup.pool2.pool
         ),
         usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool2.admin])
+      )
       .signers([setup.payerKp, setup.pool2.adminKp])
       .rpc();
   });
@@ -163,6 +185,7 @@ describe("Reward creation and accrual tests", () => {
           merkleRoots,
           totalRewards: new anchor.BN(0),
           totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -172,6 +195,11 @@ describe("Reward creation and accrual tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -189,6 +217,7 @@ describe("Reward creation and accrual tests", () => {
           merkleRoots: [],
           totalRewards: new anchor.BN(100),
           totalUsdcPayout: new anchor.BN(100),
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -198,6 +227,11 @@ describe("Reward creation and accrual tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -214,6 +248,7 @@ describe("Reward creation and accrual tests", () => {
         merkleRoots: [],
         totalRewards: new anchor.BN(0),
         totalUsdcPayout: new anchor.BN(0),
+        rewardMintPayouts: [],
       })
       .accountsStrict({
         payer: setup.payer,
@@ -223,6 +258,11 @@ describe("Reward creation and accrual tests", () => {
         rewardTokenAccount: setup.rewardTokenAccount,
         usdcTokenAccount: setup.usdcTokenAccount,
         systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
       .rpc();
@@ -260,7 +300,13 @@ describe("Reward creation and accrual tests", () => {
           setup.pool1.pool
         ),
         usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+      )
       .signers([setup.payerKp, setup.pool1.adminKp])
       .rpc();
 
@@ -269,7 +315,7 @@ describe("Reward creation and accrual tests", () => {
       setup.pool1.admin
     );
     await program.methods
-      .stake({ tokenAmount: new anchor.BN(150_000) })
+      .stake({ tokenAmount: new anchor.BN(150_000), referrer: null })
       .accountsStrict({
         owner: setup.pool1.admin,
         poolOverview: setup.poolOverview,
@@ -279,7 +325,10 @@ describe("Reward creation and accrual tests", () => {
         stakedTokenAccount: setup.pool1.stakedTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         ownerTokenAccount,
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -292,12 +341,16 @@ describe("Reward creation and accrual tests", () => {
         operatorPool: setup.pool1.pool,
         ownerStakingRecord: setup.pool1.delegatorStakingRecord,
         systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.delegator1Kp])
       .rpc();
 
     await program.methods
-      .stake({ tokenAmount: new anchor.BN(400_000) })
+      .stake({ tokenAmount: new anchor.BN(400_000), referrer: null })
       .accountsStrict({
         owner: setup.delegator1,
         poolOverview: setup.poolOverview,
@@ -310,28 +363,44 @@ describe("Reward creation and accrual tests", () => {
           setup.tokenMint,
           setup.delegator1
         ),
+        referralRecord: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.delegator1Kp])
       .rpc();
 
+    // Schedule new commission rates to apply from epoch 3.
     await program.methods
       .updateOperatorPool({
-        newRewardCommissionRateBps: { rateBps: newRewardCommissionRateBps },
-        newUsdcCommissionRateBps: { rateBps: newUsdcCommissionRateBps },
+        ...setup.sdk.getEmptyOperatorPoolFieldsForUpdateInstruction(),
+        rewardCommissionRateSchedule: [
+          {
+            effectiveEpoch: new anchor.BN(3),
+            rateBps: newRewardCommissionRateBps,
+          },
+        ],
+        usdcCommissionRateSchedule: [
+          {
+            effectiveEpoch: new anchor.BN(3),
+            rateBps: newUsdcCommissionRateBps,
+          },
+        ],
         autoStakeFees: true,
         allowDelegation: false,
         name: setup.pool1.name,
         description: setup.pool1.description,
         websiteUrl: setup.pool1.websiteUrl,
         avatarImageUrl: setup.pool1.avatarImageUrl,
-        operatorAuthKeys: null,
       })
       .accountsStrict({
         admin: setup.pool1.admin,
         operatorPool: setup.pool1.pool,
         poolOverview: setup.poolOverview,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -348,6 +417,7 @@ describe("Reward creation and accrual tests", () => {
           merkleRoots,
           totalRewards: new anchor.BN(0),
           totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -357,6 +427,11 @@ describe("Reward creation and accrual tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -381,6 +456,7 @@ describe("Reward creation and accrual tests", () => {
           merkleRoots,
           totalRewards: new anchor.BN(totalRewards.toString()),
           totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -390,6 +466,11 @@ describe("Reward creation and accrual tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -424,6 +505,7 @@ describe("Reward creation and accrual tests", () => {
           merkleRoots,
           totalRewards,
           totalUsdcPayout: new anchor.BN(0),
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -433,6 +515,11 @@ describe("Reward creation and accrual tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -457,6 +544,7 @@ describe("Reward creation and accrual tests", () => {
           merkleRoots,
           totalRewards,
           totalUsdcPayout: totalUSDC,
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -466,6 +554,11 @@ describe("Reward creation and accrual tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -490,6 +583,7 @@ describe("Reward creation and accrual tests", () => {
         merkleRoots,
         totalRewards,
         totalUsdcPayout: totalUSDC,
+        rewardMintPayouts: [],
       })
       .accountsStrict({
         payer: setup.payer,
@@ -499,6 +593,11 @@ describe("Reward creation and accrual tests", () => {
         rewardTokenAccount: setup.rewardTokenAccount,
         usdcTokenAccount: setup.usdcTokenAccount,
         systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
       .rpc();
@@ -561,6 +660,7 @@ describe("Reward creation and accrual tests", () => {
         merkleRoots,
         totalRewards,
         totalUsdcPayout: totalUsdcAmount,
+        rewardMintPayouts: [],
       })
       .accountsStrict({
         payer: setup.payer,
@@ -570,6 +670,11 @@ describe("Reward creation and accrual tests", () => {
         rewardTokenAccount: setup.rewardTokenAccount,
         usdcTokenAccount: setup.usdcTokenAccount,
         systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
       .rpc();
@@ -609,6 +714,7 @@ describe("Reward creation and accrual tests", () => {
           proofPath,
           rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
           usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolOverview: setup.poolOverview,
@@ -623,6 +729,8 @@ describe("Reward creation and accrual tests", () => {
           poolUsdcVault: setup.pool1.poolUsdcVault,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
     } catch (error) {
@@ -667,6 +775,7 @@ describe("Reward creation and accrual tests", () => {
           usdcAmount: new anchor.BN(
             setup.rewardEpochs[2][nodeIndex]?.usdcAmount.toString() ?? "0"
           ),
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolOverview: setup.poolOverview,
@@ -681,6 +790,8 @@ describe("Reward creation and accrual tests", () => {
           poolUsdcVault: setup.pool1.poolUsdcVault,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -701,6 +812,7 @@ describe("Reward creation and accrual tests", () => {
           usdcAmount: new anchor.BN(
             setup.rewardEpochs[2][nodeIndex]?.usdcAmount.toString() ?? "0"
           ),
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolOverview: setup.poolOverview,
@@ -715,6 +827,8 @@ describe("Reward creation and accrual tests", () => {
           poolUsdcVault: setup.pool1.poolUsdcVault,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -737,6 +851,7 @@ describe("Reward creation and accrual tests", () => {
           usdcAmount: new anchor.BN(
             setup.rewardEpochs[2][nodeIndex]?.usdcAmount.toString() ?? "0"
           ),
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolOverview: setup.poolOverview,
@@ -751,6 +866,8 @@ describe("Reward creation and accrual tests", () => {
           poolUsdcVault: setup.pool1.poolUsdcVault,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -773,6 +890,7 @@ describe("Reward creation and accrual tests", () => {
               (setup.rewardEpochs[2][nodeIndex]?.usdcAmount ?? 0n) + 1n
             ).toString()
           ),
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolOverview: setup.poolOverview,
@@ -787,6 +905,8 @@ describe("Reward creation and accrual tests", () => {
           poolUsdcVault: setup.pool1.poolUsdcVault,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -807,6 +927,10 @@ describe("Reward creation and accrual tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -831,6 +955,7 @@ describe("Reward creation and accrual tests", () => {
           proofPath,
           rewardAmount,
           usdcAmount,
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolOverview: setup.poolOverview,
@@ -845,6 +970,8 @@ describe("Reward creation and accrual tests", () => {
           poolUsdcVault: setup.pool1.poolUsdcVault,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -863,6 +990,10 @@ describe("Reward creation and accrual tests", () => {
         registrationFeePayoutWallet: null,
         slashingDestinationTokenAccount: null,
         slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.poolOverviewAdminKp])
       .rpc();
@@ -898,6 +1029,7 @@ describe("Reward creation and accrual tests", () => {
         proofPath,
         rewardAmount,
         usdcAmount,
+        rewardMintAmounts: [],
       })
       .accountsStrict({
         poolOverview: setup.poolOverview,
@@ -912,6 +1044,8 @@ describe("Reward creation and accrual tests", () => {
         poolUsdcVault: setup.pool1.poolUsdcVault,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .rpc();
 
@@ -920,12 +1054,14 @@ describe("Reward creation and accrual tests", () => {
       .div(new anchor.BN(10_000));
     const delegatorRewards = rewardAmount.sub(commissionFees);
 
-    // Check that OperatorPool's commission rate is not updated since there's 1 more epoch to claim.
+    // Check that OperatorPool's commission rate is not updated since the
+    // scheduled rate applies from epoch 3.
     const operatorPool = await program.account.operatorPool.fetch(
       setup.pool1.pool
     );
+    assert.equal(operatorPool.rewardCommissionRateSchedule.length, 1);
     assert.equal(
-      operatorPool.newRewardCommissionRateBps,
+      operatorPool.rewardCommissionRateSchedule[0].rateBps,
       newRewardCommissionRateBps
     );
     assert.equal(operatorPool.rewardCommissionRateBps, rewardCommissionRateBps);
@@ -980,6 +1116,7 @@ describe("Reward creation and accrual tests", () => {
           proofPath,
           rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
           usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolOverview: setup.poolOverview,
@@ -994,6 +1131,8 @@ describe("Reward creation and accrual tests", () => {
           poolUsdcVault: setup.pool1.poolUsdcVault,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -1043,6 +1182,7 @@ describe("Reward creation and accrual tests", () => {
         proofPath,
         rewardAmount,
         usdcAmount,
+        rewardMintAmounts: [],
       })
       .accountsStrict({
         poolOverview: setup.poolOverview,
@@ -1057,11 +1197,14 @@ describe("Reward creation and accrual tests", () => {
         poolUsdcVault: setup.pool1.poolUsdcVault,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .rpc();
 
+    // The scheduled commission rates apply to epoch 3.
     const commissionFees = rewardAmount
-      .mul(new anchor.BN(rewardCommissionRateBps))
+      .mul(new anchor.BN(newRewardCommissionRateBps))
       .div(new anchor.BN(10_000));
 
     const delegatorRewards = rewardAmount.sub(commissionFees);
@@ -1071,10 +1214,9 @@ describe("Reward creation and accrual tests", () => {
       .add(operatorPre.accruedRewards);
 
     // Calculate USDC commission
-    const usdcCommissionRateBps = operatorPre.usdcCommissionRateBps;
     const usdcCommissionFees = operatorPre.accruedUsdcCommission
       .add(usdcAmount)
-      .mul(new anchor.BN(usdcCommissionRateBps))
+      .mul(new anchor.BN(newUsdcCommissionRateBps))
       .div(new anchor.BN(10_000));
     const delegatorUsdcEarnings = operatorPre.accruedUsdcCommission
       .add(usdcAmount)
@@ -1093,7 +1235,8 @@ describe("Reward creation and accrual tests", () => {
     const operatorPool = await program.account.operatorPool.fetch(
       setup.pool1.pool
     );
-    assert.isNull(operatorPool.newRewardCommissionRateBps);
+    assert.isEmpty(operatorPool.rewardCommissionRateSchedule);
+    assert.isEmpty(operatorPool.usdcCommissionRateSchedule);
     assert.equal(
       operatorPool.rewardCommissionRateBps,
       newRewardCommissionRateBps
@@ -1218,6 +1361,7 @@ describe("Reward creation and accrual tests", () => {
         merkleRoots,
         totalRewards: new anchor.BN(totalRewards.toString()),
         totalUsdcPayout: new anchor.BN(totalUSDC.toString()),
+        rewardMintPayouts: [],
       })
       .accountsStrict({
         payer: setup.payer,
@@ -1227,6 +1371,11 @@ describe("Reward creation and accrual tests", () => {
         rewardTokenAccount: setup.rewardTokenAccount,
         usdcTokenAccount: setup.usdcTokenAccount,
         systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
       .rpc();
@@ -1253,6 +1402,7 @@ describe("Reward creation and accrual tests", () => {
         proofPath,
         rewardAmount,
         usdcAmount,
+        rewardMintAmounts: [],
       })
       .accountsStrict({
         poolOverview: setup.poolOverview,
@@ -1267,6 +1417,8 @@ describe("Reward creation and accrual tests", () => {
         poolUsdcVault: setup.pool1.poolUsdcVault,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .rpc();
   });
@@ -1278,6 +1430,9 @@ describe("Reward creation and accrual tests", () => {
         admin: setup.pool1.admin,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool1.pool,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool1.adminKp])
       .rpc();
@@ -1323,6 +1478,7 @@ describe("Reward creation and accrual tests", () => {
           merkleRoots,
           totalRewards,
           totalUsdcPayout: totalUsdcAmount,
+          rewardMintPayouts: [],
         })
         .accountsStrict({
           payer: setup.payer,
@@ -1332,6 +1488,11 @@ describe("Reward creation and accrual tests", () => {
           rewardTokenAccount: setup.rewardTokenAccount,
           usdcTokenAccount: setup.usdcTokenAccount,
           systemProgram: SystemProgram.programId,
+          pendingAction: null,
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
         .rpc();
@@ -1360,6 +1521,7 @@ describe("Reward creation and accrual tests", () => {
         proofPath,
         rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
         usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+        rewardMintAmounts: [],
       })
       .accountsStrict({
         poolUsdcVault: setup.pool1.poolUsdcVault,
@@ -1374,6 +1536,8 @@ describe("Reward creation and accrual tests", () => {
         usdcTokenAccount: setup.usdcTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .rpc();
 
@@ -1395,6 +1559,7 @@ describe("Reward creation and accrual tests", () => {
           proofPath,
           rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
           usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+          rewardMintAmounts: [],
         })
         .accountsStrict({
           poolUsdcVault: setup.pool1.poolUsdcVault,
@@ -1409,6 +1574,8 @@ describe("Reward creation and accrual tests", () => {
           usdcTokenAccount: setup.usdcTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .rpc();
       assert(false);
@@ -1444,6 +1611,10 @@ describe("Reward creation and accrual tests", () => {
             setup.rewardRecords[(epoch + 1) as 1 | 2 | 3 | 4 | 5 | 6],
           nextPoolRewardRecord:
             setup.rewardRecords[(epoch + 2) as 1 | 2 | 3 | 4 | 5 | 6],
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool2.adminKp])
         .rpc();
@@ -1471,6 +1642,10 @@ describe("Reward creation and accrual tests", () => {
           operatorPool: setup.pool2.pool,
           currentPoolRewardRecord: setup.rewardRecords[6],
           nextPoolRewardRecord: setup.rewardRecords[6], // Invalid - using same record
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
@@ -1491,6 +1666,10 @@ describe("Reward creation and accrual tests", () => {
           operatorPool: setup.pool2.pool,
           currentPoolRewardRecord: setup.rewardRecords[3], // Wrong - should be epoch 6
           nextPoolRewardRecord: setup.rewardRecords[4],
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool2.adminKp])
         .rpc();
@@ -1510,6 +1689,10 @@ describe("Reward creation and accrual tests", () => {
         operatorPool: setup.pool2.pool,
         currentPoolRewardRecord: setup.rewardRecords[6],
         nextPoolRewardRecord: setup.rewardRecords[7],
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.pool2.adminKp])
       .rpc();
@@ -1530,6 +1713,10 @@ describe("Reward creation and accrual tests", () => {
           operatorPool: setup.pool2.pool,
           currentPoolRewardRecord: setup.rewardRecords[6], // Invalid - pool already claimed epoch 6
           nextPoolRewardRecord: setup.rewardRecords[6],
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool2.adminKp])
         .rpc();
@@ -1573,18 +1760,33 @@ describe("Reward creation and accrual tests", () => {
           setup.pool3.pool
         ),
         usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool3.admin])
+      )
       .signers([setup.payerKp, setup.pool3.adminKp])
       .rpc();
 
     // Set halt status on pool3
     await program.methods
-      .setHaltStatus({ isHalted: true })
+      .setHaltStatus({
+        isHalted: true,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
+      })
       .accountsStrict({
         authority: setup.haltingAuthority,
         poolOverview: setup.poolOverview,
         operatorPool: setup.pool3.pool,
+        pendingAction: null,
+        adminAuditLog: null,
         instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
       })
       .signers([setup.haltingAuthorityKp])
       .rpc();
@@ -1599,6 +1801,10 @@ describe("Reward creation and accrual tests", () => {
           operatorPool: setup.pool3.pool,
           currentPoolRewardRecord: setup.rewardRecords[1],
           nextPoolRewardRecord: setup.rewardRecords[2],
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool3.adminKp])
         .rpc();
@@ -1619,6 +1825,10 @@ describe("Reward creation and accrual tests", () => {
           operatorPool: setup.pool1.pool,
           currentPoolRewardRecord: setup.rewardRecords[5],
           nextPoolRewardRecord: setup.rewardRecords[6],
+          adminAuditLog: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
         })
         .signers([setup.pool1.adminKp])
         .rpc();
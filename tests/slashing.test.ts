@@ -0,0 +1,1278 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { Connection, PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
+import { assert } from "chai";
+
+import type { InferenceStaking } from "@sdk/src/idl";
+
+import type { SetupTestResult } from "@tests/lib/setup";
+import { setupTests } from "@tests/lib/setup";
+import { assertStakingProgramError, sleep } from "@tests/lib/utils";
+
+describe("Slashing tests", () => {
+  let setup: SetupTestResult;
+  let connection: Connection;
+  let program: anchor.Program<InferenceStaking>;
+
+  const slashingDelaySeconds = new anchor.BN(3);
+  const delegatorUnstakeDelaySeconds = new anchor.BN(8);
+  const operatorStake = new anchor.BN(100_000);
+  const delegatorStake = new anchor.BN(300_000);
+
+  const poolSlashEvidenceHash = Array(32).fill(1);
+  const poolAndStakeSlashEvidenceHash = Array(32).fill(2);
+  const unstakingSlashEvidenceHash = Array(32).fill(3);
+  const secondUnstakingSlashEvidenceHash = Array(32).fill(4);
+  const approvedSlashEvidenceHash = Array(32).fill(5);
+  const reversedSlashEvidenceHash = Array(32).fill(6);
+  const releasedSlashEvidenceHash = Array(32).fill(7);
+
+  const secondSlashingAuthorityKp = Keypair.generate();
+
+  const proposeSlash = async ({
+    evidenceHash,
+    sharesAmount = new anchor.BN(0),
+    poolSlashBps = 0,
+    unstakingSlashBps = 0,
+  }: {
+    evidenceHash: number[];
+    sharesAmount?: anchor.BN;
+    poolSlashBps?: number;
+    unstakingSlashBps?: number;
+  }) => {
+    await program.methods
+      .createSlashProposal({
+        evidenceHash,
+        slashArgs: {
+          sharesAmount,
+          poolSlashBps,
+          unstakingSlashBps,
+          usdcEarningsConfiscation: { none: {} },
+          rewardCommissionConfiscation: { none: {} },
+          usdcCommissionConfiscation: { none: {} },
+        },
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        slashProposal: setup.sdk.slashProposalPda(
+          setup.pool1.pool,
+          evidenceHash
+        ),
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+  };
+
+  const slashStake = async (evidenceHash: number[]) => {
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      evidenceHash
+    );
+    const slashEscrow = setup.sdk.slashEscrowPda(slashProposal);
+
+    await program.methods
+      .slashStake()
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.slashingAuthority,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        slashProposal,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        slashEscrow,
+        escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+        escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.slashingAuthorityKp])
+      .rpc();
+
+    return slashEscrow;
+  };
+
+  const approveSlashProposal = async (
+    evidenceHash: number[],
+    authorityKp: Keypair
+  ) => {
+    await program.methods
+      .approveSlashProposal()
+      .accountsStrict({
+        authority: authorityKp.publicKey,
+        poolOverview: setup.poolOverview,
+        slashProposal: setup.sdk.slashProposalPda(
+          setup.pool1.pool,
+          evidenceHash
+        ),
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([authorityKp])
+      .rpc();
+  };
+
+  const updateSlashingConfig = async (
+    config: Partial<
+      ReturnType<
+        typeof setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction
+      >
+    >
+  ) => {
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        ...config,
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+  };
+
+  const releaseSlashEscrow = async (slashEscrow: PublicKey) => {
+    await program.methods
+      .releaseSlashEscrow()
+      .accountsStrict({
+        caller: setup.signer,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        slashEscrow,
+        escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+        escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        mint: setup.tokenMint,
+        insuranceFund: null,
+        insuranceTokenAccount: null,
+        insuranceUsdcAccount: null,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.signerKp])
+      .rpc();
+  };
+
+  const reverseSlash = async (slashEscrow: PublicKey, adminKp: Keypair) => {
+    await program.methods
+      .reverseSlash()
+      .accountsStrict({
+        programAdmin: adminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        slashEscrow,
+        escrowTokenAccount: setup.sdk.slashEscrowTokenVaultPda(slashEscrow),
+        escrowUsdcAccount: setup.sdk.slashEscrowUsdcVaultPda(slashEscrow),
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([adminKp])
+      .rpc();
+  };
+
+  const getDelegator2StakingRecord = () =>
+    setup.sdk.stakingRecordPda(setup.pool1.pool, setup.delegator2);
+
+  const claimDelegatorUnstake = async (
+    delegatorKp = setup.delegator1Kp,
+    ownerStakingRecord = setup.pool1.delegatorStakingRecord
+  ) => {
+    await program.methods
+      .claimUnstake()
+      .accountsStrict({
+        owner: delegatorKp.publicKey,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          setup.tokenMint,
+          delegatorKp.publicKey
+        ),
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
+
+  before(async () => {
+    setup = await setupTests();
+    program = setup.sdk.program;
+    connection = program.provider.connection;
+
+    await program.methods
+      .createPoolOverview()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+        newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
+        newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    // Delegators may lose at most as many tokens as the Operator in a slash.
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        allowPoolCreation: true,
+        minOperatorTokenStake: new anchor.BN(0),
+        delegatorUnstakeDelaySeconds,
+        operatorUnstakeDelaySeconds: new anchor.BN(20),
+        operatorPoolRegistrationFee: new anchor.BN(1_000),
+        slashingDelaySeconds,
+        maxDelegatorSlashRatioBps: 10_000,
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .createOperatorPool({
+        autoStakeFees: false,
+        rewardCommissionRateBps: setup.pool1.rewardCommissionRateBps,
+        usdcCommissionRateBps: setup.pool1.usdcCommissionRateBps,
+        allowDelegation: true,
+        name: setup.pool1.name,
+        description: setup.pool1.description,
+        websiteUrl: setup.pool1.websiteUrl,
+        avatarImageUrl: setup.pool1.avatarImageUrl,
+        operatorAuthKeys: null,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        stakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        poolOverview: setup.poolOverview,
+        mint: setup.tokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        adminTokenAccount: setup.pool1.adminTokenAccount,
+        registrationFeePayoutTokenAccount:
+          setup.registrationFeePayoutTokenAccount,
+        operatorUsdcVault: setup.pool1.poolUsdcVault,
+        usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+      )
+      .signers([setup.payerKp, setup.pool1.adminKp])
+      .rpc();
+
+    await program.methods
+      .stake({ tokenAmount: operatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.pool1.admin,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.stakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: setup.pool1.adminTokenAccount,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.pool1.adminKp])
+      .rpc();
+
+    await program.methods
+      .createStakingRecord()
+      .accountsStrict({
+        payer: setup.payer,
+        owner: setup.delegator1,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.delegator1Kp])
+      .rpc();
+
+    await program.methods
+      .stake({ tokenAmount: delegatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.delegator1,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          setup.tokenMint,
+          setup.delegator1
+        ),
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.delegator1Kp])
+      .rpc();
+
+    // A second delegator unstakes before the halt. The unstake becomes
+    // claimable before pending unstakes are slashed, so it is not slashed.
+    await program.methods
+      .createStakingRecord()
+      .accountsStrict({
+        payer: setup.payer,
+        owner: setup.delegator2,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: getDelegator2StakingRecord(),
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.delegator2Kp])
+      .rpc();
+
+    await program.methods
+      .stake({ tokenAmount: delegatorStake, referrer: null })
+      .accountsStrict({
+        owner: setup.delegator2,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: getDelegator2StakingRecord(),
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          setup.tokenMint,
+          setup.delegator2
+        ),
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.delegator2Kp])
+      .rpc();
+
+    const { shares: delegator2Shares } =
+      await program.account.stakingRecord.fetch(getDelegator2StakingRecord());
+    await program.methods
+      .unstake({ sharesAmount: delegator2Shares })
+      .accountsStrict({
+        owner: setup.delegator2,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: getDelegator2StakingRecord(),
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.delegator2Kp])
+      .rpc();
+
+    await program.methods
+      .setHaltStatus({
+        isHalted: true,
+        reasonCode: 0,
+        evidenceHash: Array(32).fill(0),
+        expiresAt: null,
+      })
+      .accountsStrict({
+        authority: setup.haltingAuthorityKp.publicKey,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.haltingAuthorityKp])
+      .rpc();
+
+    await sleep(slashingDelaySeconds.toNumber() * 2 * 1_000);
+  });
+
+  it("Fail to propose a pool-wide slash of the whole pool", async () => {
+    try {
+      await proposeSlash({
+        evidenceHash: poolSlashEvidenceHash,
+        poolSlashBps: 10_000,
+      });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidSlashBps");
+    }
+  });
+
+  it("Fail to slash the pool when delegators would lose more than the max. ratio", async () => {
+    // The Operator owns 25% of the pool, so delegators would lose 3x as much.
+    await proposeSlash({
+      evidenceHash: poolSlashEvidenceHash,
+      poolSlashBps: 1_000,
+    });
+
+    try {
+      await slashStake(poolSlashEvidenceHash);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "delegatorSlashRatioExceeded");
+    }
+  });
+
+  it("Slash the whole pool proportionally together with Operator stake", async () => {
+    // Slashing 25,000 Operator shares first leaves delegators with a 30,000
+    // token loss and the Operator with a 32,500 token loss.
+    const sharesAmount = new anchor.BN(25_000);
+    const poolSlashBps = 1_000;
+    await proposeSlash({
+      evidenceHash: poolAndStakeSlashEvidenceHash,
+      sharesAmount,
+      poolSlashBps,
+    });
+
+    const [
+      operatorPoolPre,
+      operatorStakingRecordPre,
+      delegatorStakingRecordPre,
+      stakedTokenAccountPre,
+    ] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
+      program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+      getAccount(connection, setup.pool1.stakedTokenAccount),
+    ]);
+
+    const slashEscrow = await slashStake(poolAndStakeSlashEvidenceHash);
+
+    const [
+      operatorPool,
+      operatorStakingRecord,
+      delegatorStakingRecord,
+      stakedTokenAccount,
+      escrowTokenAccount,
+      escrow,
+    ] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
+      program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+      getAccount(connection, setup.pool1.stakedTokenAccount),
+      getAccount(connection, setup.sdk.slashEscrowTokenVaultPda(slashEscrow)),
+      program.account.slashEscrow.fetch(slashEscrow),
+    ]);
+
+    const tokenAmountSlashed = operatorPoolPre.totalStakedAmount
+      .mul(sharesAmount)
+      .div(operatorPoolPre.totalShares);
+    const poolTokenAmountSlashed = operatorPoolPre.totalStakedAmount
+      .sub(tokenAmountSlashed)
+      .muln(poolSlashBps)
+      .divn(10_000);
+    const totalSlashed = tokenAmountSlashed.add(poolTokenAmountSlashed);
+
+    // Only the Operator loses shares, while the value of every share drops.
+    assert(
+      operatorStakingRecord.shares.eq(
+        operatorStakingRecordPre.shares.sub(sharesAmount)
+      )
+    );
+    assert(delegatorStakingRecord.shares.eq(delegatorStakingRecordPre.shares));
+    assert(
+      operatorPool.totalShares.eq(operatorPoolPre.totalShares.sub(sharesAmount))
+    );
+    assert(
+      operatorPool.totalStakedAmount.eq(
+        operatorPoolPre.totalStakedAmount.sub(totalSlashed)
+      )
+    );
+    assert.isNotNull(operatorPool.lastSlashedAt);
+
+    assert.equal(
+      stakedTokenAccount.amount,
+      stakedTokenAccountPre.amount - BigInt(totalSlashed.toString())
+    );
+    assert.equal(escrowTokenAccount.amount, BigInt(totalSlashed.toString()));
+    assert(escrow.sharesSlashed.eq(sharesAmount));
+    assert(escrow.tokenAmountSlashed.eq(tokenAmountSlashed));
+    assert(escrow.poolTokenAmountSlashed.eq(poolTokenAmountSlashed));
+    assert(escrow.unstakingTokenAmountSlashed.isZero());
+  });
+
+  it("Second delegator's unstake becomes claimable", async () => {
+    const { unstakeAtTimestamp } = await program.account.stakingRecord.fetch(
+      getDelegator2StakingRecord()
+    );
+    const secondsToClaimable =
+      unstakeAtTimestamp.toNumber() - Math.floor(Date.now() / 1_000);
+    if (secondsToClaimable >= 0) {
+      await sleep((secondsToClaimable + 1) * 1_000);
+    }
+  });
+
+  it("Delegator unstakes part of their stake from the halted pool", async () => {
+    const sharesAmount = new anchor.BN(100_000);
+    const operatorPoolPre = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+
+    await program.methods
+      .unstake({ sharesAmount })
+      .accountsStrict({
+        owner: setup.delegator1,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.delegator1Kp])
+      .rpc();
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert(
+      operatorPool.totalUnstaking.eq(
+        operatorPoolPre.totalUnstaking.add(
+          operatorPoolPre.totalStakedAmount
+            .mul(sharesAmount)
+            .div(operatorPoolPre.totalShares)
+        )
+      )
+    );
+  });
+
+  it("Fail to propose an unstaking slash of all pending unstakes", async () => {
+    try {
+      await proposeSlash({
+        evidenceHash: unstakingSlashEvidenceHash,
+        unstakingSlashBps: 10_000,
+      });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidSlashBps");
+    }
+  });
+
+  it("Fail to slash pending unstakes when delegators would lose more than the max. ratio", async () => {
+    // Delegators would lose 45,000 unstaking tokens, the Operator 9,000 tokens.
+    await proposeSlash({
+      evidenceHash: unstakingSlashEvidenceHash,
+      sharesAmount: new anchor.BN(10_000),
+      unstakingSlashBps: 5_000,
+    });
+
+    try {
+      await slashStake(unstakingSlashEvidenceHash);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "delegatorSlashRatioExceeded");
+    }
+  });
+
+  it("Slash pending unstakes together with Operator stake", async () => {
+    await updateSlashingConfig({ clearMaxDelegatorSlashRatioBps: true });
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.isNull(poolOverview.maxDelegatorSlashRatioBps);
+
+    const [operatorPoolPre, slashProposal, delegator2StakingRecord] =
+      await Promise.all([
+        program.account.operatorPool.fetch(setup.pool1.pool),
+        program.account.slashProposal.fetch(
+          setup.sdk.slashProposalPda(
+            setup.pool1.pool,
+            unstakingSlashEvidenceHash
+          )
+        ),
+        program.account.stakingRecord.fetch(getDelegator2StakingRecord()),
+      ]);
+
+    const slashEscrow = await slashStake(unstakingSlashEvidenceHash);
+
+    const [operatorPool, escrowTokenAccount, escrow] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      getAccount(connection, setup.sdk.slashEscrowTokenVaultPda(slashEscrow)),
+      program.account.slashEscrow.fetch(slashEscrow),
+    ]);
+
+    const tokenAmountSlashed = operatorPoolPre.totalStakedAmount
+      .mul(slashProposal.args.sharesAmount)
+      .div(operatorPoolPre.totalShares);
+    // The second delegator's unstake is already claimable, so only the
+    // first delegator's unstake is slashed.
+    const unstakingTokenAmountSlashed = operatorPoolPre.totalUnstaking
+      .sub(delegator2StakingRecord.tokensUnstakeAmount)
+      .muln(slashProposal.args.unstakingSlashBps)
+      .divn(10_000);
+
+    // Pending unstakes are reduced through the pool totals only, and the
+    // StakingRecords are settled once the slash is resolved.
+    assert(
+      operatorPool.totalUnstaking.eq(
+        operatorPoolPre.totalUnstaking.sub(unstakingTokenAmountSlashed)
+      )
+    );
+    assert(operatorPool.unstakingSlashEscrow?.equals(slashEscrow));
+    assert(
+      operatorPool.totalStakedAmount.eq(
+        operatorPoolPre.totalStakedAmount.sub(tokenAmountSlashed)
+      )
+    );
+    assert(escrow.unstakingTokenAmountSlashed.eq(unstakingTokenAmountSlashed));
+    assert(escrow.proposer.equals(setup.slashingAuthority));
+    assert.equal(operatorPool.unstakingSlashes.length, 1);
+    assert.equal(
+      escrowTokenAccount.amount,
+      BigInt(tokenAmountSlashed.add(unstakingTokenAmountSlashed).toString())
+    );
+  });
+
+  it("Fail to slash pending unstakes while an unstaking slash is unresolved", async () => {
+    await proposeSlash({
+      evidenceHash: secondUnstakingSlashEvidenceHash,
+      sharesAmount: new anchor.BN(10_000),
+      unstakingSlashBps: 1_000,
+    });
+
+    try {
+      await slashStake(secondUnstakingSlashEvidenceHash);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "unstakingSlashUnresolved");
+    }
+  });
+
+  it("Fail to claim a slashed unstake while the unstaking slash is unresolved", async () => {
+    await sleep(delegatorUnstakeDelaySeconds.toNumber() * 1_000);
+
+    try {
+      await claimDelegatorUnstake();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "unstakingSlashUnresolved");
+    }
+  });
+
+  it("Fail to unstake more or cancel a slashed unstake while the unstaking slash is unresolved", async () => {
+    try {
+      await program.methods
+        .unstake({ sharesAmount: new anchor.BN(1) })
+        .accountsStrict({
+          owner: setup.delegator1,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+          operatorStakingRecord: setup.pool1.stakingRecord,
+          referralRecord: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.delegator1Kp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "unstakingSlashUnresolved");
+    }
+
+    try {
+      await program.methods
+        .cancelUnstake()
+        .accountsStrict({
+          owner: setup.delegator1,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+          referralRecord: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.delegator1Kp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "unstakingSlashUnresolved");
+    }
+  });
+
+  it("Second delegator claims the unstake that was claimable before the slash", async () => {
+    const ownerTokenAccount = getAssociatedTokenAddressSync(
+      setup.tokenMint,
+      setup.delegator2
+    );
+    const [stakingRecordPre, ownerTokenAccountPre] = await Promise.all([
+      program.account.stakingRecord.fetch(getDelegator2StakingRecord()),
+      getAccount(connection, ownerTokenAccount),
+    ]);
+
+    await claimDelegatorUnstake(
+      setup.delegator2Kp,
+      getDelegator2StakingRecord()
+    );
+
+    const ownerTokenAccountPost = await getAccount(
+      connection,
+      ownerTokenAccount
+    );
+    assert.equal(
+      ownerTokenAccountPost.amount,
+      ownerTokenAccountPre.amount +
+        BigInt(stakingRecordPre.tokensUnstakeAmount.toString())
+    );
+  });
+
+  it("Releasing the slash escrow resolves the unstaking slash", async () => {
+    const slashEscrow = setup.sdk.slashEscrowPda(
+      setup.sdk.slashProposalPda(setup.pool1.pool, unstakingSlashEvidenceHash)
+    );
+    const [destinationPre, escrowTokenAccountPre] = await Promise.all([
+      getAccount(connection, setup.slashingDestinationTokenAccount),
+      getAccount(connection, setup.sdk.slashEscrowTokenVaultPda(slashEscrow)),
+    ]);
+
+    await releaseSlashEscrow(slashEscrow);
+
+    const [operatorPool, destination, escrow] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      getAccount(connection, setup.slashingDestinationTokenAccount),
+      program.account.slashEscrow.fetch(slashEscrow),
+    ]);
+    assert.isNull(operatorPool.unstakingSlashEscrow);
+    assert.isNotNull(escrow.releasedAt);
+    assert.equal(
+      destination.amount,
+      destinationPre.amount + escrowTokenAccountPre.amount
+    );
+  });
+
+  it("Delegator claims the slashed unstake", async () => {
+    const ownerTokenAccount = getAssociatedTokenAddressSync(
+      setup.tokenMint,
+      setup.delegator1
+    );
+    const [stakingRecordPre, ownerTokenAccountPre] = await Promise.all([
+      program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+      getAccount(connection, ownerTokenAccount),
+    ]);
+    const slashEscrow = await program.account.slashEscrow.fetch(
+      setup.sdk.slashEscrowPda(
+        setup.sdk.slashProposalPda(
+          setup.pool1.pool,
+          unstakingSlashEvidenceHash
+        )
+      )
+    );
+
+    await claimDelegatorUnstake();
+
+    const [stakingRecord, operatorPool, ownerTokenAccountPost] =
+      await Promise.all([
+        program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+        program.account.operatorPool.fetch(setup.pool1.pool),
+        getAccount(connection, ownerTokenAccount),
+      ]);
+
+    // The delegator has the only pending unstake, so bears the whole slash.
+    const expectedAmount = stakingRecordPre.tokensUnstakeAmount.sub(
+      slashEscrow.unstakingTokenAmountSlashed
+    );
+    assert.equal(
+      ownerTokenAccountPost.amount,
+      ownerTokenAccountPre.amount + BigInt(expectedAmount.toString())
+    );
+    assert(stakingRecord.tokensUnstakeAmount.isZero());
+    assert(operatorPool.totalUnstaking.isZero());
+  });
+
+  it("Fail to require more slashing approvals than there are slashing authorities", async () => {
+    try {
+      await updateSlashingConfig({ slashingApprovalThreshold: 2 });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidSlashingApprovalThreshold");
+    }
+  });
+
+  it("Program admin adds a slashing authority and requires two approvals", async () => {
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: null,
+        newHaltAuthorities: null,
+        newSlashingAuthorities: [
+          setup.slashingAuthorityKp.publicKey,
+          secondSlashingAuthorityKp.publicKey,
+        ],
+        newEpochAuthorities: null,
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await updateSlashingConfig({ slashingApprovalThreshold: 2 });
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.lengthOf(poolOverview.slashingAuthorities, 2);
+    assert.equal(poolOverview.slashingApprovalThreshold, 2);
+  });
+
+  it("Fail to approve a slash proposal with invalid authority", async () => {
+    await proposeSlash({
+      evidenceHash: approvedSlashEvidenceHash,
+      sharesAmount: new anchor.BN(1_000),
+    });
+
+    try {
+      await approveSlashProposal(
+        approvedSlashEvidenceHash,
+        setup.pool1.adminKp
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidSlashingAuthority");
+    }
+  });
+
+  it("Fail to slash before the slash proposal meets the approval threshold", async () => {
+    try {
+      await slashStake(approvedSlashEvidenceHash);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "slashProposalNotApproved");
+    }
+  });
+
+  it("Fail to tombstone an operator key before the slash proposal meets the approval threshold", async () => {
+    try {
+      await program.methods
+        .tombstoneOperatorKey({ key: setup.pool1.admin })
+        .accountsStrict({
+          payer: setup.payer,
+          authority: setup.slashingAuthority,
+          poolOverview: setup.poolOverview,
+          operatorPool: setup.pool1.pool,
+          slashProposal: setup.sdk.slashProposalPda(
+            setup.pool1.pool,
+            approvedSlashEvidenceHash
+          ),
+          tombstone: setup.sdk.tombstonePda(setup.pool1.admin),
+          adminAuditLog: null,
+          systemProgram: SystemProgram.programId,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          eventAuthority: setup.eventAuthority,
+          program: program.programId,
+        })
+        .signers([setup.payerKp, setup.slashingAuthorityKp])
+        .rpc();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "slashProposalNotApproved");
+    }
+  });
+
+  it("Fail to approve a slash proposal twice", async () => {
+    try {
+      await approveSlashProposal(
+        approvedSlashEvidenceHash,
+        setup.slashingAuthorityKp
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "duplicateSlashApproval");
+    }
+  });
+
+  it("Slash once the slash proposal meets the approval threshold", async () => {
+    await approveSlashProposal(
+      approvedSlashEvidenceHash,
+      secondSlashingAuthorityKp
+    );
+
+    const slashProposal = setup.sdk.slashProposalPda(
+      setup.pool1.pool,
+      approvedSlashEvidenceHash
+    );
+    const slashProposalPre = await program.account.slashProposal.fetch(
+      slashProposal
+    );
+    assert.lengthOf(slashProposalPre.approvals, 2);
+    assert(
+      slashProposalPre.approvals[1]?.equals(secondSlashingAuthorityKp.publicKey)
+    );
+
+    await slashStake(approvedSlashEvidenceHash);
+
+    const slashProposalPost = await program.account.slashProposal.fetch(
+      slashProposal
+    );
+    assert.isNotNull(slashProposalPost.executedAt);
+  });
+
+  it("Program admin lowers the slashing approval threshold", async () => {
+    await updateSlashingConfig({ slashingApprovalThreshold: 1 });
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.equal(poolOverview.slashingApprovalThreshold, 1);
+  });
+
+  it("Fail to release a slash escrow during the appeal period", async () => {
+    await updateSlashingConfig({
+      slashingAppealPeriodSeconds: new anchor.BN(3_600),
+    });
+    await proposeSlash({
+      evidenceHash: reversedSlashEvidenceHash,
+      sharesAmount: new anchor.BN(10_000),
+      poolSlashBps: 500,
+    });
+    const slashEscrow = await slashStake(reversedSlashEvidenceHash);
+
+    const escrow = await program.account.slashEscrow.fetch(slashEscrow);
+    assert(escrow.releaseAt.eq(escrow.slashedAt.addn(3_600)));
+
+    try {
+      await releaseSlashEscrow(slashEscrow);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "slashAppealPeriodNotEnded");
+    }
+  });
+
+  it("Fail to reverse a slash with invalid admin", async () => {
+    const slashEscrow = setup.sdk.slashEscrowPda(
+      setup.sdk.slashProposalPda(setup.pool1.pool, reversedSlashEvidenceHash)
+    );
+
+    try {
+      await reverseSlash(slashEscrow, setup.pool1.adminKp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidProgramAdmin");
+    }
+  });
+
+  it("Program admin reverses a slash during the appeal period", async () => {
+    const slashEscrow = setup.sdk.slashEscrowPda(
+      setup.sdk.slashProposalPda(setup.pool1.pool, reversedSlashEvidenceHash)
+    );
+    const escrowTokenAccount = setup.sdk.slashEscrowTokenVaultPda(slashEscrow);
+
+    const [
+      operatorPoolPre,
+      operatorStakingRecordPre,
+      stakedTokenAccountPre,
+      escrowTokenAccountPre,
+      escrow,
+    ] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
+      getAccount(connection, setup.pool1.stakedTokenAccount),
+      getAccount(connection, escrowTokenAccount),
+      program.account.slashEscrow.fetch(slashEscrow),
+    ]);
+
+    await reverseSlash(slashEscrow, setup.poolOverviewAdminKp);
+
+    const [
+      operatorPool,
+      operatorStakingRecord,
+      stakedTokenAccount,
+      escrowTokenAccountPost,
+      escrowPost,
+    ] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
+      getAccount(connection, setup.pool1.stakedTokenAccount),
+      getAccount(connection, escrowTokenAccount),
+      program.account.slashEscrow.fetch(slashEscrow),
+    ]);
+
+    // Slashed stake is returned to the pool, and the Operator's shares are
+    // restored at the unslashed share value.
+    const tokensRestored = escrow.tokenAmountSlashed.add(
+      escrow.poolTokenAmountSlashed
+    );
+    const sharesRestored = operatorStakingRecord.shares.sub(
+      operatorStakingRecordPre.shares
+    );
+    assert(
+      operatorPool.totalStakedAmount.eq(
+        operatorPoolPre.totalStakedAmount.add(tokensRestored)
+      )
+    );
+    assert(sharesRestored.gtn(0));
+    assert(
+      operatorPool.totalShares.eq(
+        operatorPoolPre.totalShares.add(sharesRestored)
+      )
+    );
+    assert.equal(
+      stakedTokenAccount.amount,
+      stakedTokenAccountPre.amount + escrowTokenAccountPre.amount
+    );
+    assert.equal(escrowTokenAccountPost.amount, 0n);
+    assert.isNotNull(escrowPost.reversedAt);
+    assert.isNull(escrowPost.releasedAt);
+  });
+
+  it("Fail to release or reverse a reversed slash", async () => {
+    const slashEscrow = setup.sdk.slashEscrowPda(
+      setup.sdk.slashProposalPda(setup.pool1.pool, reversedSlashEvidenceHash)
+    );
+
+    try {
+      await releaseSlashEscrow(slashEscrow);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "slashEscrowResolved");
+    }
+
+    try {
+      await reverseSlash(slashEscrow, setup.poolOverviewAdminKp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "slashEscrowResolved");
+    }
+  });
+
+  it("Fail to configure a slash split above 100%", async () => {
+    try {
+      await updateSlashingConfig({
+        slashRedistributionBps: 6_000,
+        slashBurnBps: 5_000,
+      });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidSlashSplit");
+    }
+  });
+
+  it("Releasing a slash escrow redistributes to delegators, burns and pays the slashing destination", async () => {
+    await updateSlashingConfig({
+      slashingAppealPeriodSeconds: new anchor.BN(0),
+      slashRedistributionBps: 2_000,
+      slashBurnBps: 1_000,
+    });
+    await proposeSlash({
+      evidenceHash: releasedSlashEvidenceHash,
+      sharesAmount: new anchor.BN(10_000),
+    });
+    const slashEscrow = await slashStake(releasedSlashEvidenceHash);
+    const escrowTokenAccount = setup.sdk.slashEscrowTokenVaultPda(slashEscrow);
+
+    const [
+      operatorPoolPre,
+      operatorStakingRecordPre,
+      delegatorStakingRecordPre,
+      stakedTokenAccountPre,
+      escrowTokenAccountPre,
+      destinationPre,
+      mintPre,
+      escrow,
+    ] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
+      program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+      getAccount(connection, setup.pool1.stakedTokenAccount),
+      getAccount(connection, escrowTokenAccount),
+      getAccount(connection, setup.slashingDestinationTokenAccount),
+      getMint(connection, setup.tokenMint),
+      program.account.slashEscrow.fetch(slashEscrow),
+    ]);
+
+    const totalEscrowed = escrowTokenAccountPre.amount;
+    const redistributionAmount = (totalEscrowed * 2_000n) / 10_000n;
+    const burnAmount = (totalEscrowed * 1_000n) / 10_000n;
+    assert.equal(
+      BigInt(escrow.redistributionAmount.toString()),
+      redistributionAmount
+    );
+    assert.equal(BigInt(escrow.burnAmount.toString()), burnAmount);
+
+    await releaseSlashEscrow(slashEscrow);
+
+    const [
+      operatorPool,
+      operatorStakingRecord,
+      delegatorStakingRecord,
+      stakedTokenAccount,
+      escrowTokenAccountPost,
+      destination,
+      mint,
+    ] = await Promise.all([
+      program.account.operatorPool.fetch(setup.pool1.pool),
+      program.account.stakingRecord.fetch(setup.pool1.stakingRecord),
+      program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+      getAccount(connection, setup.pool1.stakedTokenAccount),
+      getAccount(connection, escrowTokenAccount),
+      getAccount(connection, setup.slashingDestinationTokenAccount),
+      getMint(connection, setup.tokenMint),
+    ]);
+
+    // Redistributed tokens go to delegators only, so the Operator's shares
+    // are reduced to keep the value of the Operator's stake unchanged.
+    const operatorSharesRemoved = operatorStakingRecordPre.shares.sub(
+      operatorStakingRecord.shares
+    );
+    assert(operatorSharesRemoved.gtn(0));
+    assert(delegatorStakingRecord.shares.eq(delegatorStakingRecordPre.shares));
+    assert(
+      operatorPool.totalShares.eq(
+        operatorPoolPre.totalShares.sub(operatorSharesRemoved)
+      )
+    );
+    assert.equal(
+      BigInt(operatorPool.totalStakedAmount.toString()),
+      BigInt(operatorPoolPre.totalStakedAmount.toString()) +
+        redistributionAmount
+    );
+    assert.equal(
+      stakedTokenAccount.amount,
+      stakedTokenAccountPre.amount + redistributionAmount
+    );
+    assert.equal(mint.supply, mintPre.supply - burnAmount);
+    assert.equal(
+      destination.amount,
+      destinationPre.amount + totalEscrowed - redistributionAmount - burnAmount
+    );
+    assert.equal(escrowTokenAccountPost.amount, 0n);
+  });
+
+  it("Fail to reverse a released slash", async () => {
+    const slashEscrow = setup.sdk.slashEscrowPda(
+      setup.sdk.slashProposalPda(setup.pool1.pool, releasedSlashEvidenceHash)
+    );
+
+    try {
+      await reverseSlash(slashEscrow, setup.poolOverviewAdminKp);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "slashEscrowResolved");
+    }
+  });
+});
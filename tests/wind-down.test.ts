@@ -0,0 +1,675 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { SystemProgram, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { assert } from "chai";
+
+import type { InferenceStaking } from "@sdk/src/idl";
+
+import type { ConstructMerkleTreeInput } from "@tests/lib/merkle";
+import { MerkleUtils } from "@tests/lib/merkle";
+import type { SetupTestResult } from "@tests/lib/setup";
+import { setupTests } from "@tests/lib/setup";
+import {
+  assertStakingProgramError,
+  handleMarkEpochAsFinalizing,
+  sleep,
+} from "@tests/lib/utils";
+
+describe("Wind-down tests", () => {
+  let setup: SetupTestResult;
+  let connection: Connection;
+  let program: anchor.Program<InferenceStaking>;
+
+  const configChangeTimelockSeconds = new anchor.BN(4);
+  const finalEpoch = new anchor.BN(2);
+  const operatorStake = new anchor.BN(100_000);
+  const delegatorStake = new anchor.BN(300_000);
+
+  let delegator1UsdcAccount: PublicKey;
+  let windDownConfigChange: PublicKey;
+
+  type UpdatePoolOverviewArgs = ReturnType<
+    typeof setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction
+  >;
+
+  const getDelegator2StakingRecord = () =>
+    setup.sdk.stakingRecordPda(setup.pool1.pool, setup.delegator2);
+
+  const updatePoolOverview = async (args: Partial<UpdatePoolOverviewArgs>) => {
+    await program.methods
+      .updatePoolOverview({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        ...args,
+      })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        registrationFeePayoutWallet: null,
+        slashingDestinationTokenAccount: null,
+        slashingDestinationUsdcAccount: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+  };
+
+  const queueConfigChange = async (args: Partial<UpdatePoolOverviewArgs>) => {
+    const { configChangeCount } = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    const pendingConfigChange =
+      setup.sdk.pendingConfigChangePda(configChangeCount);
+
+    await program.methods
+      .queueConfigChange({
+        ...setup.sdk.getEmptyPoolOverviewFieldsForUpdateInstruction(),
+        ...args,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        pendingConfigChange,
+        registrationFeePayoutWallet: null,
+        slashingDestinationUsdcAccount: null,
+        slashingDestinationTokenAccount: null,
+        adminAuditLog: null,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    return pendingConfigChange;
+  };
+
+  const executeConfigChange = async (pendingConfigChange: PublicKey) => {
+    await program.methods
+      .executeConfigChange()
+      .accountsStrict({
+        caller: setup.signer,
+        poolOverview: setup.poolOverview,
+        pendingConfigChange,
+        rentPayer: setup.payer,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.signerKp])
+      .rpc();
+  };
+
+  const initiateWindDown = async () => {
+    await program.methods
+      .initiateWindDown({ finalEpoch })
+      .accountsStrict({
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+  };
+
+  const windDownDelegator1StakingRecord = async () => {
+    await program.methods
+      .windDownStakingRecord()
+      .accountsStrict({
+        owner: setup.delegator1,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: setup.pool1.delegatorStakingRecord,
+        referralRecord: null,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          setup.tokenMint,
+          setup.delegator1
+        ),
+        ownerUsdcAccount: delegator1UsdcAccount,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+  };
+
+  const unstakeDelegator2 = async () => {
+    const { shares } = await program.account.stakingRecord.fetch(
+      getDelegator2StakingRecord()
+    );
+    await program.methods
+      .unstake({ sharesAmount: shares })
+      .accountsStrict({
+        owner: setup.delegator2,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: getDelegator2StakingRecord(),
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.delegator2Kp])
+      .rpc();
+  };
+
+  const stake = async (
+    ownerKp: Keypair,
+    ownerStakingRecord: PublicKey,
+    tokenAmount: anchor.BN
+  ) => {
+    await program.methods
+      .stake({ tokenAmount, referrer: null })
+      .accountsStrict({
+        owner: ownerKp.publicKey,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          setup.tokenMint,
+          ownerKp.publicKey
+        ),
+        referralRecord: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([ownerKp])
+      .rpc();
+  };
+
+  const createStakingRecord = async (
+    ownerKp: Keypair,
+    ownerStakingRecord: PublicKey
+  ) => {
+    await program.methods
+      .createStakingRecord()
+      .accountsStrict({
+        payer: setup.payer,
+        owner: ownerKp.publicKey,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord,
+        systemProgram: SystemProgram.programId,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        poolOverview: setup.poolOverview,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, ownerKp])
+      .rpc();
+  };
+
+  before(async () => {
+    setup = await setupTests();
+    program = setup.sdk.program;
+    connection = program.provider.connection;
+
+    await program.methods
+      .createPoolOverview()
+      .accountsStrict({
+        payer: setup.payer,
+        programAdmin: setup.poolOverviewAdmin,
+        poolOverview: setup.poolOverview,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        mint: setup.tokenMint,
+        usdcMint: setup.usdcTokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        registrationFeePayoutWallet: setup.registrationFeePayoutWallet,
+        slashingDestinationTokenAccount: setup.slashingDestinationTokenAccount,
+        slashingDestinationUsdcAccount: setup.slashingDestinationUsdcAccount,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.poolOverviewAdminKp])
+      .rpc();
+
+    await program.methods
+      .updatePoolOverviewAuthorities({
+        newRewardDistributionAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+        newHaltAuthorities: [setup.haltingAuthorityKp.publicKey],
+        newSlashingAuthorities: [setup.slashingAuthorityKp.publicKey],
+        newEpochAuthorities: [
+          setup.rewardDistributionAuthorityKp.publicKey,
+        ],
+      })
+      .accountsStrict({
+        payer: setup.poolOverviewAdminKp.publicKey,
+        programAdmin: setup.poolOverviewAdminKp.publicKey,
+        poolOverview: setup.poolOverview,
+        systemProgram: SystemProgram.programId,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.poolOverviewAdminKp])
+      .rpc();
+
+    await updatePoolOverview({
+      allowPoolCreation: true,
+      minOperatorTokenStake: new anchor.BN(0),
+      delegatorUnstakeDelaySeconds: new anchor.BN(8),
+      operatorUnstakeDelaySeconds: new anchor.BN(20),
+      operatorPoolRegistrationFee: new anchor.BN(1_000),
+      slashingDelaySeconds: new anchor.BN(3),
+    });
+
+    // Create the first RewardRecord before OperatorPool 1 joins, so that the
+    // pool starts accruing rewards from epoch 2.
+    await handleMarkEpochAsFinalizing({ program, setup });
+    await program.methods
+      .createRewardRecord({
+        merkleRoots: [],
+        totalRewards: new anchor.BN(0),
+        totalUsdcPayout: new anchor.BN(0),
+        rewardMintPayouts: [],
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[1],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+
+    await program.methods
+      .createOperatorPool({
+        autoStakeFees: false,
+        rewardCommissionRateBps: 1_000,
+        usdcCommissionRateBps: 1_000,
+        allowDelegation: true,
+        name: setup.pool1.name,
+        description: setup.pool1.description,
+        websiteUrl: setup.pool1.websiteUrl,
+        avatarImageUrl: setup.pool1.avatarImageUrl,
+        operatorAuthKeys: null,
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        admin: setup.pool1.admin,
+        operatorPool: setup.pool1.pool,
+        stakingRecord: setup.pool1.stakingRecord,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        poolOverview: setup.poolOverview,
+        mint: setup.tokenMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        adminTokenAccount: setup.pool1.adminTokenAccount,
+        registrationFeePayoutTokenAccount:
+          setup.registrationFeePayoutTokenAccount,
+        operatorUsdcVault: setup.pool1.poolUsdcVault,
+        usdcMint: setup.usdcTokenMint,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .remainingAccounts(
+        setup.sdk.getTombstoneRemainingAccounts([setup.pool1.admin])
+      )
+      .signers([setup.payerKp, setup.pool1.adminKp])
+      .rpc();
+
+    await stake(setup.pool1.adminKp, setup.pool1.stakingRecord, operatorStake);
+
+    await createStakingRecord(
+      setup.delegator1Kp,
+      setup.pool1.delegatorStakingRecord
+    );
+    await stake(
+      setup.delegator1Kp,
+      setup.pool1.delegatorStakingRecord,
+      delegatorStake
+    );
+
+    await createStakingRecord(setup.delegator2Kp, getDelegator2StakingRecord());
+    await stake(
+      setup.delegator2Kp,
+      getDelegator2StakingRecord(),
+      delegatorStake
+    );
+
+    delegator1UsdcAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        setup.payerKp,
+        setup.usdcTokenMint,
+        setup.delegator1
+      )
+    ).address;
+
+    await updatePoolOverview({ configChangeTimelockSeconds });
+  });
+
+  it("Fail to wind down a StakingRecord before wind-down", async () => {
+    try {
+      await windDownDelegator1StakingRecord();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "programNotWindingDown");
+    }
+  });
+
+  it("Fail to initiate wind-down directly once a timelock is set", async () => {
+    try {
+      await initiateWindDown();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "configChangeRequiresTimelock");
+    }
+
+    try {
+      await updatePoolOverview({ windDownFinalEpoch: finalEpoch });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "configChangeRequiresTimelock");
+    }
+  });
+
+  it("Fail to queue wind-down with a final epoch before the current epoch", async () => {
+    try {
+      await queueConfigChange({ windDownFinalEpoch: new anchor.BN(0) });
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "invalidWindDownFinalEpoch");
+    }
+  });
+
+  it("Program admin queues wind-down as a config change", async () => {
+    windDownConfigChange = await queueConfigChange({
+      windDownFinalEpoch: finalEpoch,
+    });
+
+    try {
+      await executeConfigChange(windDownConfigChange);
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "configChangeNotMatured");
+    }
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.isNull(poolOverview.windDownAt);
+  });
+
+  it("Execute the queued wind-down once the timelock has passed", async () => {
+    await sleep((configChangeTimelockSeconds.toNumber() + 1) * 1_000);
+    await executeConfigChange(windDownConfigChange);
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert.isNotNull(poolOverview.windDownAt);
+    assert(poolOverview.windDownFinalEpoch?.eq(finalEpoch));
+    assert.isFalse(poolOverview.allowPoolCreation);
+  });
+
+  it("Fail to initiate wind-down again", async () => {
+    try {
+      await initiateWindDown();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "programWindingDown");
+    }
+  });
+
+  it("Fail to stake during wind-down", async () => {
+    try {
+      await stake(
+        setup.delegator1Kp,
+        setup.pool1.delegatorStakingRecord,
+        delegatorStake
+      );
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "programWindingDown");
+    }
+  });
+
+  it("Create the RewardRecord of the final epoch", async () => {
+    const rewardsInput: ConstructMerkleTreeInput[] = setup.rewardEpochs[2];
+    const merkleTree = MerkleUtils.constructMerkleTree(rewardsInput);
+    const totalRewards = rewardsInput.reduce(
+      (acc, curr) => acc + curr.tokenAmount,
+      0n
+    );
+    const totalUsdc = rewardsInput.reduce(
+      (acc, curr) => acc + curr.usdcAmount,
+      0n
+    );
+    await Promise.all([
+      mintTo(
+        connection,
+        setup.payerKp,
+        setup.tokenMint,
+        setup.rewardTokenAccount,
+        setup.tokenHolderKp,
+        totalRewards
+      ),
+      mintTo(
+        connection,
+        setup.payerKp,
+        setup.usdcTokenMint,
+        setup.usdcTokenAccount,
+        setup.tokenHolderKp,
+        totalUsdc
+      ),
+    ]);
+
+    await handleMarkEpochAsFinalizing({ program, setup });
+    await program.methods
+      .createRewardRecord({
+        merkleRoots: [Array.from(MerkleUtils.getTreeRoot(merkleTree))],
+        totalRewards: new anchor.BN(totalRewards.toString()),
+        totalUsdcPayout: new anchor.BN(totalUsdc.toString()),
+        rewardMintPayouts: [],
+      })
+      .accountsStrict({
+        payer: setup.payer,
+        authority: setup.rewardDistributionAuthority,
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[2],
+        rewardTokenAccount: setup.rewardTokenAccount,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        systemProgram: SystemProgram.programId,
+        pendingAction: null,
+        adminAuditLog: null,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .signers([setup.payerKp, setup.rewardDistributionAuthorityKp])
+      .rpc();
+
+    const poolOverview = await program.account.poolOverview.fetch(
+      setup.poolOverview
+    );
+    assert(poolOverview.completedRewardEpoch.eq(finalEpoch));
+  });
+
+  it("Fail to unstake before the pool accrues the rewards of the final epoch", async () => {
+    try {
+      await unstakeDelegator2();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "unclaimedRewards");
+    }
+  });
+
+  it("Fail to wind down a StakingRecord before the pool accrues the rewards of the final epoch", async () => {
+    try {
+      await windDownDelegator1StakingRecord();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "unclaimedRewards");
+    }
+  });
+
+  it("Accrue the rewards of the final epoch", async () => {
+    const rewardsInput: ConstructMerkleTreeInput[] = setup.rewardEpochs[2];
+    const merkleTree = MerkleUtils.constructMerkleTree(rewardsInput);
+    const nodeIndex = rewardsInput.findIndex(
+      (x) => x.address == setup.pool1.pool.toString()
+    );
+    const proofInputs = rewardsInput[nodeIndex];
+    assert(proofInputs != null);
+    const { proof, proofPath } = MerkleUtils.generateMerkleProof({
+      ...proofInputs,
+      index: nodeIndex,
+      merkleTree,
+    });
+
+    await program.methods
+      .accrueReward({
+        merkleIndex: 0,
+        proof: proof.map((arr) => Array.from(arr)),
+        proofPath,
+        rewardAmount: new anchor.BN(proofInputs.tokenAmount.toString()),
+        usdcAmount: new anchor.BN(proofInputs.usdcAmount.toString()),
+        rewardMintAmounts: [],
+      })
+      .accountsStrict({
+        poolOverview: setup.poolOverview,
+        rewardRecord: setup.rewardRecords[2],
+        operatorPool: setup.pool1.pool,
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        rewardTokenAccount: setup.rewardTokenAccount,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        rewardFeeTokenAccount: setup.pool1.rewardCommissionFeeTokenVault,
+        usdcFeeTokenAccount: setup.pool1.usdcCommissionFeeTokenVault,
+        usdcTokenAccount: setup.usdcTokenAccount,
+        poolUsdcVault: setup.pool1.poolUsdcVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+    const operatorPool = await program.account.operatorPool.fetch(
+      setup.pool1.pool
+    );
+    assert(operatorPool.rewardLastClaimedEpoch.eq(finalEpoch));
+  });
+
+  it("Wind down a StakingRecord without the owner's signature", async () => {
+    const ownerTokenAccount = getAssociatedTokenAddressSync(
+      setup.tokenMint,
+      setup.delegator1
+    );
+    const [operatorPoolPre, stakingRecordPre, tokenAccountPre, usdcAccountPre] =
+      await Promise.all([
+        program.account.operatorPool.fetch(setup.pool1.pool),
+        program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+        getAccount(connection, ownerTokenAccount),
+        getAccount(connection, delegator1UsdcAccount),
+      ]);
+
+    await windDownDelegator1StakingRecord();
+
+    const [stakingRecord, tokenAccount, usdcAccount] = await Promise.all([
+      program.account.stakingRecord.fetch(setup.pool1.delegatorStakingRecord),
+      getAccount(connection, ownerTokenAccount),
+      getAccount(connection, delegator1UsdcAccount),
+    ]);
+    const expectedTokenAmount = operatorPoolPre.totalStakedAmount
+      .mul(stakingRecordPre.shares)
+      .div(operatorPoolPre.totalShares);
+    assert(stakingRecord.shares.isZero());
+    assert(stakingRecord.tokensUnstakeAmount.isZero());
+    assert(stakingRecord.accruedUsdcEarnings.isZero());
+    assert.equal(
+      tokenAccount.amount,
+      tokenAccountPre.amount + BigInt(expectedTokenAmount.toString())
+    );
+    assert(usdcAccount.amount > usdcAccountPre.amount);
+  });
+
+  it("Fail to wind down a StakingRecord with nothing left to wind down", async () => {
+    try {
+      await windDownDelegator1StakingRecord();
+      assert(false);
+    } catch (error) {
+      assertStakingProgramError(error, "nothingToWindDown");
+    }
+  });
+
+  it("Unstake without a delay during wind-down and claim it", async () => {
+    await unstakeDelegator2();
+
+    const stakingRecordPre = await program.account.stakingRecord.fetch(
+      getDelegator2StakingRecord()
+    );
+    assert(
+      stakingRecordPre.unstakeAtTimestamp.lten(Math.floor(Date.now() / 1_000))
+    );
+
+    const ownerTokenAccount = getAssociatedTokenAddressSync(
+      setup.tokenMint,
+      setup.delegator2
+    );
+    const tokenAccountPre = await getAccount(connection, ownerTokenAccount);
+
+    await program.methods
+      .claimUnstake()
+      .accountsStrict({
+        owner: setup.delegator2,
+        poolOverview: setup.poolOverview,
+        operatorPool: setup.pool1.pool,
+        ownerStakingRecord: getDelegator2StakingRecord(),
+        operatorStakingRecord: setup.pool1.stakingRecord,
+        ownerTokenAccount,
+        stakedTokenAccount: setup.pool1.stakedTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        eventAuthority: setup.eventAuthority,
+        program: program.programId,
+      })
+      .rpc();
+
+    const tokenAccount = await getAccount(connection, ownerTokenAccount);
+    assert.equal(
+      tokenAccount.amount,
+      tokenAccountPre.amount +
+        BigInt(stakingRecordPre.tokensUnstakeAmount.toString())
+    );
+  });
+});